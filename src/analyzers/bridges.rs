@@ -1,16 +1,130 @@
-use crate::types::{BridgeStatus, BridgeType, Chain};
-use anyhow::Result;
+use crate::analyzers::wormhole::{TokenBridgeAttestation, Vaa};
+use crate::types::{AttestationStatus, BridgeStatus, BridgeType, Chain, WrappedAssetOrigin};
+use anyhow::{bail, Context, Result};
+use base64::Engine;
 use reqwest::Client;
 use serde::Deserialize;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
 use std::time::Duration;
 
+/// Wormhole Token Bridge (Portal) program id on Solana mainnet
+const TOKEN_BRIDGE_PROGRAM_ID: &str = "wormDTUJ6AWPNvk59vGQbDvGJmqbDTdgWgAqcLBCgUb";
+
+/// Wormhole NFT Bridge program id on Solana mainnet. A separate program (and PDA seed
+/// layout) from the fungible Token Bridge above — it wraps per-token, not per-collection.
+const NFT_BRIDGE_PROGRAM_ID: &str = "WnFt12ZrnzZrFZkt2xsNsaNWoQribnuQ5B5FrDbwDhD";
+
+/// Parse a `0x`-prefixed 20-byte EVM address into raw bytes
+fn parse_evm_address(address: &str) -> Result<[u8; 20]> {
+    let hex = address.trim_start_matches("0x");
+    if hex.len() != 40 {
+        bail!("not a 20-byte EVM address: {}", address);
+    }
+    let mut bytes = [0u8; 20];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)
+            .context("invalid hex in EVM address")?;
+    }
+    Ok(bytes)
+}
+
+/// Derive the canonical Portal-wrapped SPL mint for an EVM token deterministically. The
+/// Token Bridge program computes a wrapped mint's address as a PDA over
+/// `["wrapped", chain_id, token_address]`, so this is computable for any token on a
+/// Wormhole-connected chain — not just the ones in `KNOWN_PORTAL` — without it ever
+/// having been attested. Because the PDA is computable either way, a derived address
+/// alone doesn't prove the asset is actually bridged; `check_portal_wrapped` additionally
+/// confirms the mint account exists on-chain before reporting it.
+pub(crate) fn derive_portal_wrapped_mint(address: &str, chain: Chain) -> Result<Pubkey> {
+    let program_id =
+        Pubkey::from_str(TOKEN_BRIDGE_PROGRAM_ID).context("invalid Token Bridge program id")?;
+    let chain_id = wormhole_chain_id(chain);
+    let evm_address = parse_evm_address(address)?;
+
+    // Left-pad the 20-byte EVM address into Wormhole's 32-byte token address slot
+    let mut token_address = [0u8; 32];
+    token_address[12..].copy_from_slice(&evm_address);
+
+    let (pda, _bump) = Pubkey::find_program_address(
+        &[b"wrapped", &chain_id.to_be_bytes(), &token_address],
+        &program_id,
+    );
+    Ok(pda)
+}
+
+/// Derive the wrapped-asset-meta PDA for a wrapped mint — seeds `["meta", mint]` under
+/// whichever bridge program created it. This is the account the bridge program writes the
+/// wrapped mint's original chain, address, and decimals into at creation time. Both the
+/// Token Bridge and NFT Bridge programs use this same seed layout under their own program
+/// id, so the caller passes whichever one derived `mint`.
+fn derive_wrapped_meta(mint: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    let (pda, _bump) = Pubkey::find_program_address(&[b"meta", mint.as_ref()], program_id);
+    pda
+}
+
+/// Parse a Token Bridge `WrappedMeta` account. The program stores it behind a leading
+/// `is_initialized` byte (the solitaire `Data<T>` wrapper it's built on), followed by the
+/// struct itself: `chain: u16` (little-endian), `token_address: [u8; 32]`,
+/// `original_decimals: u8`. Returns `None` on anything shorter than that rather than
+/// erroring the whole discovery flow over an account layout mismatch.
+fn parse_wrapped_meta(data: &[u8]) -> Option<WrappedAssetOrigin> {
+    if data.len() < 36 {
+        return None;
+    }
+    let chain = u16::from_le_bytes([data[1], data[2]]);
+    let mut token_address = [0u8; 32];
+    token_address.copy_from_slice(&data[3..35]);
+    Some(WrappedAssetOrigin {
+        chain,
+        token_address,
+        original_decimals: data[35],
+    })
+}
+
+/// Derive the canonical Wormhole NFT Bridge wrapped mint for one specific token id of an
+/// ERC-721/ERC-1155 collection. The NFT bridge wraps per-token rather than per-collection,
+/// so its PDA seed includes the token id — `["wrapped", chain_id, token_address,
+/// token_id_be]` — unlike the fungible Token Bridge's collection-agnostic seed.
+pub(crate) fn derive_nft_bridge_wrapped_mint(
+    address: &str,
+    chain: Chain,
+    token_id: u64,
+) -> Result<Pubkey> {
+    let program_id =
+        Pubkey::from_str(NFT_BRIDGE_PROGRAM_ID).context("invalid NFT Bridge program id")?;
+    let chain_id = wormhole_chain_id(chain);
+    let evm_address = parse_evm_address(address)?;
+
+    let mut token_address = [0u8; 32];
+    token_address[12..].copy_from_slice(&evm_address);
+
+    // The bridge represents a token id as a full 32-byte big-endian integer; a `u64` only
+    // covers token ids that fit in 8 bytes, so it's left-padded the same way the address is.
+    let mut token_id_be = [0u8; 32];
+    token_id_be[24..].copy_from_slice(&token_id.to_be_bytes());
+
+    let (pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"wrapped",
+            &chain_id.to_be_bytes(),
+            &token_address,
+            &token_id_be,
+        ],
+        &program_id,
+    );
+    Ok(pda)
+}
+
 /// Detects existing bridges for a token using live APIs + curated fallback
 pub struct BridgeDetector {
     client: Client,
 }
 
 /// Wormhole chain IDs
-fn wormhole_chain_id(chain: Chain) -> u16 {
+pub(crate) fn wormhole_chain_id(chain: Chain) -> u16 {
     match chain {
         Chain::Ethereum => 2,
         Chain::Polygon => 5,
@@ -19,13 +133,81 @@ fn wormhole_chain_id(chain: Chain) -> u16 {
         Chain::Base => 30,
         Chain::Avalanche => 6,
         Chain::Bsc => 4,
+        Chain::Solana => 1,
     }
 }
 
+/// Wormhole Token Bridge contract address on each EVM chain's mainnet. Unlike Solana's
+/// side (where locked tokens sit in a separate custody token account owned by the
+/// program), the EVM Token Bridge contract holds locked tokens directly — so this is also
+/// the address `SupplyReconciler` reads a source token's locked balance from via
+/// `balanceOf`. `None` for `Chain::Solana`, which is never a reconciliation source chain.
+pub(crate) fn token_bridge_custody_address(chain: Chain) -> Option<&'static str> {
+    match chain {
+        Chain::Ethereum => Some("0x3ee18B2214AFF97000D974cf647E7C347E8fa585"),
+        Chain::Bsc => Some("0xB6F6D86a8f9879A9c87f643768D9efc38c1Da6E7"),
+        Chain::Polygon => Some("0x5a58505a96D1dbf8dF91cB21B54419FC36e93fdE"),
+        Chain::Avalanche => Some("0x0e082F06FF657D94310cB8cE8B0D9a04541d8052"),
+        Chain::Arbitrum => Some("0x0b2402144Bb366A632D14B83F244D2e0e21bD39c"),
+        Chain::Optimism => Some("0x1D68124e65faFC907325e3EDbF8c4d84499DAa8b"),
+        Chain::Base => Some("0x8d2de8d2f73F1F4cAB472AC9A881C9b123C79627"),
+        Chain::Solana => None,
+    }
+}
+
+/// Reverse of `wormhole_chain_id`, extended to Wormhole's other major connected chains —
+/// a wrapped asset can originate from any of them, not just the ones Daybreak directly
+/// analyzes. An unrecognized ID is reported as a bare number rather than guessed at.
+pub fn wormhole_chain_name(id: u16) -> String {
+    let name = match id {
+        1 => "Solana",
+        2 => "Ethereum",
+        3 => "Terra",
+        4 => "BSC",
+        5 => "Polygon",
+        6 => "Avalanche",
+        7 => "Oasis",
+        8 => "Algorand",
+        9 => "Aurora",
+        10 => "Fantom",
+        11 => "Karura",
+        12 => "Acala",
+        13 => "Klaytn",
+        14 => "Celo",
+        15 => "NEAR",
+        16 => "Moonbeam",
+        18 => "Terra2",
+        19 => "Injective",
+        21 => "Sui",
+        22 => "Aptos",
+        23 => "Arbitrum",
+        24 => "Optimism",
+        25 => "Gnosis",
+        28 => "XPLA",
+        30 => "Base",
+        32 => "Sei",
+        36 => "Blast",
+        38 => "Linea",
+        _ => return format!("unknown chain (Wormhole ID {})", id),
+    };
+    name.to_string()
+}
+
 /// Response from WormholeScan operations API
 #[derive(Deserialize)]
 struct WormholeOperationsResponse {
-    operations: Option<Vec<serde_json::Value>>,
+    operations: Option<Vec<WormholeOperation>>,
+}
+
+#[derive(Deserialize)]
+struct WormholeOperation {
+    vaa: Option<WormholeVaaEnvelope>,
+}
+
+/// The bits of WormholeScan's `vaa` object we need: the raw, base64-encoded VAA bytes
+#[derive(Deserialize)]
+struct WormholeVaaEnvelope {
+    raw: Option<String>,
 }
 
 /// A curated bridge entry with type annotation
@@ -136,18 +318,33 @@ impl BridgeDetector {
             return Ok(status);
         }
 
-        // 2. Query WormholeScan API for Wormhole attestation/activity
-        let wormhole_attested = self
+        // 2. Derive the Portal wrapped-mint PDA and confirm it's actually been created,
+        // covering every Wormhole-connected token rather than just the ones hand-added to
+        // KNOWN_PORTAL
+        if let Some(status) = self.check_portal_wrapped(&address_lower, chain) {
+            return Ok(status);
+        }
+
+        // 3. Query WormholeScan API and verify the VAA it returns, rather than just
+        // noting that some cross-chain activity exists. Only a quorum-verified VAA
+        // upgrades this to an actual "already on Solana" result — an operation existing
+        // without quorum is exactly the spoofable, presence-only signal this subsystem
+        // replaces.
+        let wormhole_attestation = self
             .check_wormhole_api(&address_lower, chain)
             .await
-            .unwrap_or(false);
+            .unwrap_or_default();
 
         Ok(BridgeStatus {
-            already_on_solana: false,
+            already_on_solana: wormhole_attestation.quorum_met,
             solana_address: None,
-            bridge_provider: None,
-            bridge_type: None,
-            wormhole_attested,
+            bridge_provider: wormhole_attestation
+                .quorum_met
+                .then(|| "Wormhole Portal (live)".to_string()),
+            bridge_type: wormhole_attestation.quorum_met.then_some(BridgeType::Portal),
+            bridge_verified: wormhole_attestation.quorum_met,
+            wormhole_attestation,
+            wrapped_origin: None,
         })
     }
 
@@ -161,7 +358,13 @@ impl BridgeDetector {
                     solana_address: Some(entry.solana_address.to_string()),
                     bridge_provider: Some(format!("NTT/Sunrise ({})", entry.symbol)),
                     bridge_type: Some(BridgeType::Ntt),
-                    wormhole_attested: true,
+                    bridge_verified: true,
+                    wormhole_attestation: AttestationStatus {
+                        verified: true,
+                        quorum_met: true,
+                        ..Default::default()
+                    },
+                    wrapped_origin: None,
                 });
             }
         }
@@ -174,7 +377,11 @@ impl BridgeDetector {
                     solana_address: Some(entry.solana_address.to_string()),
                     bridge_provider: Some(format!("Native ({})", entry.symbol)),
                     bridge_type: Some(BridgeType::Native),
-                    wormhole_attested: false,
+                    // Not Wormhole-bridged at all, so there's no VAA to check quorum on —
+                    // trust comes from the curated, hand-verified address pair instead.
+                    bridge_verified: true,
+                    wormhole_attestation: AttestationStatus::default(),
+                    wrapped_origin: None,
                 });
             }
         }
@@ -187,7 +394,13 @@ impl BridgeDetector {
                     solana_address: Some(entry.solana_address.to_string()),
                     bridge_provider: Some(format!("Wormhole Portal ({})", entry.symbol)),
                     bridge_type: Some(BridgeType::Portal),
-                    wormhole_attested: true,
+                    bridge_verified: true,
+                    wormhole_attestation: AttestationStatus {
+                        verified: true,
+                        quorum_met: true,
+                        ..Default::default()
+                    },
+                    wrapped_origin: None,
                 });
             }
         }
@@ -195,8 +408,106 @@ impl BridgeDetector {
         None
     }
 
-    /// Query WormholeScan API for cross-chain activity involving this token
-    async fn check_wormhole_api(&self, address: &str, chain: Chain) -> Result<bool> {
+    /// Tier 2: derive the Portal wrapped-mint PDA for `address` and confirm the mint
+    /// account actually exists on Solana mainnet before reporting it as bridged.
+    /// The PDA alone doesn't prove attestation (it's computable for tokens that were
+    /// never bridged), but only the Token Bridge program can have created an account at
+    /// its own PDA, so existence is as trustworthy a signal as the curated list — just
+    /// discovered instead of hand-maintained. Uses a synchronous `RpcClient` call
+    /// directly inside this `async fn`, same as the rest of the codebase's Solana checks.
+    fn check_portal_wrapped(&self, address: &str, chain: Chain) -> Option<BridgeStatus> {
+        let mint = derive_portal_wrapped_mint(address, chain).ok()?;
+        let client = RpcClient::new(Chain::Solana.default_rpc_url().to_string());
+        let account = client.get_account(&mint).ok()?;
+
+        // The PDA is computable for any token whether or not it was ever bridged, and a
+        // bare SOL transfer to it is enough to make `get_account` succeed — mere existence
+        // proves nothing. Only a real Token Bridge mint is owned by the SPL Token (or
+        // Token-2022) program and unpacks as a `Mint`; anything else is a System-owned
+        // account spoofing presence, not an actual wrapped asset.
+        if !Self::unpacks_as_mint(&account.owner, &account.data) {
+            return None;
+        }
+
+        // The meta PDA records what the bridge program itself believes this wrapped mint's
+        // origin is — read it back rather than just trusting that the derivation landed on
+        // the token we think it did. A missing or malformed meta account doesn't invalidate
+        // the wrapped-mint finding above, so this stays best-effort (`None` on failure).
+        let wrapped_origin = Pubkey::from_str(TOKEN_BRIDGE_PROGRAM_ID)
+            .ok()
+            .map(|program_id| derive_wrapped_meta(&mint, &program_id))
+            .and_then(|meta_pda| client.get_account_data(&meta_pda).ok())
+            .and_then(|data| parse_wrapped_meta(&data));
+
+        Some(BridgeStatus {
+            already_on_solana: true,
+            solana_address: Some(mint.to_string()),
+            bridge_provider: Some("Wormhole Portal (derived)".to_string()),
+            bridge_type: Some(BridgeType::Portal),
+            bridge_verified: true,
+            wormhole_attestation: AttestationStatus::default(),
+            wrapped_origin,
+        })
+    }
+
+    /// Confirm an account is a real SPL Token / Token-2022 mint rather than merely
+    /// existing — the check both `check_portal_wrapped` and `check_nft_bridge` need before
+    /// trusting a derived PDA's presence as proof of an actual bridged asset. A
+    /// Token-2022 mint can carry extension data appended after the base `Mint` layout, so
+    /// it's unpacked via `StateWithExtensions` rather than the fixed-length `Mint::unpack`
+    /// legacy SPL Token accounts use.
+    fn unpacks_as_mint(owner: &Pubkey, data: &[u8]) -> bool {
+        if *owner == spl_token::id() {
+            spl_token::state::Mint::unpack(data).is_ok()
+        } else if *owner == spl_token_2022::id() {
+            spl_token_2022::extension::StateWithExtensions::<spl_token_2022::state::Mint>::unpack(data).is_ok()
+        } else {
+            false
+        }
+    }
+
+    /// NFT counterpart of `check`/`check_portal_wrapped`: derive the NFT bridge's wrapped
+    /// mint for one specific token id and confirm it exists on Solana mainnet before
+    /// reporting it as bridged. Kept separate from `check` (rather than an extra
+    /// parameter) because fungible tokens have no token id and NFT collections don't have
+    /// a single bridge status — every token id bridges (or doesn't) independently.
+    pub fn check_nft_bridge(&self, address: &str, chain: Chain, token_id: u64) -> Result<BridgeStatus> {
+        let mint = derive_nft_bridge_wrapped_mint(&address.to_lowercase(), chain, token_id)?;
+        let client = RpcClient::new(Chain::Solana.default_rpc_url().to_string());
+
+        // As with `check_portal_wrapped`, the PDA's mere existence (even a bare SOL
+        // transfer to it) proves nothing — only a real mint owned by the SPL Token
+        // program counts as actually bridged.
+        let already_on_solana = client
+            .get_account(&mint)
+            .is_ok_and(|account| Self::unpacks_as_mint(&account.owner, &account.data));
+
+        let wrapped_origin = already_on_solana
+            .then(|| {
+                Pubkey::from_str(NFT_BRIDGE_PROGRAM_ID)
+                    .ok()
+                    .map(|program_id| derive_wrapped_meta(&mint, &program_id))
+            })
+            .flatten()
+            .and_then(|meta_pda| client.get_account_data(&meta_pda).ok())
+            .and_then(|data| parse_wrapped_meta(&data));
+
+        Ok(BridgeStatus {
+            already_on_solana,
+            solana_address: already_on_solana.then(|| mint.to_string()),
+            bridge_provider: already_on_solana.then(|| "Wormhole NFT Bridge".to_string()),
+            bridge_type: already_on_solana.then_some(BridgeType::Portal),
+            bridge_verified: already_on_solana,
+            wormhole_attestation: AttestationStatus::default(),
+            wrapped_origin,
+        })
+    }
+
+    /// Query WormholeScan for the token's most recent Wormhole transfer and verify the
+    /// VAA it returns, rather than just trusting that the API lists an operation.
+    /// `pub(crate)` rather than private: `commands::status` calls this directly to verify
+    /// a claimed source token's attestation, outside the full `check()` flow.
+    pub(crate) async fn check_wormhole_api(&self, address: &str, chain: Chain) -> Result<AttestationStatus> {
         let chain_id = wormhole_chain_id(chain);
         let url = format!(
             "https://api.wormholescan.io/api/v1/operations?address={}&sourceChain={}&limit=1",
@@ -211,11 +522,32 @@ impl BridgeDetector {
             .await?;
 
         if !resp.status().is_success() {
-            return Ok(false);
+            return Ok(AttestationStatus::default());
         }
 
         let body: WormholeOperationsResponse = resp.json().await?;
-        Ok(body.operations.map(|ops| !ops.is_empty()).unwrap_or(false))
+        let Some(raw) = body
+            .operations
+            .into_iter()
+            .flatten()
+            .next()
+            .and_then(|op| op.vaa)
+            .and_then(|vaa| vaa.raw)
+        else {
+            return Ok(AttestationStatus::default());
+        };
+
+        let vaa_bytes = base64::engine::general_purpose::STANDARD
+            .decode(raw)
+            .context("WormholeScan returned non-base64 VAA bytes")?;
+        let vaa = Vaa::parse(&vaa_bytes)?;
+        let mut status = vaa.verify();
+
+        if let Ok(attestation) = TokenBridgeAttestation::parse(&vaa.payload) {
+            status.attested_decimals = Some(attestation.decimals);
+        }
+
+        Ok(status)
     }
 }
 
@@ -249,7 +581,7 @@ mod tests {
         let status = result.expect("WBTC should be in curated list");
         assert!(status.already_on_solana);
         assert_eq!(status.bridge_type, Some(BridgeType::Portal));
-        assert!(status.wormhole_attested);
+        assert!(status.wormhole_attestation.verified);
     }
 
     #[test]
@@ -261,6 +593,52 @@ mod tests {
         assert_eq!(status.bridge_type, Some(BridgeType::Ntt));
     }
 
+    #[test]
+    fn test_derive_portal_wrapped_mint_is_deterministic() {
+        let a = derive_portal_wrapped_mint(
+            "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            Chain::Ethereum,
+        )
+        .unwrap();
+        let b = derive_portal_wrapped_mint(
+            "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            Chain::Ethereum,
+        )
+        .unwrap();
+        assert_eq!(a, b);
+
+        // Same address on a different source chain must derive a different PDA, since
+        // the chain id is part of the seed
+        let c = derive_portal_wrapped_mint(
+            "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            Chain::Polygon,
+        )
+        .unwrap();
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_derive_portal_wrapped_mint_rejects_malformed_address() {
+        assert!(derive_portal_wrapped_mint("0xnotanaddress", Chain::Ethereum).is_err());
+        assert!(derive_portal_wrapped_mint("0x1234", Chain::Ethereum).is_err());
+    }
+
+    #[test]
+    fn test_derive_nft_bridge_wrapped_mint_is_keyed_per_token_id() {
+        let addr = "0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d"; // BAYC
+        let token_1 = derive_nft_bridge_wrapped_mint(addr, Chain::Ethereum, 1).unwrap();
+        let token_1_again = derive_nft_bridge_wrapped_mint(addr, Chain::Ethereum, 1).unwrap();
+        let token_2 = derive_nft_bridge_wrapped_mint(addr, Chain::Ethereum, 2).unwrap();
+
+        assert_eq!(token_1, token_1_again);
+        assert_ne!(token_1, token_2);
+
+        // Also distinct from the fungible Token Bridge's PDA for the same address, since
+        // the two programs (and seed layouts) differ
+        let fungible = derive_portal_wrapped_mint(addr, Chain::Ethereum).unwrap();
+        assert_ne!(token_1, fungible);
+    }
+
     #[test]
     fn test_curated_unknown_token() {
         let detector = BridgeDetector::new();
@@ -286,6 +664,13 @@ mod tests {
         assert_eq!(wormhole_chain_id(Chain::Base), 30);
     }
 
+    #[test]
+    fn test_wormhole_chain_name_known_and_unknown() {
+        assert_eq!(wormhole_chain_name(2), "Ethereum");
+        assert_eq!(wormhole_chain_name(1), "Solana");
+        assert_eq!(wormhole_chain_name(9999), "unknown chain (Wormhole ID 9999)");
+    }
+
     #[tokio::test]
     async fn test_check_lowercases_address() {
         let detector = BridgeDetector::new();
@@ -0,0 +1,145 @@
+use crate::types::{AccessControl, BytecodeAnalysis, Chain, TokenCapabilities, TokenInfo};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// A cached analysis result for one (chain, address) pair, valid as long as the on-chain
+/// code hash hasn't changed since it was stored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedAnalysis {
+    pub code_hash: String,
+    pub token: TokenInfo,
+    pub capabilities: TokenCapabilities,
+    pub bytecode: BytecodeAnalysis,
+    pub access_control: AccessControl,
+}
+
+/// Default on-disk location for the analysis cache
+const DEFAULT_CACHE_PATH: &str = "~/.cache/daybreak/analysis-cache.json";
+
+/// Persistent on-disk cache of token analyses, keyed by chain + address and invalidated
+/// when the on-chain code hash no longer matches the stored entry. Lets `run_report` skip
+/// re-fetching and re-analyzing bytecode for a token that hasn't changed since last run.
+#[derive(Debug, Default)]
+pub struct AnalysisCache {
+    entries: HashMap<String, CachedAnalysis>,
+    path: PathBuf,
+}
+
+impl AnalysisCache {
+    fn key(chain: Chain, address: &str) -> String {
+        format!("{}:{}", chain.display_name(), address.to_lowercase())
+    }
+
+    fn expand_default_path() -> PathBuf {
+        match std::env::var("HOME") {
+            Ok(home) => PathBuf::from(DEFAULT_CACHE_PATH.replacen('~', &home, 1)),
+            Err(_) => PathBuf::from(DEFAULT_CACHE_PATH),
+        }
+    }
+
+    /// Load the cache from its default on-disk location, starting empty if missing or
+    /// malformed rather than failing the analysis
+    pub fn load() -> Self {
+        Self::load_from(Self::expand_default_path())
+    }
+
+    fn load_from(path: PathBuf) -> Self {
+        let entries = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+        Self { entries, path }
+    }
+
+    /// Look up a cached analysis, returned only if the supplied code hash still matches
+    pub fn get(&self, chain: Chain, address: &str, code_hash: &str) -> Option<&CachedAnalysis> {
+        self.entries
+            .get(&Self::key(chain, address))
+            .filter(|entry| entry.code_hash == code_hash)
+    }
+
+    /// Store (or replace) the analysis for this chain + address
+    pub fn put(&mut self, chain: Chain, address: &str, entry: CachedAnalysis) {
+        self.entries.insert(Self::key(chain, address), entry);
+    }
+
+    /// Persist the cache to disk, creating the parent directory if needed
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, serde_json::to_string_pretty(&self.entries)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BytecodeComplexity;
+
+    fn sample_entry(code_hash: &str) -> CachedAnalysis {
+        CachedAnalysis {
+            code_hash: code_hash.to_string(),
+            token: TokenInfo {
+                address: "0xabc".to_string(),
+                chain: Chain::Ethereum,
+                name: "Test".to_string(),
+                symbol: "TST".to_string(),
+                decimals: 18,
+                total_supply: "1000".to_string(),
+            },
+            capabilities: TokenCapabilities::default(),
+            bytecode: BytecodeAnalysis {
+                complexity: BytecodeComplexity::Simple,
+                ..Default::default()
+            },
+            access_control: AccessControl::default(),
+        }
+    }
+
+    #[test]
+    fn test_miss_on_empty_cache() {
+        let cache = AnalysisCache::default();
+        assert!(cache.get(Chain::Ethereum, "0xabc", "0xhash").is_none());
+    }
+
+    #[test]
+    fn test_hit_with_matching_code_hash() {
+        let mut cache = AnalysisCache::default();
+        cache.put(Chain::Ethereum, "0xabc", sample_entry("0xhash"));
+        assert!(cache.get(Chain::Ethereum, "0xabc", "0xhash").is_some());
+    }
+
+    #[test]
+    fn test_miss_when_code_hash_changed() {
+        let mut cache = AnalysisCache::default();
+        cache.put(Chain::Ethereum, "0xabc", sample_entry("0xold"));
+        assert!(cache.get(Chain::Ethereum, "0xabc", "0xnew").is_none());
+    }
+
+    #[test]
+    fn test_key_is_case_insensitive_on_address() {
+        let mut cache = AnalysisCache::default();
+        cache.put(Chain::Ethereum, "0xABC", sample_entry("0xhash"));
+        assert!(cache.get(Chain::Ethereum, "0xabc", "0xhash").is_some());
+    }
+
+    #[test]
+    fn test_save_and_reload_round_trip() {
+        let dir = std::env::temp_dir().join(format!("daybreak-cache-test-{}", std::process::id()));
+        let path = dir.join("cache.json");
+        let mut cache = AnalysisCache {
+            entries: HashMap::new(),
+            path: path.clone(),
+        };
+        cache.put(Chain::Ethereum, "0xabc", sample_entry("0xhash"));
+        cache.save().unwrap();
+
+        let reloaded = AnalysisCache::load_from(path);
+        assert!(reloaded.get(Chain::Ethereum, "0xabc", "0xhash").is_some());
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
@@ -0,0 +1,145 @@
+use crate::analyzers::solana::SolanaChecker;
+use crate::analyzers::EvmAnalyzer;
+use crate::types::{Chain, TokenInfo};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{commitment_config::CommitmentConfig, program_pack::Pack, pubkey::Pubkey};
+use spl_token::state::Mint;
+use std::str::FromStr;
+
+/// Rough order-of-magnitude cost to stand up this chain's side of an NTT bridge endpoint
+/// — enough to compare chains at a glance. For a precise, live-priced quote see
+/// `report::cost_estimate::CostEstimator`, which this intentionally doesn't duplicate.
+#[derive(Debug, Clone)]
+pub struct DeployCost {
+    pub native_amount: f64,
+    pub native_symbol: &'static str,
+}
+
+/// One chain's status-monitor integration: how to read a token's metadata there, roughly
+/// what standing up a bridge endpoint costs, and where to view an address in that chain's
+/// explorer. Adding a new chain (Sui, Aptos, another Wormhole NTT endpoint) means
+/// implementing this trait and adding one `CheckerKind` variant plus a match arm below —
+/// callers iterate over `CheckerKind`s rather than hand-rolling per-chain branches.
+#[async_trait]
+pub trait ChainChecker {
+    async fn token_info(&self, address: &str) -> Result<TokenInfo>;
+    fn estimate_deploy_cost(&self) -> DeployCost;
+    fn explorer_url(&self, address: &str) -> String;
+}
+
+/// Registered chain checkers. A plain enum (rather than `Box<dyn ChainChecker>`) so
+/// `CheckerKind::label()` and friends can match on it without downcasting, and so a
+/// caller can build a `Vec<CheckerKind>` up front without a chain-specific constructor.
+pub enum CheckerKind {
+    Solana { network: String },
+    Evm { chain: Chain, rpc_url: Option<String> },
+}
+
+impl CheckerKind {
+    pub fn label(&self) -> String {
+        match self {
+            CheckerKind::Solana { .. } => "Solana".to_string(),
+            CheckerKind::Evm { chain, .. } => chain.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl ChainChecker for CheckerKind {
+    async fn token_info(&self, address: &str) -> Result<TokenInfo> {
+        match self {
+            CheckerKind::Solana { .. } => solana_mint_token_info(address),
+            CheckerKind::Evm { chain, rpc_url } => {
+                EvmAnalyzer::new(*chain, rpc_url.clone())
+                    .get_token_info(address)
+                    .await
+            }
+        }
+    }
+
+    fn estimate_deploy_cost(&self) -> DeployCost {
+        match self {
+            // Live rent-exemption sum for the mint + NTT manager/transceiver config +
+            // token account, via `SolanaChecker` — falls back to a flat estimate itself
+            // if the RPC is unreachable (see its own doc comment).
+            CheckerKind::Solana { network } => DeployCost {
+                native_amount: SolanaChecker::with_rpc_url(solana_rpc_url(network))
+                    .estimate_ntt_deployment_cost(),
+                native_symbol: "SOL",
+            },
+            // Manager + transceiver deploy plus initializer storage writes, at a rough
+            // mid-range gas price — a ballpark, not a live quote (see `DeployCost`'s doc).
+            CheckerKind::Evm { .. } => DeployCost {
+                native_amount: 0.05,
+                native_symbol: "native gas token",
+            },
+        }
+    }
+
+    fn explorer_url(&self, address: &str) -> String {
+        match self {
+            CheckerKind::Solana { network } => {
+                if network == "mainnet" {
+                    format!("https://explorer.solana.com/address/{}", address)
+                } else {
+                    format!(
+                        "https://explorer.solana.com/address/{}?cluster={}",
+                        address, network
+                    )
+                }
+            }
+            CheckerKind::Evm { chain, .. } => {
+                let base = match chain {
+                    Chain::Ethereum => "https://etherscan.io/address",
+                    Chain::Polygon => "https://polygonscan.com/address",
+                    Chain::Arbitrum => "https://arbiscan.io/address",
+                    Chain::Optimism => "https://optimistic.etherscan.io/address",
+                    Chain::Base => "https://basescan.org/address",
+                    Chain::Bsc => "https://bscscan.com/address",
+                    Chain::Avalanche => "https://snowtrace.io/address",
+                    Chain::Solana => "https://explorer.solana.com/address",
+                };
+                format!("{}/{}", base, address)
+            }
+        }
+    }
+}
+
+/// Resolve a `--network` string ("mainnet" or "devnet") to its Solana RPC endpoint
+fn solana_rpc_url(network: &str) -> String {
+    match network {
+        "mainnet" => "https://api.mainnet-beta.solana.com",
+        _ => "https://api.devnet.solana.com",
+    }
+    .to_string()
+}
+
+/// A Solana mint carries decimals/supply/authorities on-chain, but never name/symbol —
+/// those live in a separate Metaplex metadata account this checker doesn't read. The
+/// `TokenInfo` returned here is honest about that rather than guessing: `name`/`symbol`
+/// are empty, which is the signal to the caller that they weren't available.
+fn solana_mint_token_info(mint_address: &str) -> Result<TokenInfo> {
+    let client = RpcClient::new_with_commitment(
+        Chain::Solana.default_rpc_url(),
+        CommitmentConfig::confirmed(),
+    );
+    let pubkey = Pubkey::from_str(mint_address).context("Invalid Solana mint address")?;
+
+    let account = client
+        .get_account(&pubkey)
+        .context("Token mint account not found — is the address correct?")?;
+
+    let mint = Mint::unpack(&account.data)
+        .context("Failed to parse mint account data — is this an SPL token?")?;
+
+    Ok(TokenInfo {
+        address: mint_address.to_string(),
+        chain: Chain::Solana,
+        name: String::new(),
+        symbol: String::new(),
+        decimals: mint.decimals,
+        total_supply: mint.supply.to_string(),
+    })
+}
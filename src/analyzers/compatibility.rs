@@ -1,17 +1,214 @@
 use crate::types::{
-    BytecodeAnalysis, CompatibilityIssue, CompatibilityResult, IssueSeverity, NttMode,
-    TokenCapabilities, TokenInfo,
+    AccessControl, BytecodeAnalysis, CompatibilityIssue, CompatibilityResult, ControllerType,
+    GovernanceProfile, IssueSeverity, NftCollectionInfo, NftCompatibilityResult, NttMode,
+    SupplyModel, TokenCapabilities, TokenInfo,
 };
 
-/// Checks NTT compatibility for a token
-pub struct CompatibilityChecker;
+/// A single known-vulnerability/feature pattern: `matcher` decides whether it applies to
+/// a given token, `issue` is reported verbatim when it does. Plain fn pointers (not
+/// closures) so rules are inert data — easy to seed as compile-time defaults in
+/// `default_rules` and just as easy for a caller to append their own via `with_rules`
+/// without this file knowing anything about them.
+pub struct Rule {
+    pub id: &'static str,
+    pub matcher: fn(&TokenInfo, &TokenCapabilities, &BytecodeAnalysis) -> bool,
+    pub issue: CompatibilityIssue,
+}
+
+/// Checks NTT compatibility for a token. Concerns that reduce to "does this token match a
+/// known pattern" are data-driven [`Rule`]s (see `default_rules`) so a new audit pattern —
+/// an org's own or one lifted from a vulnerability dataset — can be registered via
+/// `with_rules` without touching this file. Concerns that also feed `recommended_mode` or
+/// `solana_decimals` (not just which issues fire) stay as dedicated methods below, since a
+/// `Rule`'s output is only an issue.
+pub struct CompatibilityChecker {
+    rules: Vec<Rule>,
+}
 
 impl CompatibilityChecker {
-    /// Perform full compatibility analysis
+    /// Built-in rules only
+    pub fn new() -> Self {
+        Self {
+            rules: Self::default_rules(),
+        }
+    }
+
+    /// Built-in rules plus caller-supplied ones (e.g. org-specific audit patterns),
+    /// checked in addition to — not instead of — the defaults.
+    pub fn with_rules(extra_rules: Vec<Rule>) -> Self {
+        let mut rules = Self::default_rules();
+        rules.extend(extra_rules);
+        Self { rules }
+    }
+
+    /// The curated rule set this checker ships with: the feature/capability checks that
+    /// used to be hardcoded `if` branches, plus a pattern pulled from public ERC-20
+    /// vulnerability write-ups (the approve front-running race). Two other commonly-cited
+    /// patterns — governance logic that reads a live `totalSupply()`, and a `transfer`
+    /// that doesn't return a bool — turn on control-flow and return-data properties this
+    /// selector-presence analyzer can't see; they're exactly what `with_rules` is for once
+    /// a decoder-based detector for them exists, rather than guessed at here.
+    fn default_rules() -> Vec<Rule> {
+        vec![
+            Rule {
+                id: "REBASING",
+                matcher: |_token, capabilities, _bytecode| capabilities.is_rebasing,
+                issue: CompatibilityIssue {
+                    severity: IssueSeverity::Error,
+                    code: "REBASING".to_string(),
+                    title: "Rebasing Token Detected".to_string(),
+                    description: "This token rebases (adjusts balances without transfers). \
+                        When bridged via NTT in locking mode, locked tokens on the source \
+                        chain will desync from minted tokens on Solana, causing loss of \
+                        funds."
+                        .to_string(),
+                    recommendation: "Rebasing tokens are incompatible with NTT. Consider \
+                        wrapping the token in a non-rebasing wrapper (e.g. wstETH for \
+                        stETH) before bridging."
+                        .to_string(),
+                },
+            },
+            Rule {
+                id: "FEE_ON_TRANSFER",
+                matcher: |_token, _capabilities, bytecode| bytecode.has_fee_pattern,
+                issue: CompatibilityIssue {
+                    severity: IssueSeverity::Error,
+                    code: "FEE_ON_TRANSFER".to_string(),
+                    title: "Fee-on-Transfer Detected".to_string(),
+                    description: "Token appears to charge fees on transfers. This is \
+                        incompatible with NTT bridging as the fee mechanism cannot be \
+                        replicated across chains."
+                        .to_string(),
+                    recommendation: "Consider deploying a wrapper token without fees, or \
+                        use a different bridging solution."
+                        .to_string(),
+                },
+            },
+            Rule {
+                id: "PAUSABLE",
+                matcher: |_token, capabilities, _bytecode| capabilities.has_pause,
+                issue: CompatibilityIssue {
+                    severity: IssueSeverity::Warning,
+                    code: "PAUSABLE".to_string(),
+                    title: "Pausable Token".to_string(),
+                    description: "Token can be paused by owner. If paused during a bridge \
+                        transfer, funds could be locked."
+                        .to_string(),
+                    recommendation: "Ensure pause functionality won't interfere with \
+                        bridge operations. Consider governance controls."
+                        .to_string(),
+                },
+            },
+            Rule {
+                id: "BLACKLIST",
+                matcher: |_token, capabilities, _bytecode| capabilities.has_blacklist,
+                issue: CompatibilityIssue {
+                    severity: IssueSeverity::Warning,
+                    code: "BLACKLIST".to_string(),
+                    title: "Blacklist Functionality".to_string(),
+                    description: "Token has blacklist capability. Blacklisted addresses \
+                        cannot transfer tokens, which could affect bridge operations."
+                        .to_string(),
+                    recommendation: "Ensure NTT contracts are not blacklistable. Document \
+                        blacklist policy for users."
+                        .to_string(),
+                },
+            },
+            Rule {
+                id: "MINTABLE",
+                matcher: |_token, capabilities, _bytecode| capabilities.has_mint,
+                issue: CompatibilityIssue {
+                    severity: IssueSeverity::Info,
+                    code: "MINTABLE".to_string(),
+                    title: "Mintable Token".to_string(),
+                    description: "Token has mint capability on the source chain.".to_string(),
+                    recommendation: "Mint capability alone does not enable burning mode. \
+                        Burning mode requires burn capability so the NTT manager can burn \
+                        tokens."
+                        .to_string(),
+                },
+            },
+            Rule {
+                id: "BURNABLE",
+                matcher: |_token, capabilities, _bytecode| capabilities.has_burn,
+                issue: CompatibilityIssue {
+                    severity: IssueSeverity::Info,
+                    code: "BURNABLE".to_string(),
+                    title: "Burnable Token".to_string(),
+                    description: "Token supports burning, compatible with NTT burning mode."
+                        .to_string(),
+                    recommendation: "Burning mode is the preferred NTT configuration."
+                        .to_string(),
+                },
+            },
+            Rule {
+                id: "SELFDESTRUCT",
+                matcher: |_token, _capabilities, bytecode| bytecode.has_selfdestruct,
+                issue: CompatibilityIssue {
+                    severity: IssueSeverity::Warning,
+                    code: "SELFDESTRUCT".to_string(),
+                    title: "Self-destruct Capability".to_string(),
+                    description: "Contract contains selfdestruct opcode. If triggered, \
+                        bridged tokens could become worthless."
+                        .to_string(),
+                    recommendation: "Review contract for selfdestruct conditions. Ensure \
+                        it cannot be called maliciously."
+                        .to_string(),
+                },
+            },
+            Rule {
+                id: "APPROVE_RACE",
+                matcher: |_token, capabilities, _bytecode| {
+                    capabilities.has_unmitigated_approve_race
+                },
+                issue: CompatibilityIssue {
+                    severity: IssueSeverity::Warning,
+                    code: "APPROVE_RACE".to_string(),
+                    title: "Unmitigated Approve Race".to_string(),
+                    description: "Token exposes the standard approve(address,uint256) with \
+                        no increaseAllowance/decreaseAllowance alternative. Changing an \
+                        existing allowance is vulnerable to the classic front-running race \
+                        (spend the old allowance, then the new one)."
+                        .to_string(),
+                    recommendation: "Not an NTT-specific issue, but worth flagging to \
+                        integrators: set allowances to zero before changing them, or use a \
+                        permit-based flow instead."
+                        .to_string(),
+                },
+            },
+            Rule {
+                id: "TRANSFER_HOOK",
+                matcher: |_token, capabilities, _bytecode| capabilities.has_transfer_hook,
+                issue: CompatibilityIssue {
+                    severity: IssueSeverity::Error,
+                    code: "TRANSFER_HOOK".to_string(),
+                    title: "Transfer Hook Detected".to_string(),
+                    description: "Token calls out to a transfer hook (ERC-1820 registry \
+                        lookup, or exposes tokensToSend/tokensReceived itself). NTT's \
+                        locking mode calls transferFrom into the manager — a hook firing \
+                        mid-transfer lets the recipient re-enter the manager before the \
+                        lock completes, a classic reentrancy vector."
+                        .to_string(),
+                    recommendation: "Ensure the NTT manager follows checks-effects-\
+                        interactions or holds a reentrancy guard around the lock/burn \
+                        step, or bridge a non-hooked wrapper instead."
+                        .to_string(),
+                },
+            },
+        ]
+    }
+
+    /// Perform full compatibility analysis. `governance` is resolved upstream (it requires
+    /// live RPC calls the checker itself doesn't make — see
+    /// `EvmAnalyzer::get_governance_profile`), the same way `capabilities`/`bytecode`/
+    /// `access_control` already are.
     pub fn check(
+        &self,
         token: &TokenInfo,
         capabilities: &TokenCapabilities,
         bytecode: &BytecodeAnalysis,
+        access_control: &AccessControl,
+        governance: &GovernanceProfile,
     ) -> CompatibilityResult {
         let mut issues = Vec::new();
 
@@ -19,17 +216,30 @@ impl CompatibilityChecker {
         let (decimal_trimming_required, solana_decimals) =
             Self::check_decimals(token.decimals, &mut issues);
 
-        // Check rebasing — catastrophic for NTT bridging
-        Self::check_rebasing(capabilities, &mut issues);
+        // Run the data-driven rule set (rebasing, fee-on-transfer, pausable, blacklist,
+        // mint/burn info, selfdestruct, approve race, and any caller-registered rules)
+        for rule in &self.rules {
+            if (rule.matcher)(token, capabilities, bytecode) {
+                issues.push(rule.issue.clone());
+            }
+        }
+
+        // Proxy upgradeability needs the resolved proxy type in its description, so it
+        // can't be a static Rule issue — stays a dedicated check.
+        Self::check_proxy(bytecode, &mut issues);
 
-        // Check token features
-        Self::check_features(capabilities, bytecode, &mut issues);
+        // Determine recommended mode. Burning mode additionally needs mint authority to
+        // be externally reassignable to the NTT manager — without that, fall back to
+        // locking mode even if the token is burnable.
+        let recommended_mode = Self::determine_mode(capabilities, access_control);
 
-        // Check bytecode concerns
-        Self::check_bytecode(bytecode, &mut issues);
+        // Classify supply model and flag it if it undermines the recommended mode.
+        let supply_model = Self::determine_supply_model(capabilities, bytecode, access_control);
+        Self::check_supply_model(supply_model, recommended_mode, &mut issues);
 
-        // Determine recommended mode
-        let recommended_mode = Self::determine_mode(capabilities);
+        // Who holds the powers above matters as much as whether they exist — flag
+        // centralization risk proportional to how accountable the controller is.
+        Self::check_governance(governance, &mut issues);
 
         // Overall compatibility
         let is_compatible = !issues.iter().any(|i| i.severity == IssueSeverity::Error);
@@ -40,6 +250,70 @@ impl CompatibilityChecker {
             issues,
             decimal_trimming_required,
             solana_decimals,
+            supply_model,
+            governance: governance.clone(),
+        }
+    }
+
+    /// Checks NTT/Solana migration compatibility for an ERC-721 collection. Unlike
+    /// `check`, there's no `NttMode`/decimals/supply-model to determine — every item is
+    /// unique and non-fungible — so this just flags the things that make a faithful
+    /// Metaplex re-creation harder: metadata that can't be mirrored, or an unbounded item
+    /// count that a `CollectionDetails::V1 { size }` can't be sized for up front.
+    pub fn check_nft(&self, collection: &NftCollectionInfo) -> NftCompatibilityResult {
+        let mut issues = Vec::new();
+
+        match &collection.base_uri {
+            Some(uri) if uri.starts_with("data:") => {
+                issues.push(CompatibilityIssue {
+                    severity: IssueSeverity::Error,
+                    code: "ONCHAIN_RENDERING".to_string(),
+                    title: "On-Chain/Data-URI Metadata".to_string(),
+                    description: "This collection's metadata is a data: URI rather than a \
+                        fetchable location, so it's generated on-chain per token rather than \
+                        stored at a fixed base URI. A single Metaplex collection URI can't \
+                        mirror that."
+                        .to_string(),
+                    recommendation: "Mint each item's metadata individually on Solana instead \
+                        of assuming a shared base URI."
+                        .to_string(),
+                });
+            }
+            None => {
+                issues.push(CompatibilityIssue {
+                    severity: IssueSeverity::Warning,
+                    code: "NO_BASE_URI".to_string(),
+                    title: "No Base URI Found".to_string(),
+                    description: "Neither baseURI() nor tokenURI(1) returned a usable base \
+                        path, so the collection's metadata location couldn't be inferred."
+                        .to_string(),
+                    recommendation: "Confirm the collection's metadata host manually before \
+                        minting, or pass it explicitly."
+                        .to_string(),
+                });
+            }
+            _ => {}
+        }
+
+        if collection.total_supply.is_none() {
+            issues.push(CompatibilityIssue {
+                severity: IssueSeverity::Warning,
+                code: "UNBOUNDED_ENUMERATION".to_string(),
+                title: "Collection Size Unknown".to_string(),
+                description: "This collection doesn't implement ERC721Enumerable, so its \
+                    total item count can't be read on-chain."
+                    .to_string(),
+                recommendation: "Pass the collection size explicitly so the Solana side can \
+                    be created with a matching CollectionDetails size."
+                    .to_string(),
+            });
+        }
+
+        let is_compatible = !issues.iter().any(|i| i.severity == IssueSeverity::Error);
+
+        NftCompatibilityResult {
+            is_compatible,
+            issues,
         }
     }
 
@@ -70,146 +344,171 @@ impl CompatibilityChecker {
         }
     }
 
-    /// Rebasing tokens change balances without transfers — locked tokens desync from minted tokens
-    fn check_rebasing(capabilities: &TokenCapabilities, issues: &mut Vec<CompatibilityIssue>) {
-        if capabilities.is_rebasing {
+    /// Check proxy upgradeability. Kept separate from `default_rules` since the issue
+    /// description embeds the resolved `proxy_type`, not a static string.
+    fn check_proxy(bytecode: &BytecodeAnalysis, issues: &mut Vec<CompatibilityIssue>) {
+        if bytecode.is_proxy {
             issues.push(CompatibilityIssue {
-                severity: IssueSeverity::Error,
-                code: "REBASING".to_string(),
-                title: "Rebasing Token Detected".to_string(),
-                description: "This token rebases (adjusts balances without transfers). \
-                    When bridged via NTT in locking mode, locked tokens on the source chain \
-                    will desync from minted tokens on Solana, causing loss of funds."
-                    .to_string(),
-                recommendation: "Rebasing tokens are incompatible with NTT. Consider \
-                    wrapping the token in a non-rebasing wrapper (e.g. wstETH for stETH) \
-                    before bridging."
+                severity: IssueSeverity::Info,
+                code: "PROXY".to_string(),
+                title: "Upgradeable Proxy".to_string(),
+                description: format!(
+                    "Contract is an upgradeable proxy ({:?}). \
+                     Implementation can change over time.",
+                    bytecode.proxy_type
+                ),
+                recommendation: "Monitor for upgrades. NTT integration should be \
+                    re-verified after any implementation changes."
                     .to_string(),
             });
         }
     }
 
-    /// Check token feature compatibility
-    fn check_features(
+    /// Determine recommended NTT mode based on token capabilities. Burning mode requires
+    /// burn capability on the source chain so the NTT manager can burn tokens when
+    /// bridging, *and* a way to hand the manager mint authority (an owner or role admin)
+    /// so it can mint the corresponding supply back. A burnable token with no externally
+    /// controllable mint authority (e.g. a hardcoded minter) falls back to locking mode.
+    fn determine_mode(capabilities: &TokenCapabilities, access_control: &AccessControl) -> NttMode {
+        if capabilities.has_burn && access_control.mint_authority_controllable(capabilities) {
+            NttMode::Burning
+        } else {
+            // Locking mode: lock on source, mint on destination
+            NttMode::Locking
+        }
+    }
+
+    /// Classify how the source token's total supply can move. No externally controllable
+    /// mint authority (no mint at all, or a mint gated behind no owner/role admin, i.e.
+    /// effectively renounced) means the supply is fixed regardless of `has_mint`.
+    fn determine_supply_model(
         capabilities: &TokenCapabilities,
         bytecode: &BytecodeAnalysis,
+        access_control: &AccessControl,
+    ) -> SupplyModel {
+        if !access_control.mint_authority_controllable(capabilities) {
+            SupplyModel::Fixed
+        } else if bytecode.has_cap {
+            SupplyModel::Capped
+        } else {
+            SupplyModel::Unlimited
+        }
+    }
+
+    /// Locking mode holds the source supply 1:1 against what's minted on Solana — an
+    /// owner who can mint without bound breaks that invariant outright (Error); a
+    /// `cap()`-bounded mint only weakens it (Warning), since the locked/minted totals can
+    /// still diverge up to the cap.
+    fn check_supply_model(
+        supply_model: SupplyModel,
+        recommended_mode: NttMode,
         issues: &mut Vec<CompatibilityIssue>,
     ) {
-        // Fee-on-transfer is problematic
-        if bytecode.has_fee_pattern {
-            issues.push(CompatibilityIssue {
+        if recommended_mode != NttMode::Locking {
+            return;
+        }
+
+        match supply_model {
+            SupplyModel::Unlimited => issues.push(CompatibilityIssue {
                 severity: IssueSeverity::Error,
-                code: "FEE_ON_TRANSFER".to_string(),
-                title: "Fee-on-Transfer Detected".to_string(),
-                description: "Token appears to charge fees on transfers. \
-                    This is incompatible with NTT bridging as the fee mechanism \
-                    cannot be replicated across chains."
+                code: "UNLIMITED_SUPPLY_LOCKING".to_string(),
+                title: "Unbounded Mint in Locking Mode".to_string(),
+                description: "Token is mintable with no on-chain cap by an owner or role \
+                    admin who hasn't renounced that authority. In locking mode, locked \
+                    tokens on the source chain are meant to back minted tokens on Solana \
+                    1:1 — an unbounded mint breaks that invariant."
                     .to_string(),
-                recommendation: "Consider deploying a wrapper token without fees, \
-                    or use a different bridging solution."
+                recommendation: "Confirm the owner intends to renounce mint authority, or \
+                    treat this token's locking-mode backing as provisional until they do."
                     .to_string(),
-            });
-        }
-
-        // Pausable tokens need consideration
-        if capabilities.has_pause {
-            issues.push(CompatibilityIssue {
+            }),
+            SupplyModel::Capped => issues.push(CompatibilityIssue {
                 severity: IssueSeverity::Warning,
-                code: "PAUSABLE".to_string(),
-                title: "Pausable Token".to_string(),
-                description: "Token can be paused by owner. If paused during \
-                    a bridge transfer, funds could be locked."
+                code: "CAPPED_SUPPLY_LOCKING".to_string(),
+                title: "Capped Mint in Locking Mode".to_string(),
+                description: "Token is mintable up to an on-chain cap by an owner or role \
+                    admin. In locking mode, minting toward that cap still grows the source \
+                    supply independently of what's locked."
                     .to_string(),
-                recommendation: "Ensure pause functionality won't interfere with \
-                    bridge operations. Consider governance controls."
-                    .to_string(),
-            });
+                recommendation: "Confirm the cap and current supply before relying on \
+                    locking mode long-term.".to_string(),
+            }),
+            SupplyModel::Fixed => {}
         }
+    }
 
-        // Blacklist can prevent transfers
-        if capabilities.has_blacklist {
-            issues.push(CompatibilityIssue {
-                severity: IssueSeverity::Warning,
-                code: "BLACKLIST".to_string(),
-                title: "Blacklist Functionality".to_string(),
-                description: "Token has blacklist capability. Blacklisted addresses \
-                    cannot transfer tokens, which could affect bridge operations."
-                    .to_string(),
-                recommendation: "Ensure NTT contracts are not blacklistable. \
-                    Document blacklist policy for users."
-                    .to_string(),
-            });
+    /// Flag who holds the controlled powers (mint/pause/upgrade), escalating by how
+    /// accountable that controller is: a timelock gives the community a warning window
+    /// (Info), a multisig spreads trust across signers (Warning), and an unverified EOA
+    /// that simultaneously controls mint, upgrade, *and* pause is the single point of
+    /// failure this whole subsystem exists to surface (Error) — any one of those powers
+    /// alone is already covered by the `MINTABLE`/`PAUSABLE`/`PROXY` issues above.
+    fn check_governance(governance: &GovernanceProfile, issues: &mut Vec<CompatibilityIssue>) {
+        if governance.controller.is_none() {
+            return;
         }
 
-        // Mint capability noted but not sufficient alone for burning mode
-        if capabilities.has_mint {
-            issues.push(CompatibilityIssue {
-                severity: IssueSeverity::Info,
-                code: "MINTABLE".to_string(),
-                title: "Mintable Token".to_string(),
-                description: "Token has mint capability on the source chain.".to_string(),
-                recommendation: "Mint capability alone does not enable burning mode. \
-                    Burning mode requires burn capability so the NTT manager can burn tokens."
-                    .to_string(),
-            });
+        let controls_any =
+            governance.controls_mint || governance.controls_pause || governance.controls_upgrade;
+        if !controls_any {
+            return;
         }
 
-        // Burn capability
-        if capabilities.has_burn {
-            issues.push(CompatibilityIssue {
+        match governance.controller_type {
+            ControllerType::Timelock => issues.push(CompatibilityIssue {
                 severity: IssueSeverity::Info,
-                code: "BURNABLE".to_string(),
-                title: "Burnable Token".to_string(),
-                description: "Token supports burning, compatible with NTT burning mode."
+                code: "GOVERNANCE_TIMELOCK".to_string(),
+                title: "Privileged Powers Held by a Timelock".to_string(),
+                description: "Mint, pause, and/or upgrade authority is held by a timelock \
+                    contract, giving holders a warning window before changes take effect."
                     .to_string(),
-                recommendation: "Burning mode is the preferred NTT configuration.".to_string(),
-            });
-        }
-    }
-
-    /// Check bytecode-related concerns
-    fn check_bytecode(bytecode: &BytecodeAnalysis, issues: &mut Vec<CompatibilityIssue>) {
-        if bytecode.has_selfdestruct {
-            issues.push(CompatibilityIssue {
-                severity: IssueSeverity::Warning,
-                code: "SELFDESTRUCT".to_string(),
-                title: "Self-destruct Capability".to_string(),
-                description: "Contract contains selfdestruct opcode. If triggered, \
-                    bridged tokens could become worthless."
+                recommendation: "Confirm the timelock delay is long enough for integrators \
+                    to react before a change lands."
                     .to_string(),
-                recommendation: "Review contract for selfdestruct conditions. \
-                    Ensure it cannot be called maliciously."
+            }),
+            ControllerType::Multisig => issues.push(CompatibilityIssue {
+                severity: IssueSeverity::Warning,
+                code: "GOVERNANCE_MULTISIG".to_string(),
+                title: "Privileged Powers Held by a Multisig".to_string(),
+                description: "Mint, pause, and/or upgrade authority is held by a multisig \
+                    contract rather than a single signer, spreading out trust but still \
+                    concentrating real power in a small group."
                     .to_string(),
-            });
-        }
-
-        if bytecode.is_proxy {
-            issues.push(CompatibilityIssue {
-                severity: IssueSeverity::Info,
-                code: "PROXY".to_string(),
-                title: "Upgradeable Proxy".to_string(),
-                description: format!(
-                    "Contract is an upgradeable proxy ({:?}). \
-                     Implementation can change over time.",
-                    bytecode.proxy_type
-                ),
-                recommendation: "Monitor for upgrades. NTT integration should be \
-                    re-verified after any implementation changes."
+                recommendation: "Confirm the signer set and threshold before relying on \
+                    this token's bridged supply."
                     .to_string(),
-            });
+            }),
+            ControllerType::Eoa
+                if governance.controls_mint
+                    && governance.controls_upgrade
+                    && governance.controls_pause =>
+            {
+                issues.push(CompatibilityIssue {
+                    severity: IssueSeverity::Error,
+                    code: "GOVERNANCE_EOA_FULL_CONTROL".to_string(),
+                    title: "Unverified Single Signer Controls Mint, Upgrade, and Pause".to_string(),
+                    description: "A single externally-owned account simultaneously controls \
+                        minting, contract upgrades, and pausing, with no timelock or \
+                        multisig in between. This is a far bigger bridging risk than any \
+                        one of those powers alone — a single compromised or malicious key \
+                        can mint unbounded supply, rewrite the contract's logic, and freeze \
+                        transfers."
+                        .to_string(),
+                    recommendation: "Do not bridge this token until privileged powers move \
+                        behind a multisig or timelock, or confirm the key is otherwise \
+                        verifiably secured (e.g. hardware-wallet-backed, with documented \
+                        operational controls)."
+                        .to_string(),
+                })
+            }
+            ControllerType::Eoa | ControllerType::Unknown => {}
         }
     }
+}
 
-    /// Determine recommended NTT mode based on token capabilities.
-    /// Burning mode requires burn capability on the source chain so the
-    /// NTT manager can burn tokens when bridging. Mint-only is not enough.
-    fn determine_mode(capabilities: &TokenCapabilities) -> NttMode {
-        if capabilities.has_burn {
-            NttMode::Burning
-        } else {
-            // Locking mode: lock on source, mint on destination
-            NttMode::Locking
-        }
+impl Default for CompatibilityChecker {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -229,10 +528,12 @@ mod tests {
             total_supply: "1000000".to_string(),
         };
 
-        let result = CompatibilityChecker::check(
+        let result = CompatibilityChecker::new().check(
             &token,
             &TokenCapabilities::default(),
             &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &GovernanceProfile::default(),
         );
 
         assert!(result.decimal_trimming_required);
@@ -256,7 +557,13 @@ mod tests {
             ..Default::default()
         };
 
-        let result = CompatibilityChecker::check(&token, &TokenCapabilities::default(), &bytecode);
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &TokenCapabilities::default(),
+            &bytecode,
+            &AccessControl::default(),
+            &GovernanceProfile::default(),
+        );
 
         assert!(!result.is_compatible);
         assert!(result
@@ -271,10 +578,15 @@ mod tests {
     fn test_determine_mode_burning() {
         let caps = TokenCapabilities {
             has_burn: true,
+            has_mint: true,
+            ..Default::default()
+        };
+        let access = AccessControl {
+            has_owner: true,
             ..Default::default()
         };
         assert_eq!(
-            CompatibilityChecker::determine_mode(&caps),
+            CompatibilityChecker::determine_mode(&caps, &access),
             NttMode::Burning
         );
     }
@@ -282,7 +594,24 @@ mod tests {
     #[test]
     fn test_determine_mode_locking() {
         assert_eq!(
-            CompatibilityChecker::determine_mode(&TokenCapabilities::default()),
+            CompatibilityChecker::determine_mode(
+                &TokenCapabilities::default(),
+                &AccessControl::default()
+            ),
+            NttMode::Locking
+        );
+    }
+
+    #[test]
+    fn test_determine_mode_falls_back_to_locking_without_controllable_mint_authority() {
+        // Burnable, but no owner/role admin to hand mint authority to the NTT manager
+        let caps = TokenCapabilities {
+            has_burn: true,
+            has_mint: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            CompatibilityChecker::determine_mode(&caps, &AccessControl::default()),
             NttMode::Locking
         );
     }
@@ -299,10 +628,12 @@ mod tests {
             decimals: 6,
             total_supply: "1000000".to_string(),
         };
-        let result = CompatibilityChecker::check(
+        let result = CompatibilityChecker::new().check(
             &token,
             &TokenCapabilities::default(),
             &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &GovernanceProfile::default(),
         );
         assert!(!result.decimal_trimming_required);
         assert_eq!(result.solana_decimals, 6);
@@ -318,10 +649,12 @@ mod tests {
             decimals: 9,
             total_supply: "1000000".to_string(),
         };
-        let result = CompatibilityChecker::check(
+        let result = CompatibilityChecker::new().check(
             &token,
             &TokenCapabilities::default(),
             &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &GovernanceProfile::default(),
         );
         assert!(result.decimal_trimming_required);
         assert_eq!(result.solana_decimals, 8);
@@ -343,7 +676,13 @@ mod tests {
             is_rebasing: true,
             ..Default::default()
         };
-        let result = CompatibilityChecker::check(&token, &caps, &BytecodeAnalysis::default());
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &caps,
+            &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &GovernanceProfile::default(),
+        );
         assert!(!result.is_compatible);
         assert!(result
             .issues
@@ -368,12 +707,148 @@ mod tests {
             has_blacklist: true,
             ..Default::default()
         };
-        let result = CompatibilityChecker::check(&token, &caps, &BytecodeAnalysis::default());
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &caps,
+            &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &GovernanceProfile::default(),
+        );
         assert!(result.is_compatible); // warnings don't block
         assert!(result.issues.iter().any(|i| i.code == "PAUSABLE"));
         assert!(result.issues.iter().any(|i| i.code == "BLACKLIST"));
     }
 
+    // ── Supply model ────────────────────────────────────────
+
+    #[test]
+    fn test_supply_model_fixed_no_owner_no_issue() {
+        let token = TokenInfo {
+            address: "0x0".to_string(),
+            chain: Chain::Ethereum,
+            name: "Test".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: "1000000".to_string(),
+        };
+        let caps = TokenCapabilities {
+            has_mint: true,
+            ..Default::default()
+        };
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &caps,
+            &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &GovernanceProfile::default(),
+        );
+        assert_eq!(result.supply_model, SupplyModel::Fixed);
+        assert!(!result.issues.iter().any(|i| i.code.contains("SUPPLY_LOCKING")));
+    }
+
+    #[test]
+    fn test_supply_model_unlimited_locking_mode_errors() {
+        let token = TokenInfo {
+            address: "0x0".to_string(),
+            chain: Chain::Ethereum,
+            name: "Test".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: "1000000".to_string(),
+        };
+        let caps = TokenCapabilities {
+            has_mint: true,
+            ..Default::default()
+        };
+        let access = AccessControl {
+            has_owner: true,
+            ..Default::default()
+        };
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &caps,
+            &BytecodeAnalysis::default(),
+            &access,
+            &GovernanceProfile::default(),
+        );
+        assert_eq!(result.supply_model, SupplyModel::Unlimited);
+        assert!(!result.is_compatible);
+        assert!(result
+            .issues
+            .iter()
+            .any(|i| i.code == "UNLIMITED_SUPPLY_LOCKING" && i.severity == IssueSeverity::Error));
+    }
+
+    #[test]
+    fn test_supply_model_capped_locking_mode_warns() {
+        let token = TokenInfo {
+            address: "0x0".to_string(),
+            chain: Chain::Ethereum,
+            name: "Test".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: "1000000".to_string(),
+        };
+        let caps = TokenCapabilities {
+            has_mint: true,
+            ..Default::default()
+        };
+        let access = AccessControl {
+            has_owner: true,
+            ..Default::default()
+        };
+        let bytecode = BytecodeAnalysis {
+            has_cap: true,
+            ..Default::default()
+        };
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &caps,
+            &bytecode,
+            &access,
+            &GovernanceProfile::default(),
+        );
+        assert_eq!(result.supply_model, SupplyModel::Capped);
+        assert!(result.is_compatible);
+        assert!(result
+            .issues
+            .iter()
+            .any(|i| i.code == "CAPPED_SUPPLY_LOCKING" && i.severity == IssueSeverity::Warning));
+    }
+
+    #[test]
+    fn test_supply_model_unlimited_but_burning_mode_no_issue() {
+        // Unlimited mint, but burning mode is recommended — the locking-mode invariant
+        // doesn't apply, so no supply-model issue should fire.
+        let token = TokenInfo {
+            address: "0x0".to_string(),
+            chain: Chain::Ethereum,
+            name: "Test".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: "1000000".to_string(),
+        };
+        let caps = TokenCapabilities {
+            has_mint: true,
+            has_burn: true,
+            ..Default::default()
+        };
+        let access = AccessControl {
+            has_owner: true,
+            ..Default::default()
+        };
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &caps,
+            &BytecodeAnalysis::default(),
+            &access,
+            &GovernanceProfile::default(),
+        );
+        assert_eq!(result.recommended_mode, NttMode::Burning);
+        assert_eq!(result.supply_model, SupplyModel::Unlimited);
+        assert!(!result.issues.iter().any(|i| i.code.contains("SUPPLY_LOCKING")));
+    }
+
     // ── Burnable token produces Info issue ──────────────────
 
     #[test]
@@ -390,11 +865,279 @@ mod tests {
             has_burn: true,
             ..Default::default()
         };
-        let result = CompatibilityChecker::check(&token, &caps, &BytecodeAnalysis::default());
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &caps,
+            &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &GovernanceProfile::default(),
+        );
         assert!(result.is_compatible);
         assert!(result
             .issues
             .iter()
             .any(|i| i.code == "BURNABLE" && i.severity == IssueSeverity::Info));
     }
+
+    // ── Rule engine extensibility ────────────────────────────
+
+    #[test]
+    fn test_with_rules_runs_default_rules_too() {
+        let token = TokenInfo {
+            address: "0x0".to_string(),
+            chain: Chain::Ethereum,
+            name: "Test".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: "1000000".to_string(),
+        };
+        let caps = TokenCapabilities {
+            has_burn: true,
+            ..Default::default()
+        };
+        let result = CompatibilityChecker::with_rules(vec![]).check(
+            &token,
+            &caps,
+            &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &GovernanceProfile::default(),
+        );
+        assert!(result.issues.iter().any(|i| i.code == "BURNABLE"));
+    }
+
+    #[test]
+    fn test_with_rules_adds_custom_rule() {
+        let token = TokenInfo {
+            address: "0x0".to_string(),
+            chain: Chain::Ethereum,
+            name: "Test".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: "1000000".to_string(),
+        };
+        let custom_rule = Rule {
+            id: "ALWAYS_FLAGGED",
+            matcher: |_token, _capabilities, _bytecode| true,
+            issue: CompatibilityIssue {
+                severity: IssueSeverity::Info,
+                code: "CUSTOM".to_string(),
+                title: "Custom Org Rule".to_string(),
+                description: "Matched a custom rule.".to_string(),
+                recommendation: "N/A".to_string(),
+            },
+        };
+        let result = CompatibilityChecker::with_rules(vec![custom_rule]).check(
+            &token,
+            &TokenCapabilities::default(),
+            &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &GovernanceProfile::default(),
+        );
+        assert!(result.issues.iter().any(|i| i.code == "CUSTOM"));
+    }
+
+    #[test]
+    fn test_approve_race_warning() {
+        let token = TokenInfo {
+            address: "0x0".to_string(),
+            chain: Chain::Ethereum,
+            name: "Test".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: "1000000".to_string(),
+        };
+        let caps = TokenCapabilities {
+            has_unmitigated_approve_race: true,
+            ..Default::default()
+        };
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &caps,
+            &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &GovernanceProfile::default(),
+        );
+        assert!(result.is_compatible); // warning, not blocking
+        assert!(result
+            .issues
+            .iter()
+            .any(|i| i.code == "APPROVE_RACE" && i.severity == IssueSeverity::Warning));
+    }
+
+    #[test]
+    fn test_transfer_hook_incompatible() {
+        let token = TokenInfo {
+            address: "0x0".to_string(),
+            chain: Chain::Ethereum,
+            name: "Test".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: "1000000".to_string(),
+        };
+        let caps = TokenCapabilities {
+            has_transfer_hook: true,
+            ..Default::default()
+        };
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &caps,
+            &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &GovernanceProfile::default(),
+        );
+        assert!(!result.is_compatible);
+        assert!(result
+            .issues
+            .iter()
+            .any(|i| i.code == "TRANSFER_HOOK" && i.severity == IssueSeverity::Error));
+    }
+
+    // ── Governance / centralization ─────────────────────────
+
+    #[test]
+    fn test_governance_eoa_full_control_errors() {
+        let token = TokenInfo {
+            address: "0x0".to_string(),
+            chain: Chain::Ethereum,
+            name: "Test".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: "1000000".to_string(),
+        };
+        let governance = GovernanceProfile {
+            controller: Some("0xdead".to_string()),
+            controller_type: ControllerType::Eoa,
+            controls_mint: true,
+            controls_pause: true,
+            controls_upgrade: true,
+        };
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &TokenCapabilities::default(),
+            &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &governance,
+        );
+        assert!(!result.is_compatible);
+        assert!(result
+            .issues
+            .iter()
+            .any(|i| i.code == "GOVERNANCE_EOA_FULL_CONTROL" && i.severity == IssueSeverity::Error));
+    }
+
+    #[test]
+    fn test_governance_eoa_partial_control_no_issue() {
+        // An EOA controlling only mint (not upgrade/pause too) isn't the triple-threat case
+        let token = TokenInfo {
+            address: "0x0".to_string(),
+            chain: Chain::Ethereum,
+            name: "Test".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: "1000000".to_string(),
+        };
+        let governance = GovernanceProfile {
+            controller: Some("0xdead".to_string()),
+            controller_type: ControllerType::Eoa,
+            controls_mint: true,
+            controls_pause: false,
+            controls_upgrade: false,
+        };
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &TokenCapabilities::default(),
+            &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &governance,
+        );
+        assert!(!result
+            .issues
+            .iter()
+            .any(|i| i.code.starts_with("GOVERNANCE_")));
+    }
+
+    #[test]
+    fn test_governance_multisig_warns() {
+        let token = TokenInfo {
+            address: "0x0".to_string(),
+            chain: Chain::Ethereum,
+            name: "Test".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: "1000000".to_string(),
+        };
+        let governance = GovernanceProfile {
+            controller: Some("0xsafe".to_string()),
+            controller_type: ControllerType::Multisig,
+            controls_mint: true,
+            controls_pause: false,
+            controls_upgrade: false,
+        };
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &TokenCapabilities::default(),
+            &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &governance,
+        );
+        assert!(result.is_compatible); // warning, not blocking
+        assert!(result
+            .issues
+            .iter()
+            .any(|i| i.code == "GOVERNANCE_MULTISIG" && i.severity == IssueSeverity::Warning));
+    }
+
+    #[test]
+    fn test_governance_timelock_info_only() {
+        let token = TokenInfo {
+            address: "0x0".to_string(),
+            chain: Chain::Ethereum,
+            name: "Test".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: "1000000".to_string(),
+        };
+        let governance = GovernanceProfile {
+            controller: Some("0xtimelock".to_string()),
+            controller_type: ControllerType::Timelock,
+            controls_mint: true,
+            controls_pause: true,
+            controls_upgrade: true,
+        };
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &TokenCapabilities::default(),
+            &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &governance,
+        );
+        assert!(result.is_compatible);
+        assert!(result
+            .issues
+            .iter()
+            .any(|i| i.code == "GOVERNANCE_TIMELOCK" && i.severity == IssueSeverity::Info));
+    }
+
+    #[test]
+    fn test_governance_no_controller_resolved_no_issue() {
+        let token = TokenInfo {
+            address: "0x0".to_string(),
+            chain: Chain::Ethereum,
+            name: "Test".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: "1000000".to_string(),
+        };
+        let result = CompatibilityChecker::new().check(
+            &token,
+            &TokenCapabilities::default(),
+            &BytecodeAnalysis::default(),
+            &AccessControl::default(),
+            &GovernanceProfile::default(),
+        );
+        assert!(!result
+            .issues
+            .iter()
+            .any(|i| i.code.starts_with("GOVERNANCE_")));
+    }
 }
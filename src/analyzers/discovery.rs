@@ -1,15 +1,48 @@
-use anyhow::Result;
+use alloy::primitives::Address;
+use anyhow::{Context, Result};
 use reqwest::Client;
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::time::Duration;
 
+/// On-disk cache of resolved CoinGecko coin id -> Ethereum address, so a repeat discovery
+/// run doesn't re-fetch the per-coin detail endpoint for tokens it has already resolved
+const ADDRESS_CACHE_PATH: &str = "~/.cache/daybreak/discovery-addresses.json";
+
+fn expand_path(path: &str) -> PathBuf {
+    match std::env::var("HOME") {
+        Ok(home) => PathBuf::from(path.replacen('~', &home, 1)),
+        Err(_) => PathBuf::from(path),
+    }
+}
+
+fn load_address_cache() -> HashMap<String, String> {
+    std::fs::read_to_string(expand_path(ADDRESS_CACHE_PATH))
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn save_address_cache(cache: &HashMap<String, String>) {
+    let path = expand_path(ADDRESS_CACHE_PATH);
+    if let Some(parent) = path.parent() {
+        if std::fs::create_dir_all(parent).is_err() {
+            return;
+        }
+    }
+    if let Ok(json) = serde_json::to_string_pretty(cache) {
+        let _ = std::fs::write(path, json);
+    }
+}
+
 /// A discovered token candidate for migration
 #[derive(Debug, Clone)]
 pub struct DiscoveredToken {
     pub symbol: String,
     #[allow(dead_code)]
     pub name: String,
-    pub address: String,
+    pub address: Address,
     pub market_cap_rank: Option<u32>,
 }
 
@@ -31,6 +64,8 @@ struct CoinGeckoDetail {
 /// Discovers ERC-20 migration candidates dynamically
 pub struct TokenDiscovery {
     client: Client,
+    /// When set, `discover` never hits the network and serves the curated list directly
+    offline: bool,
 }
 
 impl TokenDiscovery {
@@ -40,11 +75,24 @@ impl TokenDiscovery {
                 .timeout(Duration::from_secs(15))
                 .build()
                 .unwrap_or_default(),
+            offline: false,
+        }
+    }
+
+    /// Construct a discovery instance that never calls CoinGecko, for use without
+    /// network access or to avoid its rate limits
+    pub fn new_offline() -> Self {
+        Self {
+            offline: true,
+            ..Self::new()
         }
     }
 
     /// Discover top ERC-20 tokens, falling back to curated list
     pub async fn discover(&self, limit: usize) -> Vec<DiscoveredToken> {
+        if self.offline {
+            return self.curated_fallback(limit);
+        }
         match self.discover_from_api(limit).await {
             Ok(tokens) if !tokens.is_empty() => tokens,
             _ => self.curated_fallback(limit),
@@ -72,10 +120,25 @@ impl TokenDiscovery {
 
         let items: Vec<CoinGeckoMarketItem> = resp.json().await?;
 
-        // For each token, try to get the Ethereum contract address
+        // For each token, try to get the Ethereum contract address, preferring a
+        // previously-resolved address over a fresh CoinGecko detail lookup
+        let mut address_cache = load_address_cache();
+        let mut cache_dirty = false;
         let mut tokens = Vec::new();
         for item in items.iter().take(limit) {
-            if let Ok(Some(address)) = self.get_eth_address(&item.id).await {
+            let address = if let Some(cached) = address_cache.get(&item.id) {
+                Address::parse_checksummed(cached, None).ok()
+            } else if let Ok(Some(address)) = self.get_eth_address(&item.id).await {
+                address_cache.insert(item.id.clone(), address.to_checksum(None));
+                cache_dirty = true;
+                tokio::time::sleep(Duration::from_millis(250)).await;
+                Some(address)
+            } else {
+                tokio::time::sleep(Duration::from_millis(250)).await;
+                None
+            };
+
+            if let Some(address) = address {
                 tokens.push(DiscoveredToken {
                     symbol: item.symbol.to_uppercase(),
                     name: item.name.clone(),
@@ -83,15 +146,18 @@ impl TokenDiscovery {
                     market_cap_rank: item.market_cap_rank,
                 });
             }
-            // Brief pause to respect CoinGecko rate limits
-            tokio::time::sleep(Duration::from_millis(250)).await;
+        }
+
+        if cache_dirty {
+            save_address_cache(&address_cache);
         }
 
         Ok(tokens)
     }
 
-    /// Get Ethereum contract address for a CoinGecko coin
-    async fn get_eth_address(&self, coin_id: &str) -> Result<Option<String>> {
+    /// Get Ethereum contract address for a CoinGecko coin. Returns `None` if CoinGecko
+    /// doesn't list one, or if it's malformed / fails EIP-55 checksum validation.
+    async fn get_eth_address(&self, coin_id: &str) -> Result<Option<Address>> {
         let url = format!(
             "https://api.coingecko.com/api/v3/coins/{}?localization=false&tickers=false&community_data=false&developer_data=false",
             coin_id
@@ -112,8 +178,33 @@ impl TokenDiscovery {
         Ok(detail
             .platforms
             .get("ethereum")
-            .and_then(|v| v.clone())
-            .filter(|addr| addr.starts_with("0x") && addr.len() == 42))
+            .and_then(|v| v.as_deref())
+            .and_then(|addr| Address::parse_checksummed(addr, None).ok()))
+    }
+
+    /// Fetch the current USD price for a CoinGecko coin id (e.g. "ethereum", "solana")
+    pub async fn get_price_usd(&self, coin_id: &str) -> Result<f64> {
+        let url = format!(
+            "https://api.coingecko.com/api/v3/simple/price?ids={}&vs_currencies=usd",
+            coin_id
+        );
+
+        let resp = self
+            .client
+            .get(&url)
+            .header("Accept", "application/json")
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            anyhow::bail!("CoinGecko API returned {}", resp.status());
+        }
+
+        let body: HashMap<String, HashMap<String, f64>> = resp.json().await?;
+        body.get(coin_id)
+            .and_then(|prices| prices.get("usd"))
+            .copied()
+            .with_context(|| format!("CoinGecko response missing USD price for {}", coin_id))
     }
 
     /// Curated list of high-value ERC-20 migration candidates
@@ -326,7 +417,8 @@ impl TokenDiscovery {
             .map(|(i, (symbol, name, address))| DiscoveredToken {
                 symbol: symbol.to_string(),
                 name: name.to_string(),
-                address: address.to_string(),
+                address: Address::parse_checksummed(address, None)
+                    .unwrap_or_else(|_| panic!("curated address for {symbol} is not a valid EIP-55 address: {address}")),
                 market_cap_rank: Some(i as u32 + 1),
             })
             .collect()
@@ -364,27 +456,19 @@ mod tests {
         assert_eq!(tokens[0].symbol, "ONDO");
         assert_eq!(
             tokens[0].address,
-            "0xfAbA6f8e4a5E8Ab82F62fe7C39859FA577269BE3"
+            Address::parse_checksummed("0xfAbA6f8e4a5E8Ab82F62fe7C39859FA577269BE3", None)
+                .unwrap()
         );
     }
 
+    /// Every curated address is constructed via `Address::parse_checksummed`, which panics
+    /// on a bad checksum — this just exercises the full list so a future typo is caught here
+    /// instead of at first runtime use.
     #[test]
-    fn test_curated_fallback_has_valid_addresses() {
+    fn test_curated_fallback_addresses_are_valid_eip55() {
         let discovery = TokenDiscovery::new();
-        let tokens = discovery.curated_fallback(45);
-        for token in &tokens {
-            assert!(
-                token.address.starts_with("0x"),
-                "{} address should start with 0x",
-                token.symbol
-            );
-            assert_eq!(
-                token.address.len(),
-                42,
-                "{} address should be 42 chars",
-                token.symbol
-            );
-        }
+        let tokens = discovery.curated_fallback(1000);
+        assert!(tokens.len() > 40);
     }
 
     #[test]
@@ -397,6 +481,14 @@ mod tests {
         assert!(symbols.contains(&"WBTC"));
     }
 
+    #[tokio::test]
+    async fn test_offline_discovery_serves_curated_list_without_network() {
+        let discovery = TokenDiscovery::new_offline();
+        let tokens = discovery.discover(5).await;
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens[0].symbol, "ONDO");
+    }
+
     #[test]
     fn test_curated_large_limit() {
         let discovery = TokenDiscovery::new();
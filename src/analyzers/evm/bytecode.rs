@@ -1,32 +1,191 @@
-use crate::types::{BytecodeAnalysis, BytecodeComplexity, ProxyType, TokenCapabilities};
-
-/// Function selectors for token capability detection
-mod capability_selectors {
-    pub const MINT: &str = "40c10f19"; // mint(address,uint256)
-    pub const BURN: &str = "42966c68"; // burn(uint256)
-    pub const BURN_FROM: &str = "79cc6790"; // burnFrom(address,uint256)
-    pub const PAUSE: &str = "8456cb59"; // pause()
-    pub const UNPAUSE: &str = "3f4ba83a"; // unpause()
-    pub const BLACKLIST: &str = "f9f92be4"; // blacklist(address)
-    pub const ADD_BLACKLIST: &str = "44337ea1"; // addBlacklist(address)
-    pub const PERMIT: &str = "d505accf"; // permit(address,address,uint256,uint256,uint8,bytes32,bytes32)
-}
+use super::signatures::SignatureDatabase;
+use crate::types::{
+    AccessControl, BytecodeAnalysis, BytecodeComplexity, ControllerType, ProxyType,
+    TokenCapabilities,
+};
+use std::collections::HashSet;
 
 /// EVM opcodes of interest
 mod opcodes {
+    pub const EQ: u8 = 0x14;
+    pub const PUSH1: u8 = 0x60;
+    pub const PUSH4: u8 = 0x63;
+    pub const PUSH32: u8 = 0x7f;
+    pub const JUMPI: u8 = 0x57;
+    pub const SLOAD: u8 = 0x54;
+    pub const CALL: u8 = 0xf1;
     pub const DELEGATECALL: u8 = 0xf4;
     pub const SELFDESTRUCT: u8 = 0xff;
+    pub const PUSH20: u8 = 0x73;
+}
+
+/// A single genuine instruction reached by the linear sweep: its byte offset and opcode.
+/// PUSH immediate data never appears here.
+type Instruction = (usize, u8);
+
+/// Decode a hex bytecode string (no `0x` prefix) into raw bytes, ignoring any
+/// trailing odd nibble or non-hex noise rather than failing the whole analysis.
+fn decode_hex(bytecode: &str) -> Vec<u8> {
+    (0..bytecode.len() / 2)
+        .filter_map(|i| u8::from_str_radix(&bytecode[i * 2..i * 2 + 2], 16).ok())
+        .collect()
+}
+
+/// Strip the Solidity CBOR metadata trailer (added by the compiler, not reachable code).
+/// It ends in a 2-byte big-endian length of the CBOR blob that precedes it.
+fn strip_metadata(bytes: &[u8]) -> &[u8] {
+    if bytes.len() < 2 {
+        return bytes;
+    }
+    let cbor_len = u16::from_be_bytes([bytes[bytes.len() - 2], bytes[bytes.len() - 1]]) as usize;
+    if cbor_len > 0 && cbor_len + 2 <= bytes.len() {
+        &bytes[..bytes.len() - 2 - cbor_len]
+    } else {
+        bytes
+    }
+}
+
+/// Linear-sweep disassembly: walk the code left to right, skipping PUSH1..PUSH32
+/// immediate data instead of treating it as opcodes. This is what makes
+/// `has_opcode`/selector extraction exact rather than a substring-match heuristic.
+fn disassemble(bytes: &[u8]) -> Vec<Instruction> {
+    let mut ops = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let op = bytes[i];
+        ops.push((i, op));
+        if (opcodes::PUSH1..=opcodes::PUSH32).contains(&op) {
+            i += 1 + (op - (opcodes::PUSH1 - 1)) as usize;
+        } else {
+            i += 1;
+        }
+    }
+    ops
+}
+
+/// Extract real 4-byte function selectors from the ABI dispatch table by recognizing the
+/// `PUSH4 <selector> EQ ... JUMPI` pattern the Solidity router compiles to, rather than
+/// scanning for the 4 bytes anywhere in the code (which catches PUSH data too).
+fn extract_selectors(bytes: &[u8], ops: &[Instruction]) -> HashSet<String> {
+    let mut selectors = HashSet::new();
+    for (idx, &(pos, op)) in ops.iter().enumerate() {
+        if op != opcodes::PUSH4 || pos + 5 > bytes.len() {
+            continue;
+        }
+        if ops.get(idx + 1).map(|&(_, o)| o) != Some(opcodes::EQ) {
+            continue;
+        }
+        let followed_by_jumpi = ops[idx + 1..]
+            .iter()
+            .take(4)
+            .any(|&(_, o)| o == opcodes::JUMPI);
+        if followed_by_jumpi {
+            let selector = &bytes[pos + 1..pos + 5];
+            selectors.insert(format!(
+                "{:02x}{:02x}{:02x}{:02x}",
+                selector[0], selector[1], selector[2], selector[3]
+            ));
+        }
+    }
+    selectors
+}
+
+/// Fixed ERC-20/extension selectors used for the approve-race check below. Unlike the
+/// `mint`/`burn`/... categories in [`SignatureDatabase`], these never vary between
+/// projects — they're the literal standard signatures — so they're plain consts here
+/// rather than another user-extensible signature category.
+const APPROVE_SELECTOR: &str = "095ea7b3"; // approve(address,uint256)
+const INCREASE_ALLOWANCE_SELECTOR: &str = "39509351"; // increaseAllowance(address,uint256)
+const DECREASE_ALLOWANCE_SELECTOR: &str = "a457c2d7"; // decreaseAllowance(address,uint256)
+
+/// Fixed tells for the controller types `classify_controller` recognizes — Gnosis-Safe-style
+/// multisigs and OpenZeppelin `TimelockController` — for the same reason the approve-race
+/// selectors above are plain consts: these never vary between projects.
+const SAFE_GET_OWNERS_SELECTOR: &str = "a0e67e2b"; // getOwners()
+const SAFE_EXEC_TRANSACTION_SELECTOR: &str = "6a761202"; // execTransaction(address,uint256,bytes,uint8,uint256,uint256,uint256,address,address,bytes)
+const SAFE_GET_THRESHOLD_SELECTOR: &str = "e75235b8"; // getThreshold()
+const TIMELOCK_GET_MIN_DELAY_SELECTOR: &str = "f27a0c92"; // getMinDelay()
+const TIMELOCK_SCHEDULE_SELECTOR: &str = "01d5062a"; // schedule(address,uint256,bytes,bytes32,bytes32,uint256)
+const TIMELOCK_PROPOSER_ROLE_SELECTOR: &str = "8f61f4f5"; // PROPOSER_ROLE()
+
+/// ERC-777 transfer-hook selectors (`IERC777Sender`/`IERC777Recipient`) — fixed standard
+/// signatures, used by `has_transfer_hook` below alongside the ERC-1820 registry tell.
+const TOKENS_TO_SEND_SELECTOR: &str = "5d70838a"; // tokensToSend(bytes32,address,address,address,uint256,bytes,bytes)
+const TOKENS_RECEIVED_SELECTOR: &str = "1551e636"; // tokensReceived(bytes32,address,address,address,uint256,bytes,bytes)
+
+/// The ERC-1820 registry singleton (`0x1820a4B7618BdE71Dce8cdc73aAB6C95905faD24`), deployed
+/// via the same keyless-deployment transaction on every chain — every ERC-777 token looks
+/// up its sender/recipient transfer hooks through this exact address.
+const ERC1820_REGISTRY_ADDR: [u8; 20] = [
+    0x18, 0x20, 0xa4, 0xb7, 0x61, 0x8b, 0xde, 0x71, 0xdc, 0xe8, 0xcd, 0xc7, 0x3a, 0xab, 0x6c, 0x95,
+    0x90, 0x5f, 0xad, 0x24,
+];
+
+/// Whether the code pushes the ERC-1820 registry address and calls out to it — the actual
+/// tell that this contract looks up an ERC-777 transfer hook before/after a transfer,
+/// rather than guessing from the address's mere presence (which a PUSH20 of unrelated
+/// immediate data could coincidentally match, however unlikely for 20 bytes).
+fn calls_erc1820_registry(bytes: &[u8], ops: &[Instruction]) -> bool {
+    ops.iter().enumerate().any(|(idx, &(pos, op))| {
+        op == opcodes::PUSH20
+            && bytes.get(pos + 1..pos + 21) == Some(ERC1820_REGISTRY_ADDR.as_slice())
+            && ops[idx + 1..]
+                .iter()
+                .take(4)
+                .any(|&(_, o)| o == opcodes::CALL)
+    })
 }
 
 /// EIP-1167 minimal proxy prefix
 const MINIMAL_PROXY_PREFIX: &str = "363d3d373d3d3d363d73";
 
+/// EIP-1967 implementation storage slot: `bytes32(uint256(keccak256('eip1967.proxy.implementation')) - 1)`
+const EIP1967_IMPL_SLOT_HEX: &str =
+    "360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc";
+
+/// Whether the code pushes the EIP-1967 implementation slot and reads it with SLOAD —
+/// the actual tell for a transparent/UUPS proxy, rather than guessing from size alone
+fn reads_eip1967_impl_slot(bytes: &[u8], ops: &[Instruction]) -> bool {
+    let Ok(slot) = (0..EIP1967_IMPL_SLOT_HEX.len() / 2)
+        .map(|i| u8::from_str_radix(&EIP1967_IMPL_SLOT_HEX[i * 2..i * 2 + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+    else {
+        return false;
+    };
+
+    ops.iter().enumerate().any(|(idx, &(pos, op))| {
+        op == opcodes::PUSH32
+            && bytes.get(pos + 1..pos + 33) == Some(slot.as_slice())
+            && ops[idx + 1..]
+                .iter()
+                .take(4)
+                .any(|&(_, o)| o == opcodes::SLOAD)
+    })
+}
+
+/// Default location for a user-extensible signature file, so new fee setters or
+/// blacklist variants can be added without recompiling
+const USER_SIGNATURES_PATH: &str = "~/.config/daybreak/signatures.json";
+
 /// Analyzes contract bytecode for patterns and capabilities
-pub struct BytecodeAnalyzer;
+pub struct BytecodeAnalyzer {
+    signatures: SignatureDatabase,
+}
 
 impl BytecodeAnalyzer {
     pub fn new() -> Self {
-        Self
+        let path = match std::env::var("HOME") {
+            Ok(home) => USER_SIGNATURES_PATH.replacen('~', &home, 1),
+            Err(_) => USER_SIGNATURES_PATH.to_string(),
+        };
+        Self {
+            signatures: SignatureDatabase::load(Some(&path)),
+        }
+    }
+
+    /// Construct with an explicit signature database (e.g. defaults only, for tests)
+    pub fn with_signatures(signatures: SignatureDatabase) -> Self {
+        Self { signatures }
     }
 
     /// Full bytecode analysis
@@ -39,11 +198,19 @@ impl BytecodeAnalyzer {
             return BytecodeAnalysis::default();
         }
 
+        let raw = decode_hex(bytecode);
+        let code = strip_metadata(&raw);
+        let ops = disassemble(code);
+
         let complexity = Self::calculate_complexity(size_bytes);
-        let (is_proxy, proxy_type) = self.detect_proxy(bytecode);
-        let has_selfdestruct = self.has_opcode(bytecode, opcodes::SELFDESTRUCT);
-        let has_delegatecall = self.has_opcode(bytecode, opcodes::DELEGATECALL);
-        let has_fee_pattern = self.detect_fee_pattern(bytecode);
+        let has_selfdestruct = Self::has_opcode(&ops, opcodes::SELFDESTRUCT);
+        let has_delegatecall = Self::has_opcode(&ops, opcodes::DELEGATECALL);
+        let reads_impl_slot = reads_eip1967_impl_slot(code, &ops);
+        let (is_proxy, proxy_type) =
+            self.detect_proxy(bytecode, has_delegatecall, reads_impl_slot, size_bytes);
+        let selectors = extract_selectors(code, &ops);
+        let has_fee_pattern = self.detect_fee_pattern(&selectors);
+        let has_cap = selectors.iter().any(|s| self.signatures.cap.contains(s));
 
         BytecodeAnalysis {
             size_bytes,
@@ -53,25 +220,110 @@ impl BytecodeAnalyzer {
             has_selfdestruct,
             has_delegatecall,
             has_fee_pattern,
+            fee_bps: None,
+            max_fee: None,
+            has_cap,
             complexity,
         }
     }
 
-    /// Detect token capabilities from bytecode function selectors
+    /// Detect token capabilities from the contract's real ABI dispatch-table selectors,
+    /// not a substring scan over the raw hex
     pub fn detect_capabilities(&self, bytecode: &str) -> TokenCapabilities {
         let bytecode = bytecode.trim_start_matches("0x").to_lowercase();
+        let raw = decode_hex(&bytecode);
+        let code = strip_metadata(&raw);
+        let ops = disassemble(code);
+        let selectors = extract_selectors(code, &ops);
+        let has_delegatecall = Self::has_opcode(&ops, opcodes::DELEGATECALL);
+        let reads_impl_slot = reads_eip1967_impl_slot(code, &ops);
 
         TokenCapabilities {
-            has_mint: bytecode.contains(capability_selectors::MINT),
-            has_burn: bytecode.contains(capability_selectors::BURN)
-                || bytecode.contains(capability_selectors::BURN_FROM),
-            has_pause: bytecode.contains(capability_selectors::PAUSE)
-                || bytecode.contains(capability_selectors::UNPAUSE),
-            has_blacklist: bytecode.contains(capability_selectors::BLACKLIST)
-                || bytecode.contains(capability_selectors::ADD_BLACKLIST),
-            has_permit: bytecode.contains(capability_selectors::PERMIT),
-            is_upgradeable: self.detect_proxy(&bytecode).0,
+            has_mint: selectors.iter().any(|s| self.signatures.mint.contains(s)),
+            has_burn: selectors.iter().any(|s| self.signatures.burn.contains(s)),
+            has_pause: selectors.iter().any(|s| self.signatures.pause.contains(s)),
+            has_blacklist: selectors
+                .iter()
+                .any(|s| self.signatures.blacklist.contains(s)),
+            has_permit: selectors.iter().any(|s| self.signatures.permit.contains(s)),
+            is_upgradeable: self
+                .detect_proxy(
+                    &bytecode,
+                    has_delegatecall,
+                    reads_impl_slot,
+                    bytecode.len() / 2,
+                )
+                .0,
+            has_unmitigated_approve_race: selectors.contains(APPROVE_SELECTOR)
+                && !selectors.contains(INCREASE_ALLOWANCE_SELECTOR)
+                && !selectors.contains(DECREASE_ALLOWANCE_SELECTOR),
+            // Arbitrary external-call hooks wired into an overridden `_beforeTokenTransfer`
+            // are a control-flow property tied to the transfer function specifically — not
+            // soundly detectable from selector/opcode presence alone, so only the two
+            // genuine ERC-777 tells are checked: an ERC-1820 registry lookup, and the
+            // contract exposing the hook selectors itself.
+            has_transfer_hook: calls_erc1820_registry(code, &ops)
+                || selectors.contains(TOKENS_TO_SEND_SELECTOR)
+                || selectors.contains(TOKENS_RECEIVED_SELECTOR),
+        }
+    }
+
+    /// Detect the contract's admin pattern from its real ABI dispatch-table selectors —
+    /// the thing that would have to transfer mint authority to the NTT manager
+    pub fn detect_access_control(&self, bytecode: &str) -> AccessControl {
+        let bytecode = bytecode.trim_start_matches("0x").to_lowercase();
+        let raw = decode_hex(&bytecode);
+        let code = strip_metadata(&raw);
+        let ops = disassemble(code);
+        let selectors = extract_selectors(code, &ops);
+
+        AccessControl {
+            has_owner: selectors.iter().any(|s| self.signatures.owner.contains(s)),
+            has_role_based_access: selectors
+                .iter()
+                .any(|s| self.signatures.role_admin.contains(s)),
+        }
+    }
+
+    /// Classify a resolved controller (e.g. a token's `owner()`) from its own bytecode:
+    /// no code at all means an externally-owned account; Gnosis Safe's
+    /// `getOwners`/`execTransaction`/`getThreshold` mean a multisig; OpenZeppelin
+    /// `TimelockController`'s `getMinDelay`/`schedule`/`PROPOSER_ROLE` mean a timelock.
+    /// A contract with code but none of those tells is `Unknown` rather than guessed at.
+    pub fn classify_controller(&self, bytecode: &str) -> ControllerType {
+        let bytecode = bytecode.trim_start_matches("0x");
+        if bytecode.is_empty() {
+            return ControllerType::Eoa;
+        }
+
+        let raw = decode_hex(bytecode);
+        let code = strip_metadata(&raw);
+        let ops = disassemble(code);
+        let selectors = extract_selectors(code, &ops);
+
+        let is_safe = [
+            SAFE_GET_OWNERS_SELECTOR,
+            SAFE_EXEC_TRANSACTION_SELECTOR,
+            SAFE_GET_THRESHOLD_SELECTOR,
+        ]
+        .iter()
+        .any(|s| selectors.contains(*s));
+        if is_safe {
+            return ControllerType::Multisig;
+        }
+
+        let is_timelock = [
+            TIMELOCK_GET_MIN_DELAY_SELECTOR,
+            TIMELOCK_SCHEDULE_SELECTOR,
+            TIMELOCK_PROPOSER_ROLE_SELECTOR,
+        ]
+        .iter()
+        .any(|s| selectors.contains(*s));
+        if is_timelock {
+            return ControllerType::Timelock;
         }
+
+        ControllerType::Unknown
     }
 
     /// Calculate complexity based on bytecode size
@@ -85,8 +337,15 @@ impl BytecodeAnalyzer {
         }
     }
 
-    /// Detect if contract is a proxy and what type
-    fn detect_proxy(&self, bytecode: &str) -> (bool, Option<ProxyType>) {
+    /// Detect if contract is a proxy and what type. `reads_impl_slot` should come from
+    /// [`reads_eip1967_impl_slot`] — a genuine EIP-1967 tell rather than a size guess.
+    fn detect_proxy(
+        &self,
+        bytecode: &str,
+        has_delegatecall: bool,
+        reads_impl_slot: bool,
+        size_bytes: usize,
+    ) -> (bool, Option<ProxyType>) {
         let bytecode_lower = bytecode.to_lowercase();
 
         // EIP-1167 minimal proxy (clone)
@@ -94,57 +353,28 @@ impl BytecodeAnalyzer {
             return (true, Some(ProxyType::MinimalProxy));
         }
 
-        // Small bytecode with delegatecall is likely a proxy
-        let size = bytecode.len() / 2;
-        let has_delegatecall = self.has_opcode(&bytecode_lower, opcodes::DELEGATECALL);
-
-        if has_delegatecall && size < 1000 {
-            // EIP-1967 uses specific storage slot pattern
-            // We can't detect slot usage from bytecode alone, but small + delegatecall = proxy
+        if has_delegatecall && reads_impl_slot {
             return (true, Some(ProxyType::Eip1967));
         }
 
         // Larger contracts with delegatecall might be upgradeable
-        if has_delegatecall && size < 5000 {
+        if has_delegatecall && size_bytes < 5000 {
             return (true, Some(ProxyType::TransparentUpgradeable));
         }
 
         (false, None)
     }
 
-    /// Check if bytecode contains a specific opcode
-    fn has_opcode(&self, bytecode: &str, opcode: u8) -> bool {
-        // Convert bytecode to bytes and scan for opcode
-        // This is a simplified check - a full implementation would parse
-        // the bytecode properly to avoid false positives from PUSH data
-        let target = format!("{:02x}", opcode);
-
-        // Simple heuristic: check if opcode appears in bytecode
-        // Not perfect but good enough for risk assessment
-        bytecode.to_lowercase().contains(&target)
+    /// Check whether a genuine instruction (never PUSH immediate data) with this
+    /// opcode occurs anywhere in the disassembled code
+    fn has_opcode(ops: &[Instruction], opcode: u8) -> bool {
+        ops.iter().any(|&(_, op)| op == opcode)
     }
 
-    /// Detect fee-on-transfer patterns via known function selectors
+    /// Detect fee-on-transfer patterns via known fee-setter function selectors
     /// Only checks for explicit fee setter functions to avoid false positives
-    fn detect_fee_pattern(&self, bytecode: &str) -> bool {
-        let bytecode_lower = bytecode.to_lowercase();
-
-        // Look for common fee-related function selectors
-        // setFee, setTaxFee, etc.
-        let fee_selectors = [
-            "69fe0e2d", // setFee(uint256)
-            "c0b0fda2", // setTaxFee(uint256)
-            "e01af92c", // setTaxRate(uint256)
-            "f41e60c5", // setFees(uint256)
-        ];
-
-        for selector in fee_selectors {
-            if bytecode_lower.contains(selector) {
-                return true;
-            }
-        }
-
-        false
+    fn detect_fee_pattern(&self, selectors: &HashSet<String>) -> bool {
+        selectors.iter().any(|s| self.signatures.fee.contains(s))
     }
 }
 
@@ -163,11 +393,60 @@ mod tests {
         let analyzer = BytecodeAnalyzer::new();
         // EIP-1167 minimal proxy bytecode prefix
         let proxy_bytecode = "363d3d373d3d3d363d73bebebebebebebebebebebebebebebebebebebebe5af43d82803e903d91602b57fd5bf3";
-        let (is_proxy, proxy_type) = analyzer.detect_proxy(proxy_bytecode);
+        let size = proxy_bytecode.len() / 2;
+        let (is_proxy, proxy_type) = analyzer.detect_proxy(proxy_bytecode, false, false, size);
         assert!(is_proxy);
         assert_eq!(proxy_type, Some(ProxyType::MinimalProxy));
     }
 
+    #[test]
+    fn test_eip1967_slot_read_detected() {
+        // PUSH32 <eip1967 impl slot> SLOAD — the genuine tell, independent of size
+        let mut bytes = vec![opcodes::PUSH32];
+        bytes.extend_from_slice(&decode_hex(EIP1967_IMPL_SLOT_HEX));
+        bytes.push(opcodes::SLOAD);
+        let ops = disassemble(&bytes);
+        assert!(reads_eip1967_impl_slot(&bytes, &ops));
+    }
+
+    #[test]
+    fn test_eip1967_slot_push_without_sload_not_detected() {
+        // Same slot pushed, but never read — shouldn't be mistaken for a proxy tell
+        let mut bytes = vec![opcodes::PUSH32];
+        bytes.extend_from_slice(&decode_hex(EIP1967_IMPL_SLOT_HEX));
+        bytes.push(0x00); // STOP, not SLOAD
+        let ops = disassemble(&bytes);
+        assert!(!reads_eip1967_impl_slot(&bytes, &ops));
+    }
+
+    #[test]
+    fn test_has_opcode_skips_push_immediate_data() {
+        // PUSH1 0xff followed by a real STOP — the 0xff must not be mistaken for SELFDESTRUCT
+        let bytes = decode_hex("60ff00");
+        let ops = disassemble(&bytes);
+        assert!(!BytecodeAnalyzer::has_opcode(&ops, opcodes::SELFDESTRUCT));
+    }
+
+    #[test]
+    fn test_extract_selectors_ignores_push_data_matching_selector() {
+        // PUSH4 with the mint() selector as immediate data, but never followed by EQ/JUMPI
+        let bytes = decode_hex("6340c10f1900");
+        let ops = disassemble(&bytes);
+        let selectors = extract_selectors(&bytes, &ops);
+        assert!(selectors.is_empty());
+    }
+
+    #[test]
+    fn test_extract_selectors_finds_dispatch_table_entry() {
+        // PUSH4 <mint selector> EQ PUSH2 <dest> JUMPI, the real router pattern
+        let bytes = decode_hex("6340c10f1914610102");
+        let mut full = bytes.clone();
+        full.push(opcodes::JUMPI);
+        let ops = disassemble(&full);
+        let selectors = extract_selectors(&full, &ops);
+        assert!(selectors.contains("40c10f19")); // mint(address,uint256)
+    }
+
     #[test]
     fn test_complexity_simple() {
         assert_eq!(
@@ -191,4 +470,133 @@ mod tests {
             BytecodeComplexity::Complex
         );
     }
+
+    #[test]
+    fn test_detect_capabilities_uses_injected_signature_db() {
+        // A custom mint selector that isn't in the bundled defaults
+        let mut signatures = SignatureDatabase::default();
+        signatures.mint.insert("aabbccdd".to_string());
+        let analyzer = BytecodeAnalyzer::with_signatures(signatures);
+
+        // PUSH4 <custom selector> EQ PUSH2 <dest> JUMPI
+        let mut bytecode = decode_hex("63aabbccdd14610102");
+        bytecode.push(opcodes::JUMPI);
+        let hex = bytecode.iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+        assert!(analyzer.detect_capabilities(&hex).has_mint);
+    }
+
+    #[test]
+    fn test_analyze_finds_cap_selector() {
+        let analyzer = BytecodeAnalyzer::new();
+        // PUSH4 <cap()> EQ PUSH2 <dest> JUMPI
+        let mut bytecode = decode_hex("63355274ea14610102");
+        bytecode.push(opcodes::JUMPI);
+        let hex = bytecode.iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+        assert!(analyzer.analyze(&hex).has_cap);
+    }
+
+    #[test]
+    fn test_detect_capabilities_flags_unmitigated_approve_race() {
+        let analyzer = BytecodeAnalyzer::new();
+        // PUSH4 <approve()> EQ PUSH2 <dest> JUMPI, with no safe-allowance alternative
+        let mut bytecode = decode_hex("63095ea7b314610102");
+        bytecode.push(opcodes::JUMPI);
+        let hex = bytecode.iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+        assert!(analyzer.detect_capabilities(&hex).has_unmitigated_approve_race);
+    }
+
+    #[test]
+    fn test_detect_capabilities_approve_race_mitigated_by_increase_allowance() {
+        let analyzer = BytecodeAnalyzer::new();
+        // Both approve() and increaseAllowance() reachable from the dispatch table
+        let mut bytecode = decode_hex("63095ea7b314610102");
+        bytecode.push(opcodes::JUMPI);
+        bytecode.extend(decode_hex("6339509351146101"));
+        bytecode.extend(decode_hex("03"));
+        bytecode.push(opcodes::JUMPI);
+        let hex = bytecode.iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+        assert!(!analyzer.detect_capabilities(&hex).has_unmitigated_approve_race);
+    }
+
+    #[test]
+    fn test_detect_capabilities_flags_erc1820_registry_call() {
+        let analyzer = BytecodeAnalyzer::new();
+        // PUSH20 <ERC-1820 registry address> CALL
+        let hex = "731820a4b7618bde71dce8cdc73aab6c95905fad24f1";
+        assert!(analyzer.detect_capabilities(hex).has_transfer_hook);
+    }
+
+    #[test]
+    fn test_detect_capabilities_flags_tokens_received_selector() {
+        let analyzer = BytecodeAnalyzer::new();
+        // PUSH4 <tokensReceived(...)> EQ PUSH2 <dest> JUMPI
+        let mut bytecode = decode_hex("631551e63614610102");
+        bytecode.push(opcodes::JUMPI);
+        let hex = bytecode.iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+        assert!(analyzer.detect_capabilities(&hex).has_transfer_hook);
+    }
+
+    #[test]
+    fn test_detect_capabilities_no_transfer_hook_by_default() {
+        let analyzer = BytecodeAnalyzer::new();
+        assert!(!analyzer.detect_capabilities("6001600101").has_transfer_hook);
+    }
+
+    #[test]
+    fn test_detect_access_control_finds_owner_selector() {
+        let analyzer = BytecodeAnalyzer::new();
+        // PUSH4 <owner()> EQ PUSH2 <dest> JUMPI
+        let mut bytecode = decode_hex("638da5cb5b14610102");
+        bytecode.push(opcodes::JUMPI);
+        let hex = bytecode.iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+        let access = analyzer.detect_access_control(&hex);
+        assert!(access.has_owner);
+        assert!(!access.has_role_based_access);
+    }
+
+    #[test]
+    fn test_classify_controller_empty_code_is_eoa() {
+        let analyzer = BytecodeAnalyzer::new();
+        assert_eq!(analyzer.classify_controller("0x"), ControllerType::Eoa);
+        assert_eq!(analyzer.classify_controller(""), ControllerType::Eoa);
+    }
+
+    #[test]
+    fn test_classify_controller_finds_gnosis_safe() {
+        let analyzer = BytecodeAnalyzer::new();
+        // PUSH4 <getThreshold()> EQ PUSH2 <dest> JUMPI
+        let mut bytecode = decode_hex("63e75235b814610102");
+        bytecode.push(opcodes::JUMPI);
+        let hex = bytecode.iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+        assert_eq!(analyzer.classify_controller(&hex), ControllerType::Multisig);
+    }
+
+    #[test]
+    fn test_classify_controller_finds_timelock() {
+        let analyzer = BytecodeAnalyzer::new();
+        // PUSH4 <getMinDelay()> EQ PUSH2 <dest> JUMPI
+        let mut bytecode = decode_hex("63f27a0c9214610102");
+        bytecode.push(opcodes::JUMPI);
+        let hex = bytecode.iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+        assert_eq!(analyzer.classify_controller(&hex), ControllerType::Timelock);
+    }
+
+    #[test]
+    fn test_classify_controller_unrecognized_contract_is_unknown() {
+        let analyzer = BytecodeAnalyzer::new();
+        // PUSH4 <owner()> EQ PUSH2 <dest> JUMPI — has code, but none of the multisig/timelock tells
+        let mut bytecode = decode_hex("638da5cb5b14610102");
+        bytecode.push(opcodes::JUMPI);
+        let hex = bytecode.iter().map(|b| format!("{b:02x}")).collect::<String>();
+
+        assert_eq!(analyzer.classify_controller(&hex), ControllerType::Unknown);
+    }
 }
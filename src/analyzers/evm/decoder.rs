@@ -1,3 +1,4 @@
+use alloy::primitives::Address;
 use anyhow::{Context, Result};
 
 /// ABI decoder for EVM return values
@@ -76,6 +77,140 @@ impl AbiDecoder {
         result.iter().rev().map(|d| (b'0' + d) as char).collect()
     }
 
+    /// Decode a signed int256 from hex string into a decimal string, two's-complement.
+    /// If the top bit of the 32-byte word is set the value is negative: negate it
+    /// (invert every nibble, add 1) to recover the magnitude, decode that as an unsigned
+    /// value via `hex_to_decimal`/`decode_uint256`, and prefix with `-`.
+    pub fn decode_int256(hex: &str) -> Result<String> {
+        let hex = hex.trim_start_matches("0x");
+        if hex.is_empty() {
+            return Ok("0".to_string());
+        }
+
+        let padded = format!("{hex:0>64}");
+        let sign_nibble = padded
+            .chars()
+            .next()
+            .and_then(|c| c.to_digit(16))
+            .context("Failed to parse int256")?;
+
+        if sign_nibble < 8 {
+            return Self::decode_uint256(hex);
+        }
+
+        let inverted: String = padded
+            .chars()
+            .map(|c| {
+                let d = c.to_digit(16).unwrap_or(0);
+                std::char::from_digit(15 - d, 16).unwrap_or('0')
+            })
+            .collect();
+        let magnitude_hex = Self::hex_add_one(&inverted);
+        let magnitude = Self::decode_uint256(&magnitude_hex)?;
+        Ok(format!("-{magnitude}"))
+    }
+
+    /// Add 1 to a hex string of equal-width nibbles, carrying as needed
+    fn hex_add_one(hex: &str) -> String {
+        let mut digits: Vec<u32> = hex.chars().map(|c| c.to_digit(16).unwrap_or(0)).collect();
+        let mut carry = 1u32;
+        for d in digits.iter_mut().rev() {
+            let val = *d + carry;
+            *d = val % 16;
+            carry = val / 16;
+            if carry == 0 {
+                break;
+            }
+        }
+        digits
+            .iter()
+            .map(|d| std::char::from_digit(*d, 16).unwrap_or('0'))
+            .collect()
+    }
+
+    /// Decode a bool from hex string — ABI-encodes as a 32-byte word, the value lives in
+    /// the last byte
+    pub fn decode_bool(hex: &str) -> Result<bool> {
+        let hex = hex.trim_start_matches("0x");
+        if hex.len() < 2 {
+            return Ok(false);
+        }
+        let last_byte = &hex[hex.len() - 2..];
+        let value = u8::from_str_radix(last_byte, 16).context("Failed to parse bool")?;
+        Ok(value != 0)
+    }
+
+    /// Decode an address from hex string — ABI-encodes as a 32-byte word, the 20-byte
+    /// address lives in the low bytes. Returns the EIP-55 checksummed form.
+    pub fn decode_address(hex: &str) -> Result<String> {
+        let hex = hex.trim_start_matches("0x");
+        if hex.len() < 40 {
+            anyhow::bail!("Address word too short to decode");
+        }
+        let addr_hex = &hex[hex.len() - 40..];
+        let address: Address = format!("0x{addr_hex}")
+            .parse()
+            .context("Failed to parse address word")?;
+        Ok(address.to_checksum(None))
+    }
+
+    /// Decode a dynamic `bytes` return value into its raw hex form.
+    /// Format: offset (32 bytes) + length (32 bytes) + data (padded to 32 bytes)
+    pub fn decode_bytes(hex: &str) -> Result<String> {
+        let hex = hex.trim_start_matches("0x");
+
+        if hex.len() < 128 {
+            anyhow::bail!("bytes response too short to contain an offset+length header");
+        }
+
+        let length_hex = &hex[64..128];
+        let length =
+            usize::from_str_radix(length_hex.trim_start_matches('0').max("0"), 16).unwrap_or(0);
+
+        if length == 0 {
+            return Ok(String::new());
+        }
+
+        let data_start = 128;
+        let data_end = (data_start + length * 2).min(hex.len());
+        Ok(format!("0x{}", &hex[data_start..data_end]))
+    }
+
+    /// Decode a dynamic array of statically-sized elements (each one 32-byte slot, e.g.
+    /// `uint256[]`, `address[]`, `bool[]`). Format: head offset (32 bytes) pointing at the
+    /// tail, where the tail is element count (32 bytes) followed by one slot per element.
+    /// `decode_element` decodes a single 32-byte slot's hex into `T`.
+    pub fn decode_array<T>(hex: &str, decode_element: impl Fn(&str) -> Result<T>) -> Result<Vec<T>> {
+        let hex = hex.trim_start_matches("0x");
+        if hex.len() < 64 {
+            anyhow::bail!("array response too short to contain a head offset");
+        }
+
+        let offset_hex = &hex[0..64];
+        let offset_bytes =
+            usize::from_str_radix(offset_hex.trim_start_matches('0').max("0"), 16).unwrap_or(0);
+        let tail_start = offset_bytes * 2;
+        if tail_start + 64 > hex.len() {
+            anyhow::bail!("array head offset points outside the response");
+        }
+
+        let length_hex = &hex[tail_start..tail_start + 64];
+        let length =
+            usize::from_str_radix(length_hex.trim_start_matches('0').max("0"), 16).unwrap_or(0);
+
+        let elements_start = tail_start + 64;
+        let mut elements = Vec::with_capacity(length);
+        for i in 0..length {
+            let slot_start = elements_start + i * 64;
+            let slot_end = slot_start + 64;
+            if slot_end > hex.len() {
+                anyhow::bail!("array element {i} is outside the response");
+            }
+            elements.push(decode_element(&hex[slot_start..slot_end])?);
+        }
+        Ok(elements)
+    }
+
     /// Decode a uint8 from hex string (for decimals)
     pub fn decode_uint8(hex: &str) -> Result<u8> {
         let hex = hex.trim_start_matches("0x");
@@ -133,6 +268,48 @@ impl AbiDecoder {
         String::from_utf8(bytes).context("Failed to decode string as UTF-8")
     }
 
+    /// Decode a `name()`/`symbol()` return value that may be either a dynamic ABI
+    /// `string` or the legacy fixed-width `bytes32` encoding a large population of early
+    /// ERC-20 tokens (MKR, SAI, and others) used instead. A `bytes32` return has no
+    /// offset/length header — it's just the 32-byte word itself — so it's always shorter
+    /// than a dynamic string's minimum 128-hex-char header-plus-data layout; anything that
+    /// short, or that fails to decode as a dynamic string, is treated as `bytes32`.
+    pub fn decode_string_or_bytes32(hex: &str) -> Result<String> {
+        let trimmed = hex.trim_start_matches("0x");
+
+        if trimmed.len() < 128 {
+            return Self::decode_bytes32(trimmed);
+        }
+
+        match Self::decode_string(hex) {
+            Ok(s) => Ok(s),
+            Err(_) => Self::decode_bytes32(trimmed),
+        }
+    }
+
+    /// Decode a right-padded `bytes32` word as UTF-8: trim trailing zero bytes, then
+    /// interpret what's left as the string.
+    fn decode_bytes32(hex: &str) -> Result<String> {
+        let bytes: Vec<u8> = (0..hex.len())
+            .step_by(2)
+            .filter_map(|i| {
+                if i + 2 <= hex.len() {
+                    u8::from_str_radix(&hex[i..i + 2], 16).ok()
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let end = bytes
+            .iter()
+            .rposition(|&b| b != 0)
+            .map(|i| i + 1)
+            .unwrap_or(0);
+
+        String::from_utf8(bytes[..end].to_vec()).context("Failed to decode bytes32 as UTF-8")
+    }
+
     /// Extract ASCII characters from hex (fallback for non-standard responses)
     fn extract_ascii(hex: &str) -> Result<String> {
         let bytes: Vec<u8> = (0..hex.len())
@@ -228,6 +405,103 @@ mod tests {
         assert_eq!(result, "1461501637330902918203684832716283019655932542976");
     }
 
+    // ── int256 ─────────────────────────────────────────────
+
+    #[test]
+    fn test_decode_int256_positive() {
+        let hex = "0x0000000000000000000000000000000000000000000000000000000000000f4240";
+        assert_eq!(AbiDecoder::decode_int256(hex).unwrap(), "1000000");
+    }
+
+    #[test]
+    fn test_decode_int256_zero() {
+        let hex = "0x0000000000000000000000000000000000000000000000000000000000000000";
+        assert_eq!(AbiDecoder::decode_int256(hex).unwrap(), "0");
+    }
+
+    #[test]
+    fn test_decode_int256_negative_one() {
+        // -1 in two's complement is all Fs
+        let hex = "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+        assert_eq!(AbiDecoder::decode_int256(hex).unwrap(), "-1");
+    }
+
+    #[test]
+    fn test_decode_int256_negative_value() {
+        // -1000000: two's complement of 0xF4240 over 256 bits
+        let hex = "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffff0bdc0";
+        assert_eq!(AbiDecoder::decode_int256(hex).unwrap(), "-1000000");
+    }
+
+    // ── bool ───────────────────────────────────────────────
+
+    #[test]
+    fn test_decode_bool_true() {
+        let hex = "0x0000000000000000000000000000000000000000000000000000000000000001";
+        assert!(AbiDecoder::decode_bool(hex).unwrap());
+    }
+
+    #[test]
+    fn test_decode_bool_false() {
+        let hex = "0x0000000000000000000000000000000000000000000000000000000000000000";
+        assert!(!AbiDecoder::decode_bool(hex).unwrap());
+    }
+
+    // ── address ────────────────────────────────────────────
+
+    #[test]
+    fn test_decode_address() {
+        let hex = "0x000000000000000000000000c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
+        assert_eq!(
+            AbiDecoder::decode_address(hex).unwrap(),
+            "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"
+        );
+    }
+
+    // ── bytes ──────────────────────────────────────────────
+
+    #[test]
+    fn test_decode_bytes() {
+        // offset=0x20, length=2, data=0xbeef
+        let hex = "0x\
+            0000000000000000000000000000000000000000000000000000000000000020\
+            0000000000000000000000000000000000000000000000000000000000000002\
+            beef000000000000000000000000000000000000000000000000000000000000";
+        assert_eq!(AbiDecoder::decode_bytes(hex).unwrap(), "0xbeef");
+    }
+
+    #[test]
+    fn test_decode_bytes_empty() {
+        let hex = "0x\
+            0000000000000000000000000000000000000000000000000000000000000020\
+            0000000000000000000000000000000000000000000000000000000000000000";
+        assert_eq!(AbiDecoder::decode_bytes(hex).unwrap(), "");
+    }
+
+    // ── array ──────────────────────────────────────────────
+
+    #[test]
+    fn test_decode_array_uint256() {
+        // offset=0x20, length=3, elements 1, 2, 3
+        let hex = "0x\
+            0000000000000000000000000000000000000000000000000000000000000020\
+            0000000000000000000000000000000000000000000000000000000000000003\
+            0000000000000000000000000000000000000000000000000000000000000001\
+            0000000000000000000000000000000000000000000000000000000000000002\
+            0000000000000000000000000000000000000000000000000000000000000003";
+        let result = AbiDecoder::decode_array(hex, AbiDecoder::decode_uint256).unwrap();
+        assert_eq!(result, vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_decode_array_empty() {
+        let hex = "0x\
+            0000000000000000000000000000000000000000000000000000000000000020\
+            0000000000000000000000000000000000000000000000000000000000000000";
+        let result: Vec<String> = AbiDecoder::decode_array(hex, AbiDecoder::decode_uint256).unwrap();
+        assert!(result.is_empty());
+    }
+
     // ── string ─────────────────────────────────────────────
 
     #[test]
@@ -257,4 +531,29 @@ mod tests {
         let result = AbiDecoder::decode_string(hex).unwrap();
         assert!(result.contains("MKR"));
     }
+
+    // ── string-or-bytes32 ──────────────────────────────────
+
+    #[test]
+    fn test_decode_string_or_bytes32_standard_string() {
+        let hex = "0x\
+            0000000000000000000000000000000000000000000000000000000000000020\
+            0000000000000000000000000000000000000000000000000000000000000004\
+            5553444300000000000000000000000000000000000000000000000000000000";
+        assert_eq!(AbiDecoder::decode_string_or_bytes32(hex).unwrap(), "USDC");
+    }
+
+    #[test]
+    fn test_decode_string_or_bytes32_legacy_bytes32() {
+        // MKR declares `name`/`symbol` as `bytes32` — a single right-padded 32-byte word,
+        // no offset/length header
+        let hex = "0x4d4b520000000000000000000000000000000000000000000000000000000000";
+        assert_eq!(AbiDecoder::decode_string_or_bytes32(hex).unwrap(), "MKR");
+    }
+
+    #[test]
+    fn test_decode_string_or_bytes32_all_zero_is_empty() {
+        let hex = "0x0000000000000000000000000000000000000000000000000000000000000000";
+        assert_eq!(AbiDecoder::decode_string_or_bytes32(hex).unwrap(), "");
+    }
 }
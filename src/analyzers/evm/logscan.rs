@@ -0,0 +1,198 @@
+use super::rpc::EvmRpcClient;
+use crate::analyzers::holders::HolderSource;
+use crate::types::{Chain, HolderData, HolderInfo};
+use alloy::primitives::U256;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// `Transfer(address,address,uint256)` event signature hash — `topics[0]` for every
+/// ERC-20 transfer log.
+const TRANSFER_TOPIC0: &str =
+    "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+/// The zero address — `Transfer` logs to/from it are mint/burn, not a real holder balance
+/// change, and are skipped rather than crediting/debiting a phantom "0x00...00" holder.
+const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
+
+/// Blocks per `eth_getLogs` call. Wide enough to keep round-trips down, narrow enough
+/// that most public RPCs won't reject the range outright (many cap unbounded ranges
+/// somewhere in the low thousands).
+const CHUNK_SIZE: u64 = 2_000;
+
+/// How far back from the chain head to scan by default. `Transfer` logs back to genesis
+/// would be an unbounded, potentially multi-million-log scan for an old token — this
+/// reconstructs a recent concentration snapshot instead, and reports the exact window
+/// scanned so callers can label it as such rather than as full on-chain history.
+const DEFAULT_WINDOW_BLOCKS: u64 = 50_000;
+
+/// Reconstructs holder balances directly from on-chain `Transfer` logs, for tokens/chains
+/// where an Etherscan-style `tokenholderlist` PRO key isn't available. Folds every
+/// transfer's credit/debit into a running balance map over a bounded recent block window.
+/// This is a window snapshot, not full on-chain history — `HolderData::scanned_window`
+/// always records exactly which blocks were covered.
+pub struct LogScanHolderAnalyzer<'a> {
+    rpc: &'a EvmRpcClient,
+}
+
+impl<'a> LogScanHolderAnalyzer<'a> {
+    pub fn new(rpc: &'a EvmRpcClient) -> Self {
+        Self { rpc }
+    }
+
+    /// Reconstruct holder balances for `address` over the trailing
+    /// `DEFAULT_WINDOW_BLOCKS` blocks up to the current chain head.
+    pub async fn scan(&self, address: &str) -> Result<HolderData> {
+        let head = self.rpc.get_block_number().await?;
+        let from_block = head.saturating_sub(DEFAULT_WINDOW_BLOCKS);
+        self.scan_range(address, from_block, head).await
+    }
+
+    /// Same as `scan`, but over a caller-chosen block range.
+    pub async fn scan_range(
+        &self,
+        address: &str,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<HolderData> {
+        let mut balances: HashMap<String, U256> = HashMap::new();
+
+        let mut chunk_start = from_block;
+        while chunk_start <= to_block {
+            let chunk_end = (chunk_start + CHUNK_SIZE - 1).min(to_block);
+            let logs = self
+                .rpc
+                .get_logs(address, &[TRANSFER_TOPIC0], chunk_start, chunk_end)
+                .await
+                .context("fetching Transfer logs")?;
+
+            for log in &logs {
+                let Some((from, to, value)) = Self::decode_transfer(log) else {
+                    continue;
+                };
+                if from != ZERO_ADDRESS {
+                    let entry = balances.entry(from).or_insert(U256::ZERO);
+                    *entry = entry.saturating_sub(value);
+                }
+                if to != ZERO_ADDRESS {
+                    let entry = balances.entry(to).or_insert(U256::ZERO);
+                    *entry = entry.saturating_add(value);
+                }
+            }
+
+            chunk_start = chunk_end + 1;
+        }
+
+        balances.retain(|_, balance| !balance.is_zero());
+
+        let mut holders: Vec<(String, U256)> = balances.into_iter().collect();
+        holders.sort_by(|a, b| b.1.cmp(&a.1));
+        holders.truncate(10);
+
+        let total = holders
+            .iter()
+            .fold(U256::ZERO, |acc, (_, balance)| acc + balance);
+
+        let top_holders: Vec<HolderInfo> = holders
+            .into_iter()
+            .map(|(address, balance)| HolderInfo {
+                address,
+                balance: balance.to_string(),
+                percentage: Self::percentage(balance, total),
+            })
+            .collect();
+
+        let top_10_concentration: f64 = top_holders.iter().map(|h| h.percentage).sum();
+
+        Ok(HolderData {
+            top_holders,
+            top_10_concentration,
+            total_holders: None, // Not resolvable from a windowed scan — would need full history
+            scanned_window: Some((from_block, to_block)),
+        })
+    }
+
+    /// Exact `balance / total * 100` computed in integer space, mirroring
+    /// `HolderAnalyzer::percentage` for the same f64-precision reasons.
+    fn percentage(balance: U256, total: U256) -> f64 {
+        if total.is_zero() {
+            return 0.0;
+        }
+        let scaled = balance.saturating_mul(U256::from(10_000u32)) / total;
+        let scaled: u64 = scaled.try_into().unwrap_or(u64::MAX);
+        ((scaled as f64 / 100.0) * 10.0).round() / 10.0
+    }
+
+    /// Decode a raw `Transfer` log into `(from, to, value)`. `from`/`to` are the low 20
+    /// bytes of `topics[1]`/`topics[2]` (both addresses are indexed on the standard
+    /// `Transfer` event); `value` is the non-indexed `data` word.
+    fn decode_transfer(log: &Value) -> Option<(String, String, U256)> {
+        let topics = log.get("topics")?.as_array()?;
+        let from = Self::topic_to_address(topics.get(1)?.as_str()?)?;
+        let to = Self::topic_to_address(topics.get(2)?.as_str()?)?;
+        let value = log.get("data")?.as_str()?.parse::<U256>().ok()?;
+        Some((from, to, value))
+    }
+
+    fn topic_to_address(topic: &str) -> Option<String> {
+        let hex = topic.trim_start_matches("0x");
+        if hex.len() < 40 {
+            return None;
+        }
+        Some(format!("0x{}", &hex[hex.len() - 40..]))
+    }
+}
+
+#[async_trait]
+impl<'a> HolderSource for LogScanHolderAnalyzer<'a> {
+    /// `chain` is unused — the RPC client this analyzer was built from is already bound
+    /// to a specific chain's endpoints, and `eth_getLogs`/Transfer-topic scanning has no
+    /// chain-specific branching the way Etherscan's per-chain API hosts do.
+    async fn get_holders(&self, address: &str, _chain: Chain) -> Result<HolderData> {
+        self.scan(address).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topic_to_address() {
+        let topic = "0x000000000000000000000000ee0056579691295aa7ea710e9b54a26f30337b07";
+        assert_eq!(
+            LogScanHolderAnalyzer::topic_to_address(topic),
+            Some("0xee0056579691295aa7ea710e9b54a26f30337b07".to_string())
+        );
+    }
+
+    #[test]
+    fn test_topic_to_address_too_short_is_none() {
+        assert_eq!(LogScanHolderAnalyzer::topic_to_address("0x1234"), None);
+    }
+
+    #[test]
+    fn test_decode_transfer() {
+        let log = serde_json::json!({
+            "topics": [
+                TRANSFER_TOPIC0,
+                "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "0x0000000000000000000000000000000000000000000000000000000000000002",
+            ],
+            "data": "0x00000000000000000000000000000000000000000000000000000000000003e8",
+        });
+        let (from, to, value) = LogScanHolderAnalyzer::decode_transfer(&log).unwrap();
+        assert_eq!(from, "0x0000000000000000000000000000000000000001");
+        assert_eq!(to, "0x0000000000000000000000000000000000000002");
+        assert_eq!(value, U256::from(1000u32));
+    }
+
+    #[test]
+    fn test_percentage_zero_total_yields_zero() {
+        assert_eq!(
+            LogScanHolderAnalyzer::percentage(U256::from(5u32), U256::ZERO),
+            0.0
+        );
+    }
+}
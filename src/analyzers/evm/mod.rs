@@ -1,14 +1,22 @@
 pub mod rpc;
 pub mod token;
+pub mod nft;
 pub mod bytecode;
 pub mod decoder;
+pub mod logscan;
+pub mod signatures;
 
 use anyhow::Result;
-use crate::types::{Chain, TokenInfo, TokenCapabilities, BytecodeAnalysis};
+use crate::types::{
+    AccessControl, BytecodeAnalysis, Chain, ChainRegistry, GovernanceProfile, NftCollectionInfo,
+    TokenCapabilities, TokenInfo,
+};
 
-pub use rpc::EvmRpcClient;
+pub use rpc::{EvmRpcClient, MethodStats, RpcStats};
 pub use token::TokenAnalyzer;
+pub use nft::NftAnalyzer;
 pub use bytecode::BytecodeAnalyzer;
+pub use logscan::LogScanHolderAnalyzer;
 
 /// Main EVM analyzer combining all EVM-related analysis
 pub struct EvmAnalyzer {
@@ -18,39 +26,147 @@ pub struct EvmAnalyzer {
 
 impl EvmAnalyzer {
     pub fn new(chain: Chain, rpc_url: Option<String>) -> Self {
-        let url = rpc_url.unwrap_or_else(|| chain.default_rpc_url().to_string());
+        // An explicit `--rpc-url` takes priority and is the only endpoint tried.
+        // Otherwise fall over across every endpoint the registry configures for this
+        // chain (so a user can add fallbacks without recompiling, see `ChainRegistry`),
+        // then the hardcoded default as a last resort.
+        let urls = match rpc_url {
+            Some(url) => vec![url],
+            None => {
+                let mut urls: Vec<String> = ChainRegistry::load()
+                    .resolve(chain.display_name())
+                    .map(|spec| spec.rpc_urls.clone())
+                    .unwrap_or_default();
+                if urls.is_empty() {
+                    urls.push(chain.default_rpc_url().to_string());
+                }
+                urls
+            }
+        };
         Self {
-            rpc: EvmRpcClient::new(&url),
+            rpc: EvmRpcClient::with_endpoints(urls),
             chain,
         }
     }
 
+    /// Access the underlying RPC client, e.g. for gas estimation
+    pub fn rpc(&self) -> &EvmRpcClient {
+        &self.rpc
+    }
+
     /// Fetch basic token information (name, symbol, decimals, supply)
     pub async fn get_token_info(&self, address: &str) -> Result<TokenInfo> {
         let token_analyzer = TokenAnalyzer::new(&self.rpc);
         token_analyzer.get_token_info(address, self.chain).await
     }
 
-    /// Detect token capabilities from bytecode/function signatures
+    /// Whether this address is an ERC-721 collection (EIP-165 `supportsInterface`)
+    pub async fn is_erc721(&self, address: &str) -> Result<bool> {
+        NftAnalyzer::new(&self.rpc).is_erc721(address).await
+    }
+
+    /// Whether this address is an ERC-1155 multi-token collection (EIP-165
+    /// `supportsInterface`)
+    pub async fn is_erc1155(&self, address: &str) -> Result<bool> {
+        NftAnalyzer::new(&self.rpc).is_erc1155(address).await
+    }
+
+    /// Fetch ERC-721 collection metadata (name, symbol, base URI, total supply)
+    pub async fn get_collection_info(&self, address: &str) -> Result<NftCollectionInfo> {
+        NftAnalyzer::new(&self.rpc)
+            .get_collection_info(address, self.chain)
+            .await
+    }
+
+    /// Detect token capabilities from bytecode/function signatures. For a proxy, this
+    /// resolves and inspects the logic contract so capabilities reflect the code that
+    /// actually executes, not the thin delegatecall stub.
     pub async fn get_capabilities(&self, address: &str) -> Result<TokenCapabilities> {
-        let bytecode = self.rpc.get_code(address).await?;
         let bytecode_analyzer = BytecodeAnalyzer::new();
+        let bytecode = self.rpc.get_code(address).await?;
+
+        if let Ok(Some((impl_addr, _))) = self.rpc.resolve_implementation(address).await {
+            if let Ok(impl_bytecode) = self.rpc.get_code(&impl_addr).await {
+                let mut capabilities = bytecode_analyzer.detect_capabilities(&impl_bytecode);
+                capabilities.is_upgradeable = true;
+                return Ok(capabilities);
+            }
+        }
+
         Ok(bytecode_analyzer.detect_capabilities(&bytecode))
     }
 
-    /// Analyze contract bytecode for proxy patterns and dangerous opcodes
+    /// Detect the contract's admin pattern (owner/role-based). For a proxy, this resolves
+    /// the implementation first since admin checks commonly live there, not the stub.
+    pub async fn get_access_control(&self, address: &str) -> Result<AccessControl> {
+        let bytecode_analyzer = BytecodeAnalyzer::new();
+        let bytecode = self.rpc.get_code(address).await?;
+
+        if let Ok(Some((impl_addr, _))) = self.rpc.resolve_implementation(address).await {
+            if let Ok(impl_bytecode) = self.rpc.get_code(&impl_addr).await {
+                return Ok(bytecode_analyzer.detect_access_control(&impl_bytecode));
+            }
+        }
+
+        Ok(bytecode_analyzer.detect_access_control(&bytecode))
+    }
+
+    /// Analyze contract bytecode for proxy patterns and dangerous opcodes. Storage-backed
+    /// resolution (EIP-1967, its beacon variant, EIP-1822, the transparent admin slot, and
+    /// the EIP-1167 clone pattern) is authoritative over the bytecode-only heuristic in
+    /// `detect_proxy`, since it actually reads the relevant slots/bytes rather than
+    /// guessing from size and opcode presence — it catches proxy kinds the heuristic
+    /// alone misses.
     pub async fn analyze_bytecode(&self, address: &str) -> Result<BytecodeAnalysis> {
         let bytecode = self.rpc.get_code(address).await?;
         let bytecode_analyzer = BytecodeAnalyzer::new();
         let mut analysis = bytecode_analyzer.analyze(&bytecode);
 
-        // If it's a proxy, try to fetch the implementation address
-        if analysis.is_proxy {
-            if let Ok(Some(impl_addr)) = self.rpc.get_eip1967_implementation(address).await {
-                analysis.implementation_address = Some(impl_addr);
+        if let Ok(Some((impl_addr, proxy_type))) = self.rpc.resolve_implementation(address).await
+        {
+            if let Ok(impl_bytecode) = self.rpc.get_code(&impl_addr).await {
+                let impl_analysis = bytecode_analyzer.analyze(&impl_bytecode);
+                analysis.has_selfdestruct = impl_analysis.has_selfdestruct;
+                analysis.has_delegatecall = impl_analysis.has_delegatecall;
+                analysis.has_fee_pattern = impl_analysis.has_fee_pattern;
             }
+            analysis.is_proxy = true;
+            analysis.proxy_type = Some(proxy_type);
+            analysis.implementation_address = Some(impl_addr);
         }
 
         Ok(analysis)
     }
+
+    /// Resolve who ultimately controls this token's privileged powers and classify them —
+    /// the centralization layer `CompatibilityChecker::check`'s `governance` argument feeds
+    /// into `check_governance`. Only the `Ownable`-style `owner()` pattern is resolved; a
+    /// role-based `AccessControl` admin would need `getRoleMember` enumeration over an
+    /// unknown member count, which isn't attempted here. A reverted `owner()` call (no
+    /// such function) is treated the same as "no owner resolved", not an error.
+    pub async fn get_governance_profile(
+        &self,
+        address: &str,
+        capabilities: &TokenCapabilities,
+        access_control: &AccessControl,
+    ) -> Result<GovernanceProfile> {
+        if !access_control.has_owner {
+            return Ok(GovernanceProfile::default());
+        }
+
+        let Some(controller) = self.rpc.get_owner(address).await.unwrap_or(None) else {
+            return Ok(GovernanceProfile::default());
+        };
+
+        let controller_code = self.rpc.get_code(&controller).await?;
+        let controller_type = BytecodeAnalyzer::new().classify_controller(&controller_code);
+
+        Ok(GovernanceProfile {
+            controller: Some(controller),
+            controller_type,
+            controls_mint: capabilities.has_mint,
+            controls_pause: capabilities.has_pause,
+            controls_upgrade: capabilities.is_upgradeable,
+        })
+    }
 }
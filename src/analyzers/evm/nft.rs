@@ -0,0 +1,126 @@
+use anyhow::{Context, Result};
+use crate::types::{Chain, NftCollectionInfo};
+use super::decoder::AbiDecoder;
+use super::rpc::EvmRpcClient;
+
+/// Function selectors for ERC-721 methods
+pub mod selectors {
+    pub const NAME: &str = "0x06fdde03";
+    pub const SYMBOL: &str = "0x95d89b41";
+    pub const TOTAL_SUPPLY: &str = "0x18160ddd";
+    pub const BASE_URI: &str = "0x6c0360eb";
+    pub const TOKEN_URI: &str = "0xc87b56dd";
+    pub const SUPPORTS_INTERFACE: &str = "0x01ffc9a7";
+}
+
+/// EIP-165 interface IDs relevant to NFT migration
+pub const ERC721_INTERFACE_ID: &str = "80ac58cd";
+pub const ERC721_ENUMERABLE_INTERFACE_ID: &str = "780e9d63";
+pub const ERC1155_INTERFACE_ID: &str = "d9b67a26";
+
+/// Analyzes ERC-721 collection metadata
+pub struct NftAnalyzer<'a> {
+    rpc: &'a EvmRpcClient,
+}
+
+impl<'a> NftAnalyzer<'a> {
+    pub fn new(rpc: &'a EvmRpcClient) -> Self {
+        Self { rpc }
+    }
+
+    /// EIP-165 `supportsInterface(bytes4)` probe. A contract with no EIP-165 support at
+    /// all (the call reverts or the RPC errors) is treated the same as "doesn't implement
+    /// this interface" rather than surfaced as an error.
+    pub async fn supports_interface(&self, address: &str, interface_id: &str) -> Result<bool> {
+        let calldata = format!("{}{:0<64}", selectors::SUPPORTS_INTERFACE, interface_id);
+        match self.rpc.eth_call(address, &calldata).await {
+            Ok(result) => Ok(AbiDecoder::decode_bool(&result).unwrap_or(false)),
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Whether this address is an ERC-721 collection
+    pub async fn is_erc721(&self, address: &str) -> Result<bool> {
+        self.supports_interface(address, ERC721_INTERFACE_ID).await
+    }
+
+    /// Whether this address is an ERC-1155 multi-token collection. Daybreak treats it the
+    /// same as ERC-721 for migration planning purposes (both are bridged per-token via the
+    /// Wormhole NFT bridge), but keeps the two checks distinct since nothing else here
+    /// currently reads ERC-1155-specific semantics (batch balances, supply-per-id, etc).
+    pub async fn is_erc1155(&self, address: &str) -> Result<bool> {
+        self.supports_interface(address, ERC1155_INTERFACE_ID).await
+    }
+
+    /// Fetch collection name, symbol, base URI, and (when `ERC721Enumerable` is
+    /// implemented) total supply
+    pub async fn get_collection_info(&self, address: &str, chain: Chain) -> Result<NftCollectionInfo> {
+        let name = self.get_name(address).await?;
+        let symbol = self.get_symbol(address).await?;
+
+        let is_enumerable = self
+            .supports_interface(address, ERC721_ENUMERABLE_INTERFACE_ID)
+            .await
+            .unwrap_or(false);
+        let total_supply = if is_enumerable {
+            self.get_total_supply(address).await.ok()
+        } else {
+            None
+        };
+
+        let base_uri = self.get_base_uri(address).await;
+
+        Ok(NftCollectionInfo {
+            address: address.to_string(),
+            chain,
+            name,
+            symbol,
+            base_uri,
+            total_supply,
+        })
+    }
+
+    async fn get_name(&self, address: &str) -> Result<String> {
+        let result = self
+            .rpc
+            .eth_call(address, selectors::NAME)
+            .await
+            .context("Failed to fetch collection name")?;
+        AbiDecoder::decode_string_or_bytes32(&result).context("Failed to decode collection name")
+    }
+
+    async fn get_symbol(&self, address: &str) -> Result<String> {
+        let result = self
+            .rpc
+            .eth_call(address, selectors::SYMBOL)
+            .await
+            .context("Failed to fetch collection symbol")?;
+        AbiDecoder::decode_string_or_bytes32(&result).context("Failed to decode collection symbol")
+    }
+
+    async fn get_total_supply(&self, address: &str) -> Result<u64> {
+        let result = self.rpc.eth_call(address, selectors::TOTAL_SUPPLY).await?;
+        AbiDecoder::decode_uint256(&result)?
+            .parse::<u64>()
+            .context("Collection total supply doesn't fit in u64")
+    }
+
+    /// `baseURI()` isn't part of the ERC-721 standard, so this first tries the common
+    /// OpenZeppelin getter, falling back to `tokenURI(1)` with its trailing path segment
+    /// stripped — a heuristic that holds for the common `baseURI + tokenId[.json]` layout
+    /// but can't be guaranteed for every collection.
+    async fn get_base_uri(&self, address: &str) -> Option<String> {
+        if let Ok(result) = self.rpc.eth_call(address, selectors::BASE_URI).await {
+            if let Ok(uri) = AbiDecoder::decode_string_or_bytes32(&result) {
+                if !uri.is_empty() {
+                    return Some(uri);
+                }
+            }
+        }
+
+        let calldata = format!("{}{:064x}", selectors::TOKEN_URI, 1u64);
+        let result = self.rpc.eth_call(address, &calldata).await.ok()?;
+        let uri = AbiDecoder::decode_string_or_bytes32(&result).ok()?;
+        uri.rsplit_once('/').map(|(base, _)| format!("{}/", base))
+    }
+}
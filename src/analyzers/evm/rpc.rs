@@ -1,12 +1,134 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use crate::analyzers::fixtures::FixtureStore;
+use crate::types::ProxyType;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
-/// Low-level EVM JSON-RPC client
+/// Initial per-endpoint retry backoff, doubled on each subsequent attempt
+const INITIAL_BACKOFF_MS: u64 = 100;
+/// Backoff cap so a flaky endpoint can't stall a request indefinitely
+const MAX_BACKOFF_MS: u64 = 3_000;
+/// Retry attempts against a single endpoint before rotating to the next one
+const ATTEMPTS_PER_ENDPOINT: u32 = 2;
+
+/// Canonical Multicall3 deployment address — identical across Ethereum, BSC, Polygon,
+/// Arbitrum, Optimism, Base, and Avalanche
+const MULTICALL3_ADDRESS: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
+/// `aggregate3((address target, bool allowFailure, bytes callData)[])` selector
+const AGGREGATE3_SELECTOR: &str = "82ad56cb";
+
+/// Number of exponential latency buckets tracked per method. Bucket `i` covers
+/// `[2^i, 2^(i+1))` milliseconds, so 16 buckets cover up to ~32 seconds before everything
+/// past that collapses into the last bucket.
+const LATENCY_BUCKETS: usize = 16;
+
+/// A streaming latency histogram for one JSON-RPC method: count/min/max plus
+/// power-of-two bucket tallies, from which p50/p90/p99 can be estimated without storing
+/// every individual sample.
+#[derive(Debug, Clone, Default)]
+struct MethodLatency {
+    count: u64,
+    total_ms: u64,
+    min_ms: u64,
+    max_ms: u64,
+    buckets: [u64; LATENCY_BUCKETS],
+}
+
+impl MethodLatency {
+    fn record(&mut self, elapsed_ms: u64) {
+        self.min_ms = if self.count == 0 {
+            elapsed_ms
+        } else {
+            self.min_ms.min(elapsed_ms)
+        };
+        self.max_ms = self.max_ms.max(elapsed_ms);
+        self.count += 1;
+        self.total_ms += elapsed_ms;
+
+        let bucket = (64 - elapsed_ms.max(1).leading_zeros() as usize).min(LATENCY_BUCKETS - 1);
+        self.buckets[bucket] += 1;
+    }
+
+    /// Estimate the `q`th percentile (e.g. 0.5, 0.9, 0.99) from the bucket tallies, as the
+    /// upper bound (in ms) of the first bucket whose cumulative count reaches it
+    fn percentile(&self, q: f64) -> u64 {
+        if self.count == 0 {
+            return 0;
+        }
+        let target = ((self.count as f64) * q).ceil() as u64;
+        let mut cumulative = 0u64;
+        for (i, &bucket_count) in self.buckets.iter().enumerate() {
+            cumulative += bucket_count;
+            if cumulative >= target {
+                return 1u64 << (i + 1);
+            }
+        }
+        self.max_ms
+    }
+}
+
+/// Latency percentiles and request counts for one JSON-RPC method, as reported by
+/// `EvmRpcClient::stats_summary`
+#[derive(Debug, Clone)]
+pub struct MethodStats {
+    pub method: &'static str,
+    pub count: u64,
+    pub min_ms: u64,
+    pub max_ms: u64,
+    pub avg_ms: u64,
+    pub p50_ms: u64,
+    pub p90_ms: u64,
+    pub p99_ms: u64,
+}
+
+/// A snapshot of opt-in RPC instrumentation, for a `--stats` summary
+#[derive(Debug, Clone, Default)]
+pub struct RpcStats {
+    pub total_requests: u64,
+    pub total_retries: u64,
+    pub last_endpoint: Option<String>,
+    pub methods: Vec<MethodStats>,
+}
+
+/// Low-level EVM JSON-RPC client. Holds an ordered list of endpoints and fails over to
+/// the next one on a transport error or HTTP 429/5xx, so a flaky public RPC doesn't fail
+/// the whole request. Endpoints that keep failing accumulate a failure count and sink to
+/// the back of the rotation for the rest of the process, rather than being retried first
+/// on every single call.
 pub struct EvmRpcClient {
     client: Client,
-    url: String,
+    urls: Vec<String>,
+    failure_counts: Vec<AtomicU32>,
+    last_endpoint: Mutex<Option<String>>,
+    stats_enabled: AtomicBool,
+    total_requests: AtomicU64,
+    total_retries: AtomicU64,
+    method_latency: Mutex<HashMap<&'static str, MethodLatency>>,
+    fixtures: Option<Arc<FixtureStore>>,
+}
+
+/// Parsed `eth_feeHistory` response: per-block base fees (one more entry than
+/// `gas_used_ratio` — the node appends its own next-block projection), gas-used
+/// ratios, and the sampled priority-fee percentiles per block
+#[derive(Debug, Clone)]
+pub struct FeeHistory {
+    pub base_fee_per_gas: Vec<f64>,
+    pub gas_used_ratio: Vec<f64>,
+    pub reward: Vec<Vec<f64>>,
+}
+
+/// Parse a `0x`-prefixed hex quantity string into an f64 (fee values can exceed u64 on
+/// some L2s, so this goes through u128)
+fn parse_hex_quantity(value: &Value) -> Option<f64> {
+    value
+        .as_str()
+        .and_then(|s| u128::from_str_radix(s.trim_start_matches("0x"), 16).ok())
+        .map(|v| v as f64)
 }
 
 #[derive(Serialize)]
@@ -23,21 +145,146 @@ struct JsonRpcResponse {
     error: Option<JsonRpcError>,
 }
 
+/// A single entry in a batched JSON-RPC response array, identified by the `id` the
+/// matching request was assigned — the server is free to return these in any order
+#[derive(Deserialize)]
+struct BatchJsonRpcResponse {
+    id: u64,
+    result: Option<Value>,
+    error: Option<JsonRpcError>,
+}
+
 #[derive(Deserialize)]
 struct JsonRpcError {
     message: String,
 }
 
 impl EvmRpcClient {
+    /// Construct a client against a single endpoint
     pub fn new(url: &str) -> Self {
+        Self::with_endpoints(vec![url.to_string()])
+    }
+
+    /// Construct a client with an ordered list of fallback endpoints — `call` tries them
+    /// in order (healthiest first), rotating to the next on a transport error or HTTP
+    /// 429/5xx
+    pub fn with_endpoints(urls: Vec<String>) -> Self {
+        let failure_counts = urls.iter().map(|_| AtomicU32::new(0)).collect();
         Self {
             client: Client::new(),
-            url: url.to_string(),
+            urls,
+            failure_counts,
+            last_endpoint: Mutex::new(None),
+            stats_enabled: AtomicBool::new(false),
+            total_requests: AtomicU64::new(0),
+            total_retries: AtomicU64::new(0),
+            method_latency: Mutex::new(HashMap::new()),
+            fixtures: None,
         }
     }
 
-    /// Make a raw JSON-RPC call with retry logic for rate-limited public RPCs
+    /// Attach a record/replay fixture store — see `analyzers::fixtures::FixtureStore`.
+    /// Every `call` checks it before (and, in `Record` mode, updates it after) touching
+    /// the network, so a full analysis run can be pinned to a fixture file for hermetic
+    /// regression tests.
+    pub fn with_fixtures(mut self, fixtures: Arc<FixtureStore>) -> Self {
+        self.fixtures = Some(fixtures);
+        self
+    }
+
+    /// The endpoint that served the most recent successful call, if any — lets a caller
+    /// report which provider in the fallback list actually answered
+    pub fn last_endpoint(&self) -> Option<String> {
+        self.last_endpoint.lock().ok().and_then(|guard| guard.clone())
+    }
+
+    /// Turn on per-method latency tracking and request/retry counters for `--stats`. Off
+    /// by default so normal runs don't pay for bucket bookkeeping on every call.
+    pub fn enable_stats(&self) {
+        self.stats_enabled.store(true, Ordering::Relaxed);
+    }
+
+    /// Snapshot the current request/retry counts and per-method latency percentiles.
+    /// Methods are sorted by name for stable output. Empty if `enable_stats` was never
+    /// called.
+    pub fn stats_summary(&self) -> RpcStats {
+        let mut methods: Vec<MethodStats> = self
+            .method_latency
+            .lock()
+            .map(|guard| {
+                guard
+                    .iter()
+                    .map(|(&method, latency)| MethodStats {
+                        method,
+                        count: latency.count,
+                        min_ms: latency.min_ms,
+                        max_ms: latency.max_ms,
+                        avg_ms: if latency.count > 0 {
+                            latency.total_ms / latency.count
+                        } else {
+                            0
+                        },
+                        p50_ms: latency.percentile(0.50),
+                        p90_ms: latency.percentile(0.90),
+                        p99_ms: latency.percentile(0.99),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        methods.sort_by_key(|m| m.method);
+
+        RpcStats {
+            total_requests: self.total_requests.load(Ordering::Relaxed),
+            total_retries: self.total_retries.load(Ordering::Relaxed),
+            last_endpoint: self.last_endpoint(),
+            methods,
+        }
+    }
+
+    /// Record a retry (a failed attempt against an endpoint, whether retried in place or
+    /// followed by rotation to the next one) — a no-op unless stats are enabled
+    fn record_retry(&self) {
+        if self.stats_enabled.load(Ordering::Relaxed) {
+            self.total_retries.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Record one successful call's latency under `method` — a no-op unless stats are
+    /// enabled
+    fn record_latency(&self, method: &'static str, elapsed_ms: u64) {
+        if !self.stats_enabled.load(Ordering::Relaxed) {
+            return;
+        }
+        self.total_requests.fetch_add(1, Ordering::Relaxed);
+        if let Ok(mut guard) = self.method_latency.lock() {
+            guard.entry(method).or_default().record(elapsed_ms);
+        }
+    }
+
+    /// Make a raw JSON-RPC call, retrying each endpoint with exponential backoff before
+    /// rotating to the next one. Endpoints are tried in ascending order of accumulated
+    /// failure count rather than strict list order, so a consistently-failing URL is
+    /// deprioritized for the rest of the process instead of eating a retry budget first
+    /// on every call.
     async fn call(&self, method: &'static str, params: Value) -> Result<Value> {
+        if let Some(fixtures) = &self.fixtures {
+            let key = FixtureStore::key(method, &params);
+            if let Some(cached) = fixtures.get(&key)? {
+                return Ok(cached);
+            }
+        }
+
+        let result = self.call_live(method, params.clone()).await?;
+
+        if let Some(fixtures) = &self.fixtures {
+            fixtures.record(&FixtureStore::key(method, &params), result.clone());
+        }
+
+        Ok(result)
+    }
+
+    /// The actual network round-trip `call` wraps with fixture lookup/recording.
+    async fn call_live(&self, method: &'static str, params: Value) -> Result<Value> {
         let request = JsonRpcRequest {
             jsonrpc: "2.0",
             method,
@@ -45,38 +292,210 @@ impl EvmRpcClient {
             id: 1,
         };
 
+        let mut order: Vec<usize> = (0..self.urls.len()).collect();
+        order.sort_by_key(|&i| self.failure_counts[i].load(Ordering::Relaxed));
+
+        let started = Instant::now();
         let mut last_err = None;
-        for attempt in 0..3 {
-            if attempt > 0 {
-                // Exponential backoff: 500ms, 1500ms
-                tokio::time::sleep(std::time::Duration::from_millis(500 * (1 << attempt))).await;
+        for (attempt_idx, &endpoint_idx) in order.iter().enumerate() {
+            let url = &self.urls[endpoint_idx];
+            if attempt_idx > 0 {
+                eprintln!("  \u{2192} retrying via fallback RPC endpoint: {}", url);
             }
 
-            let response = match self.client.post(&self.url).json(&request).send().await {
-                Ok(r) => r,
-                Err(e) => {
-                    last_err = Some(anyhow::anyhow!("Failed to send RPC request: {}", e));
+            let mut backoff_ms = INITIAL_BACKOFF_MS;
+            let mut endpoint_failed = false;
+            for attempt in 0..ATTEMPTS_PER_ENDPOINT {
+                if attempt > 0 {
+                    tokio::time::sleep(std::time::Duration::from_millis(backoff_ms)).await;
+                    backoff_ms = (backoff_ms * 2).min(MAX_BACKOFF_MS);
+                }
+
+                let response = match self.client.post(url).json(&request).send().await {
+                    Ok(r) => r,
+                    Err(e) => {
+                        last_err = Some(anyhow::anyhow!("Failed to send RPC request to {}: {}", url, e));
+                        endpoint_failed = true;
+                        self.record_retry();
+                        continue;
+                    }
+                };
+
+                let status = response.status();
+                if status.as_u16() == 429 || status.is_server_error() {
+                    last_err = Some(anyhow::anyhow!("RPC endpoint {} returned {}", url, status));
+                    endpoint_failed = true;
+                    self.record_retry();
                     continue;
                 }
-            };
 
-            let rpc_response: JsonRpcResponse = match response.json().await {
-                Ok(r) => r,
-                Err(e) => {
-                    last_err = Some(anyhow::anyhow!("Failed to parse RPC response: {}", e));
+                let rpc_response: JsonRpcResponse = match response.json().await {
+                    Ok(r) => r,
+                    Err(e) => {
+                        last_err = Some(anyhow::anyhow!("Failed to parse RPC response from {}: {}", url, e));
+                        endpoint_failed = true;
+                        self.record_retry();
+                        continue;
+                    }
+                };
+
+                if let Some(error) = rpc_response.error {
+                    last_err = Some(anyhow::anyhow!("RPC error from {}: {}", url, error.message));
+                    endpoint_failed = true;
+                    self.record_retry();
                     continue;
                 }
-            };
 
-            if let Some(error) = rpc_response.error {
-                last_err = Some(anyhow::anyhow!("RPC error: {}", error.message));
-                continue;
+                if let Ok(mut last) = self.last_endpoint.lock() {
+                    *last = Some(url.clone());
+                }
+                self.record_latency(method, started.elapsed().as_millis() as u64);
+                return rpc_response.result.context("No result in RPC response");
+            }
+
+            if endpoint_failed {
+                self.failure_counts[endpoint_idx].fetch_add(1, Ordering::Relaxed);
             }
+        }
+
+        Err(last_err.unwrap_or_else(|| anyhow::anyhow!("RPC call failed after exhausting all endpoints")))
+    }
+
+    /// Send several heterogeneous JSON-RPC requests as a single batched array (JSON-RPC
+    /// 2.0 batching) — one HTTP round-trip instead of one per call. Each sub-request gets
+    /// a distinct `id`; responses are re-associated back to their request by that `id`,
+    /// since the server may return them in a different order. Falls back to sequential
+    /// `call`s (on the normal per-endpoint failover/backoff path) if the endpoint doesn't
+    /// support batching — signaled by a non-array response or a transport-level error.
+    pub async fn call_batch(
+        &self,
+        calls: Vec<(&'static str, Value)>,
+    ) -> Result<Vec<Result<Value>>> {
+        let requests: Vec<JsonRpcRequest> = calls
+            .iter()
+            .enumerate()
+            .map(|(id, (method, params))| JsonRpcRequest {
+                jsonrpc: "2.0",
+                method: *method,
+                params: params.clone(),
+                id: id as u64,
+            })
+            .collect();
+
+        match self.call_batch_raw(&requests).await {
+            Ok(responses) => Ok(Self::reassociate_batch(responses, calls.len())),
+            Err(_) => {
+                let mut results = Vec::with_capacity(calls.len());
+                for (method, params) in calls {
+                    results.push(self.call(method, params).await);
+                }
+                Ok(results)
+            }
+        }
+    }
 
-            return rpc_response.result.context("No result in RPC response");
+    /// POST the whole batch as one JSON array, retrying/rotating endpoints exactly like
+    /// a single `call`. Bails (triggering `call_batch`'s sequential fallback) if the
+    /// response isn't a JSON array — some public endpoints reject or mishandle batching.
+    async fn call_batch_raw(&self, requests: &[JsonRpcRequest]) -> Result<Vec<BatchJsonRpcResponse>> {
+        let mut order: Vec<usize> = (0..self.urls.len()).collect();
+        order.sort_by_key(|&i| self.failure_counts[i].load(Ordering::Relaxed));
+
+        let started = Instant::now();
+        let mut last_err = None;
+        for (attempt_idx, &endpoint_idx) in order.iter().enumerate() {
+            let url = &self.urls[endpoint_idx];
+            if attempt_idx > 0 {
+                eprintln!("  \u{2192} retrying batch via fallback RPC endpoint: {}", url);
+            }
+
+            let mut backoff_ms = INITIAL_BACKOFF_MS;
+            let mut endpoint_failed = false;
+            for attempt in 0..ATTEMPTS_PER_ENDPOINT {
+                if attempt > 0 {
+                    tokio::time::sleep(std::time::Duration::from_millis(backoff_ms)).await;
+                    backoff_ms = (backoff_ms * 2).min(MAX_BACKOFF_MS);
+                }
+
+                let response = match self.client.post(url).json(requests).send().await {
+                    Ok(r) => r,
+                    Err(e) => {
+                        last_err = Some(anyhow::anyhow!("Failed to send RPC batch to {}: {}", url, e));
+                        endpoint_failed = true;
+                        self.record_retry();
+                        continue;
+                    }
+                };
+
+                let status = response.status();
+                if status.as_u16() == 429 || status.is_server_error() {
+                    last_err = Some(anyhow::anyhow!("RPC endpoint {} returned {}", url, status));
+                    endpoint_failed = true;
+                    self.record_retry();
+                    continue;
+                }
+
+                let body: Value = match response.json().await {
+                    Ok(v) => v,
+                    Err(e) => {
+                        last_err = Some(anyhow::anyhow!("Failed to parse RPC batch response from {}: {}", url, e));
+                        endpoint_failed = true;
+                        self.record_retry();
+                        continue;
+                    }
+                };
+
+                let Some(array) = body.as_array() else {
+                    anyhow::bail!("endpoint {} does not support batched requests", url);
+                };
+
+                let responses: Vec<BatchJsonRpcResponse> = array
+                    .iter()
+                    .filter_map(|v| serde_json::from_value(v.clone()).ok())
+                    .collect();
+
+                if let Ok(mut last) = self.last_endpoint.lock() {
+                    *last = Some(url.clone());
+                }
+                // One batched round-trip can't be attributed to a single method, so it's
+                // tracked under a synthetic "batch" bucket rather than per sub-call.
+                self.record_latency("batch", started.elapsed().as_millis() as u64);
+                return Ok(responses);
+            }
+
+            if endpoint_failed {
+                self.failure_counts[endpoint_idx].fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| anyhow::anyhow!("RPC batch call failed after exhausting all endpoints")))
+    }
+
+    /// Re-associate batched responses to their original request order by `id`, since the
+    /// server may reorder them; a missing/unparseable entry becomes an error for that slot
+    /// rather than failing the whole batch
+    fn reassociate_batch(
+        responses: Vec<BatchJsonRpcResponse>,
+        expected_len: usize,
+    ) -> Vec<Result<Value>> {
+        let mut ordered: Vec<Option<Result<Value>>> = (0..expected_len).map(|_| None).collect();
+
+        for response in responses {
+            let idx = response.id as usize;
+            if idx >= expected_len {
+                continue;
+            }
+            let value = match response.error {
+                Some(error) => Err(anyhow::anyhow!("RPC error: {}", error.message)),
+                None => response.result.context("No result in RPC response"),
+            };
+            ordered[idx] = Some(value);
         }
 
-        Err(last_err.unwrap_or_else(|| anyhow::anyhow!("RPC call failed after retries")))
+        ordered
+            .into_iter()
+            .map(|entry| entry.unwrap_or_else(|| Err(anyhow::anyhow!("missing response in RPC batch"))))
+            .collect()
     }
 
     /// Execute eth_call to read contract data
@@ -106,6 +525,17 @@ impl EvmRpcClient {
             .context("eth_getCode result is not a string")
     }
 
+    /// Get the contract's current code hash via `eth_getProof`, without transferring the
+    /// full bytecode. Used to check a cached analysis for staleness cheaply.
+    pub async fn get_code_hash(&self, address: &str) -> Result<String> {
+        let params = json!([address, Vec::<String>::new(), "latest"]);
+        let result = self.call("eth_getProof", params).await?;
+        result["codeHash"]
+            .as_str()
+            .map(|s| s.to_string())
+            .context("eth_getProof result missing codeHash")
+    }
+
     /// Read storage slot at a given position
     pub async fn get_storage_at(&self, address: &str, slot: &str) -> Result<String> {
         let params = json!([address, slot, "latest"]);
@@ -121,10 +551,395 @@ impl EvmRpcClient {
     pub async fn get_eip1967_implementation(&self, proxy_address: &str) -> Result<Option<String>> {
         const EIP1967_IMPL_SLOT: &str =
             "0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc";
+        self.read_address_slot(proxy_address, EIP1967_IMPL_SLOT)
+            .await
+    }
+
+    /// Get EIP-1967 beacon implementation: read the beacon address from its storage slot,
+    /// then call the beacon's `implementation()` to resolve the real logic contract.
+    /// Beacon slot: 0xa3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50
+    pub async fn get_eip1967_beacon_implementation(
+        &self,
+        proxy_address: &str,
+    ) -> Result<Option<String>> {
+        const EIP1967_BEACON_SLOT: &str =
+            "0xa3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50";
+
+        let Some(beacon) = self
+            .read_address_slot(proxy_address, EIP1967_BEACON_SLOT)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        // implementation() selector: 5c60da1b
+        let result = self.eth_call(&beacon, "0x5c60da1b").await?;
+        let result = result.trim_start_matches("0x");
+        if result.len() >= 40 && !result.chars().all(|c| c == '0') {
+            Ok(Some(format!("0x{}", &result[result.len() - 40..])))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get EIP-1822 (UUPS) implementation address from the proxiable storage slot
+    /// Slot: 0xc5f16f0fcc639fa48a6947836d9850f504798523bf8c9a3a87d5876cf622bec8
+    pub async fn get_eip1822_implementation(&self, proxy_address: &str) -> Result<Option<String>> {
+        const EIP1822_PROXIABLE_SLOT: &str =
+            "0xc5f16f0fcc639fa48a6947836d9850f504798523bf8c9a3a87d5876cf622bec8";
+        self.read_address_slot(proxy_address, EIP1822_PROXIABLE_SLOT)
+            .await
+    }
+
+    /// Get the OpenZeppelin `TransparentUpgradeableProxy` admin address from its storage
+    /// slot, if set. A nonzero admin marks this as an older-style transparent proxy — the
+    /// logic contract itself still lives in the standard EIP-1967 implementation slot.
+    /// Slot: 0xb53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103
+    pub async fn get_transparent_admin(&self, proxy_address: &str) -> Result<Option<String>> {
+        const TRANSPARENT_ADMIN_SLOT: &str =
+            "0xb53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103";
+        self.read_address_slot(proxy_address, TRANSPARENT_ADMIN_SLOT)
+            .await
+    }
+
+    /// Resolve the logic contract behind any of the common proxy patterns, trying each in
+    /// turn and returning the first match along with which pattern matched: EIP-1967,
+    /// its beacon variant, EIP-1822 (UUPS), the OpenZeppelin transparent-proxy admin slot,
+    /// then the EIP-1167 minimal clone pattern. All-zero storage (or a non-matching
+    /// bytecode pattern) is treated as "not this kind" rather than an error, so the chain
+    /// falls through to the next candidate.
+    pub async fn resolve_implementation(
+        &self,
+        address: &str,
+    ) -> Result<Option<(String, ProxyType)>> {
+        // The transparent-proxy admin slot and the generic EIP-1967 implementation slot
+        // are both set on a real `TransparentUpgradeableProxy` — check the admin slot
+        // first, or every transparent proxy falls through to the generic `Eip1967` arm
+        // below and the `TransparentUpgradeable` label is never actually reached.
+        if self.get_transparent_admin(address).await?.is_some() {
+            if let Some(impl_addr) = self.get_eip1967_implementation(address).await? {
+                return Ok(Some((impl_addr, ProxyType::TransparentUpgradeable)));
+            }
+        }
+
+        if let Some(impl_addr) = self.get_eip1967_implementation(address).await? {
+            return Ok(Some((impl_addr, ProxyType::Eip1967)));
+        }
+
+        if let Some(impl_addr) = self.get_eip1967_beacon_implementation(address).await? {
+            return Ok(Some((impl_addr, ProxyType::Eip1967)));
+        }
+
+        if let Some(impl_addr) = self.get_eip1822_implementation(address).await? {
+            return Ok(Some((impl_addr, ProxyType::Eip1822)));
+        }
+
+        let bytecode = self.get_code(address).await?;
+        if let Some(impl_addr) = Self::decode_minimal_proxy_target(&bytecode) {
+            return Ok(Some((impl_addr, ProxyType::MinimalProxy)));
+        }
+
+        Ok(None)
+    }
+
+    /// Extract the embedded implementation address from EIP-1167 minimal-proxy bytecode:
+    /// `363d3d373d3d3d363d73<20-byte impl>5af43d82803e903d91602b57fd5bf3`
+    fn decode_minimal_proxy_target(bytecode: &str) -> Option<String> {
+        const MINIMAL_PROXY_PREFIX: &str = "363d3d373d3d3d363d73";
+
+        let bytecode = bytecode.trim_start_matches("0x").to_lowercase();
+        if !bytecode.starts_with(MINIMAL_PROXY_PREFIX) {
+            return None;
+        }
+
+        let addr_start = MINIMAL_PROXY_PREFIX.len();
+        let addr_end = addr_start + 40;
+        bytecode
+            .get(addr_start..addr_end)
+            .map(|addr| format!("0x{}", addr))
+    }
+
+    /// Call `owner()` (selector `8da5cb5b`) and decode the result as an address — the live
+    /// counterpart to `detect_access_control`'s bytecode-only `has_owner` flag, used to
+    /// resolve who actually holds that power. `Ok(None)` for an all-zero word (ownership
+    /// renounced); an `Err` if the call reverts is left to the caller to decide whether
+    /// that means "no `owner()` to call" or a real RPC failure.
+    pub async fn get_owner(&self, address: &str) -> Result<Option<String>> {
+        let result = self.eth_call(address, "0x8da5cb5b").await?;
+        let result = result.trim_start_matches("0x");
+        if result.len() >= 40 && !result.chars().all(|c| c == '0') {
+            Ok(Some(format!("0x{}", &result[result.len() - 40..])))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Call `balanceOf(address)` (selector `70a08231`) against an ERC-20 contract and
+    /// decode the result as a `u128` — used by `SupplyReconciler` to read a bridge
+    /// custody contract's actual locked balance directly from the source chain, rather
+    /// than trusting a cached or self-reported figure.
+    pub async fn balance_of(&self, token_address: &str, holder: &str) -> Result<u128> {
+        let holder_hex = holder.trim_start_matches("0x");
+        if holder_hex.len() != 40 {
+            bail!("not a 20-byte EVM address: {}", holder);
+        }
+        let calldata = format!("0x70a08231{:0>64}", holder_hex);
+        let result = self.eth_call(token_address, &calldata).await?;
+        let hex = result.trim_start_matches("0x");
+        if hex.is_empty() || hex.chars().all(|c| c == '0') {
+            return Ok(0);
+        }
+        u128::from_str_radix(hex.trim_start_matches('0'), 16)
+            .context("balanceOf result does not fit in a u128")
+    }
+
+    /// The EIP-155 chain id this endpoint actually answers for — used to catch a
+    /// misconfigured RPC URL (e.g. a Polygon endpoint that's quietly been pointed at
+    /// Ethereum) by cross-checking against a known-good value like `ChainSpec::chain_id`.
+    pub async fn get_chain_id(&self) -> Result<u64> {
+        let result = self.call("eth_chainId", json!([])).await?;
+        let hex = result
+            .as_str()
+            .context("eth_chainId result is not a string")?;
+        u64::from_str_radix(hex.trim_start_matches("0x"), 16).context("invalid eth_chainId hex")
+    }
+
+    /// Current chain head block number — used to pick a bounded `eth_getLogs` scan window
+    /// when a caller only has "latest" to work from (e.g. `LogScanHolderAnalyzer`).
+    pub async fn get_block_number(&self) -> Result<u64> {
+        let result = self.call("eth_blockNumber", json!([])).await?;
+        let hex = result
+            .as_str()
+            .context("eth_blockNumber result is not a string")?;
+        u64::from_str_radix(hex.trim_start_matches("0x"), 16).context("invalid eth_blockNumber hex")
+    }
+
+    /// Fetch logs for `address` matching `topics` (position-ordered, `eth_getLogs`
+    /// convention: `topics[0]` is the event signature hash) over `[from_block, to_block]`
+    /// inclusive. Returns the raw log entries — decoding `data`/`topics` is left to the
+    /// caller, since different event shapes decode differently.
+    pub async fn get_logs(
+        &self,
+        address: &str,
+        topics: &[&str],
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<Value>> {
+        let params = json!([{
+            "address": address,
+            "topics": topics,
+            "fromBlock": format!("0x{:x}", from_block),
+            "toBlock": format!("0x{:x}", to_block),
+        }]);
+        let result = self.call("eth_getLogs", params).await?;
+        result
+            .as_array()
+            .cloned()
+            .context("eth_getLogs result is not an array")
+    }
+
+    /// Fetch fee history over the trailing `block_count` blocks with the given reward
+    /// percentiles, for deriving the current EIP-1559 base fee and priority fee
+    pub async fn fee_history(
+        &self,
+        block_count: u64,
+        reward_percentiles: &[f64],
+    ) -> Result<FeeHistory> {
+        let params = json!([format!("0x{:x}", block_count), "latest", reward_percentiles]);
+        let result = self.call("eth_feeHistory", params).await?;
+
+        let base_fee_per_gas = result["baseFeePerGas"]
+            .as_array()
+            .context("eth_feeHistory response missing baseFeePerGas")?
+            .iter()
+            .filter_map(parse_hex_quantity)
+            .collect();
+
+        let gas_used_ratio = result["gasUsedRatio"]
+            .as_array()
+            .context("eth_feeHistory response missing gasUsedRatio")?
+            .iter()
+            .filter_map(|v| v.as_f64())
+            .collect();
+
+        let reward = result["reward"]
+            .as_array()
+            .map(|blocks| {
+                blocks
+                    .iter()
+                    .map(|block| {
+                        block
+                            .as_array()
+                            .map(|vals| vals.iter().filter_map(parse_hex_quantity).collect())
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(FeeHistory {
+            base_fee_per_gas,
+            gas_used_ratio,
+            reward,
+        })
+    }
+
+    /// Whether Multicall3 is deployed at its canonical address on this chain. A token
+    /// analysis flow that fires many `eth_call`s should check this once up front and fall
+    /// back to sequential `eth_call`s if it's false, rather than let `multicall` fail.
+    pub async fn is_multicall3_deployed(&self) -> bool {
+        matches!(self.get_code(MULTICALL3_ADDRESS).await.as_deref(), Ok(code) if code.len() > 2)
+    }
+
+    /// Batch several `eth_call`s into a single Multicall3 `aggregate3` request, collapsing
+    /// what would otherwise be a dozen network round-trips (name, symbol, decimals,
+    /// capability selectors, owner, ...) into one. `allowFailure` is always set, so one
+    /// reverting leg doesn't fail the whole batch — its slot in the result is `None`.
+    /// Returns one entry per input call, in the same order.
+    pub async fn multicall(&self, calls: &[(String, String)]) -> Result<Vec<Option<String>>> {
+        let calldata = Self::encode_aggregate3(calls)?;
+        let result = self.eth_call(MULTICALL3_ADDRESS, &calldata).await?;
+        Self::decode_aggregate3_result(&result, calls.len())
+    }
+
+    /// ABI-encode `aggregate3(Call3[])` for the given (target, callData) pairs
+    fn encode_aggregate3(calls: &[(String, String)]) -> Result<String> {
+        let mut tuples = Vec::with_capacity(calls.len());
+        for (target, data) in calls {
+            tuples.push(Self::encode_call3(target, data)?);
+        }
+
+        // Dynamic array of dynamic tuples: head is one offset word per element (relative
+        // to the start of the array's content, i.e. right after the length word), tail is
+        // each tuple's own encoding concatenated in order.
+        let head_size = tuples.len() * 32;
+        let mut heads = Vec::new();
+        let mut tails = Vec::new();
+        let mut offset = head_size;
+        for tuple in &tuples {
+            heads.extend_from_slice(&Self::encode_uint(offset as u128));
+            tails.extend_from_slice(tuple);
+            offset += tuple.len();
+        }
+
+        let mut array_content = Self::encode_uint(tuples.len() as u128).to_vec();
+        array_content.extend(heads);
+        array_content.extend(tails);
+
+        let mut out = Self::decode_hex_bytes(AGGREGATE3_SELECTOR)?;
+        out.extend_from_slice(&Self::encode_uint(32)); // offset to the array argument
+        out.extend(array_content);
+
+        Ok(format!("0x{}", Self::encode_hex_bytes(&out)))
+    }
+
+    /// ABI-encode one `Call3 { target, allowFailure: true, callData }` tuple
+    fn encode_call3(target: &str, call_data: &str) -> Result<Vec<u8>> {
+        let call_data = Self::decode_hex_bytes(call_data.trim_start_matches("0x"))?;
+
+        let mut head = Vec::with_capacity(96);
+        head.extend_from_slice(&Self::encode_address(target)?);
+        head.extend_from_slice(&Self::encode_uint(1)); // allowFailure = true
+        head.extend_from_slice(&Self::encode_uint(96)); // offset to callData, relative to tuple start
+
+        let mut out = head;
+        out.extend(Self::encode_bytes(&call_data));
+        Ok(out)
+    }
+
+    /// Decode the `(bool success, bytes returnData)[]` result of `aggregate3`, mapping
+    /// failed entries (or anything truncated/malformed) to `None` rather than erroring the
+    /// whole batch
+    fn decode_aggregate3_result(hex: &str, expected_len: usize) -> Result<Vec<Option<String>>> {
+        let bytes = Self::decode_hex_bytes(hex.trim_start_matches("0x"))?;
+        let read_uint = |offset: usize| -> Option<usize> {
+            bytes
+                .get(offset..offset + 32)
+                .map(|w| w.iter().fold(0u128, |acc, b| (acc << 8) | *b as u128) as usize)
+        };
+
+        // Top-level return is a single dynamic array: offset word, then length, then one
+        // offset per element (relative to the start of the array content)
+        let array_offset = read_uint(0).unwrap_or(0);
+        let len = read_uint(array_offset).unwrap_or(0);
+        let content_start = array_offset + 32;
+
+        let mut results = Vec::with_capacity(expected_len);
+        for i in 0..len {
+            let rel_offset = match read_uint(content_start + i * 32) {
+                Some(o) => o,
+                None => {
+                    results.push(None);
+                    continue;
+                }
+            };
+            let tuple_start = content_start + rel_offset;
+
+            // Tuple layout: bool success (32 bytes), then offset to bytes returnData
+            let success = read_uint(tuple_start).unwrap_or(0) != 0;
+            let data_rel_offset = read_uint(tuple_start + 32).unwrap_or(0);
+            let data_len_offset = tuple_start + data_rel_offset;
+            let data_len = read_uint(data_len_offset).unwrap_or(0);
+            let data_start = data_len_offset + 32;
+
+            let return_data = bytes.get(data_start..data_start + data_len);
+            match (success, return_data) {
+                (true, Some(data)) => results.push(Some(format!("0x{}", Self::encode_hex_bytes(data)))),
+                _ => results.push(None),
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Right-pad ABI `bytes` encoding: length word followed by the data, padded to a
+    /// multiple of 32 bytes
+    fn encode_bytes(data: &[u8]) -> Vec<u8> {
+        let mut out = Self::encode_uint(data.len() as u128).to_vec();
+        out.extend_from_slice(data);
+        let padding = (32 - (data.len() % 32)) % 32;
+        out.extend(std::iter::repeat(0u8).take(padding));
+        out
+    }
+
+    /// Left-pad a `uint256`/`bool` value into its 32-byte ABI word
+    fn encode_uint(value: u128) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[16..].copy_from_slice(&value.to_be_bytes());
+        word
+    }
+
+    /// Left-pad a 20-byte address into its 32-byte ABI word
+    fn encode_address(address: &str) -> Result<[u8; 32]> {
+        let bytes = Self::decode_hex_bytes(address.trim_start_matches("0x"))?;
+        if bytes.len() != 20 {
+            anyhow::bail!("'{}' is not a 20-byte address", address);
+        }
+        let mut word = [0u8; 32];
+        word[12..].copy_from_slice(&bytes);
+        Ok(word)
+    }
+
+    /// Parse a (non-`0x`-prefixed) hex string into raw bytes
+    fn decode_hex_bytes(hex: &str) -> Result<Vec<u8>> {
+        if hex.len() % 2 != 0 {
+            anyhow::bail!("hex string '{}' has odd length", hex);
+        }
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+            .collect::<std::result::Result<Vec<u8>, _>>()
+            .context("invalid hex string")
+    }
+
+    /// Render raw bytes as a lowercase hex string (no `0x` prefix)
+    fn encode_hex_bytes(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
 
-        let storage = self
-            .get_storage_at(proxy_address, EIP1967_IMPL_SLOT)
-            .await?;
+    /// Read a storage slot and extract the trailing 20 bytes as an address, if non-zero
+    async fn read_address_slot(&self, address: &str, slot: &str) -> Result<Option<String>> {
+        let storage = self.get_storage_at(address, slot).await?;
 
         // Storage returns 32 bytes, address is last 20 bytes
         // If all zeros, no implementation set
@@ -0,0 +1,126 @@
+use serde::Deserialize;
+use std::collections::HashSet;
+
+/// Built-in 4-byte selector signatures, bundled at compile time so detection works with
+/// zero setup. Ships as a sibling JSON file (rather than the old `capability_selectors`
+/// consts) so the categories below can be extended without recompiling.
+const BUILTIN_SIGNATURES_JSON: &str = include_str!("signatures.json");
+
+/// A raw signature file: 4-byte selector hex strings (no `0x`) grouped by the capability
+/// they imply.
+#[derive(Debug, Deserialize, Default)]
+struct SignatureFile {
+    #[serde(default)]
+    mint: Vec<String>,
+    #[serde(default)]
+    burn: Vec<String>,
+    #[serde(default)]
+    pause: Vec<String>,
+    #[serde(default)]
+    blacklist: Vec<String>,
+    #[serde(default)]
+    permit: Vec<String>,
+    #[serde(default)]
+    fee: Vec<String>,
+    #[serde(default)]
+    owner: Vec<String>,
+    #[serde(default)]
+    role_admin: Vec<String>,
+    #[serde(default)]
+    cap: Vec<String>,
+}
+
+/// Loaded 4-byte function-selector signatures used for capability/fee-pattern detection.
+/// Built from the bundled defaults, optionally extended by a user signature file so new
+/// fee setters or blacklist variants can be added without recompiling.
+#[derive(Debug, Clone, Default)]
+pub struct SignatureDatabase {
+    pub mint: HashSet<String>,
+    pub burn: HashSet<String>,
+    pub pause: HashSet<String>,
+    pub blacklist: HashSet<String>,
+    pub permit: HashSet<String>,
+    pub fee: HashSet<String>,
+    pub owner: HashSet<String>,
+    pub role_admin: HashSet<String>,
+    pub cap: HashSet<String>,
+}
+
+impl SignatureDatabase {
+    /// Load the bundled defaults, extended with entries from `extra_path` if it exists
+    /// and parses — a missing or malformed override file silently falls back to defaults
+    /// rather than failing analysis.
+    pub fn load(extra_path: Option<&str>) -> Self {
+        let mut db = Self::from_json(BUILTIN_SIGNATURES_JSON).unwrap_or_default();
+
+        if let Some(path) = extra_path {
+            if let Ok(contents) = std::fs::read_to_string(path) {
+                if let Ok(extra) = Self::from_json(&contents) {
+                    db.merge(extra);
+                }
+            }
+        }
+
+        db
+    }
+
+    fn from_json(contents: &str) -> serde_json::Result<Self> {
+        let file: SignatureFile = serde_json::from_str(contents)?;
+        Ok(Self {
+            mint: file.mint.into_iter().collect(),
+            burn: file.burn.into_iter().collect(),
+            pause: file.pause.into_iter().collect(),
+            blacklist: file.blacklist.into_iter().collect(),
+            permit: file.permit.into_iter().collect(),
+            fee: file.fee.into_iter().collect(),
+            owner: file.owner.into_iter().collect(),
+            role_admin: file.role_admin.into_iter().collect(),
+            cap: file.cap.into_iter().collect(),
+        })
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.mint.extend(other.mint);
+        self.burn.extend(other.burn);
+        self.pause.extend(other.pause);
+        self.blacklist.extend(other.blacklist);
+        self.permit.extend(other.permit);
+        self.fee.extend(other.fee);
+        self.owner.extend(other.owner);
+        self.role_admin.extend(other.role_admin);
+        self.cap.extend(other.cap);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_signatures_parse() {
+        let db = SignatureDatabase::load(None);
+        assert!(db.mint.contains("40c10f19"));
+        assert!(db.burn.contains("42966c68"));
+        assert!(db.fee.contains("69fe0e2d"));
+        assert!(db.owner.contains("8da5cb5b"));
+        assert!(db.role_admin.contains("91d14854"));
+        assert!(db.cap.contains("355274ea"));
+    }
+
+    #[test]
+    fn test_missing_override_file_falls_back_to_defaults() {
+        let db = SignatureDatabase::load(Some("/nonexistent/signatures.json"));
+        assert!(db.mint.contains("40c10f19"));
+    }
+
+    #[test]
+    fn test_merge_adds_without_dropping_defaults() {
+        let mut db = SignatureDatabase::load(None);
+        db.merge(SignatureDatabase {
+            fee: HashSet::from(["deadbeef".to_string()]),
+            ..Default::default()
+        });
+        assert!(db.fee.contains("69fe0e2d"));
+        assert!(db.fee.contains("deadbeef"));
+    }
+}
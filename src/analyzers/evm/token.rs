@@ -11,6 +11,12 @@ pub mod selectors {
     pub const TOTAL_SUPPLY: &str = "0x18160ddd";
 }
 
+/// Max individual calls packed into a single `aggregate3` multicall — keeps the encoded
+/// calldata and decoded response within sizes most public RPCs accept, rather than risk
+/// an oversized batch getting rejected outright. 100 calls is 25 tokens' worth of the
+/// four metadata selectors each.
+const MAX_CALLS_PER_MULTICALL: usize = 100;
+
 /// Analyzes ERC-20 token metadata
 pub struct TokenAnalyzer<'a> {
     rpc: &'a EvmRpcClient,
@@ -21,11 +27,22 @@ impl<'a> TokenAnalyzer<'a> {
         Self { rpc }
     }
 
-    /// Fetch complete token information
+    /// Fetch complete token information. Tries a single Multicall3 `aggregate3` round
+    /// trip first (one request instead of four); falls back to the per-selector
+    /// `eth_call`s when Multicall3 isn't deployed on this chain or the batch call itself
+    /// fails outright (an individual reverting selector inside a successful batch is
+    /// still handled by the multicall path, not treated as a reason to fall back).
     pub async fn get_token_info(&self, address: &str, chain: Chain) -> Result<TokenInfo> {
         // Normalize address to checksummed format
         let address = Self::normalize_address(address)?;
 
+        if self.rpc.is_multicall3_deployed().await {
+            let calls = Self::metadata_calls(&address);
+            if let Ok(raw) = self.rpc.multicall(&calls).await {
+                return Self::decode_token_info(&address, chain, &raw);
+            }
+        }
+
         // Fetch all metadata in parallel
         let (name, symbol, decimals, total_supply) = tokio::try_join!(
             self.get_name(&address),
@@ -44,6 +61,94 @@ impl<'a> TokenAnalyzer<'a> {
         })
     }
 
+    /// Fetch metadata for many tokens, packing as many of their `name`/`symbol`/
+    /// `decimals`/`totalSupply` reads as fit under `MAX_CALLS_PER_MULTICALL` into each
+    /// aggregate call — cutting request volume by roughly 4x per token versus calling
+    /// `get_token_info` once per address. Falls back to `get_token_info` one-by-one when
+    /// Multicall3 isn't deployed on this chain, or per-chunk if an aggregate call itself
+    /// fails. Each input address gets its own `Result`, so one bad address doesn't sink
+    /// the whole batch.
+    pub async fn get_token_infos(
+        &self,
+        addresses: &[&str],
+        chain: Chain,
+    ) -> Result<Vec<Result<TokenInfo>>> {
+        let normalized: Vec<String> = addresses
+            .iter()
+            .map(|a| Self::normalize_address(a))
+            .collect::<Result<_>>()?;
+
+        if !self.rpc.is_multicall3_deployed().await {
+            let mut out = Vec::with_capacity(normalized.len());
+            for address in &normalized {
+                out.push(self.get_token_info(address, chain).await);
+            }
+            return Ok(out);
+        }
+
+        let tokens_per_chunk = (MAX_CALLS_PER_MULTICALL / 4).max(1);
+        let mut out = Vec::with_capacity(normalized.len());
+        for chunk in normalized.chunks(tokens_per_chunk) {
+            let mut calls = Vec::with_capacity(chunk.len() * 4);
+            for address in chunk {
+                calls.extend(Self::metadata_calls(address));
+            }
+
+            match self.rpc.multicall(&calls).await {
+                Ok(raw) => {
+                    for (i, address) in chunk.iter().enumerate() {
+                        let slice = &raw[i * 4..i * 4 + 4];
+                        out.push(Self::decode_token_info(address, chain, slice));
+                    }
+                }
+                Err(_) => {
+                    for address in chunk {
+                        out.push(self.get_token_info(address, chain).await);
+                    }
+                }
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// The four metadata selector calls for one token, in the fixed order
+    /// `decode_token_info` expects them back in.
+    fn metadata_calls(address: &str) -> Vec<(String, String)> {
+        vec![
+            (address.to_string(), selectors::NAME.to_string()),
+            (address.to_string(), selectors::SYMBOL.to_string()),
+            (address.to_string(), selectors::DECIMALS.to_string()),
+            (address.to_string(), selectors::TOTAL_SUPPLY.to_string()),
+        ]
+    }
+
+    /// Decode a multicall's four `(name, symbol, decimals, totalSupply)` results — in
+    /// that order, matching `metadata_calls` — into a `TokenInfo`. A reverted selector
+    /// (`None` in the multicall result) fails the whole token, same as an `eth_call`
+    /// error would on the non-batched path.
+    fn decode_token_info(address: &str, chain: Chain, raw: &[Option<String>]) -> Result<TokenInfo> {
+        let name_raw = raw[0].as_deref().context("name() reverted in multicall")?;
+        let symbol_raw = raw[1].as_deref().context("symbol() reverted in multicall")?;
+        let decimals_raw = raw[2].as_deref().context("decimals() reverted in multicall")?;
+        let supply_raw = raw[3]
+            .as_deref()
+            .context("totalSupply() reverted in multicall")?;
+
+        Ok(TokenInfo {
+            address: address.to_string(),
+            chain,
+            name: AbiDecoder::decode_string_or_bytes32(name_raw)
+                .context("Failed to decode token name")?,
+            symbol: AbiDecoder::decode_string_or_bytes32(symbol_raw)
+                .context("Failed to decode token symbol")?,
+            decimals: AbiDecoder::decode_uint8(decimals_raw)
+                .context("Failed to decode token decimals")?,
+            total_supply: AbiDecoder::decode_uint256(supply_raw)
+                .context("Failed to decode total supply")?,
+        })
+    }
+
     /// Normalize and validate Ethereum address
     fn normalize_address(address: &str) -> Result<String> {
         let address = address.trim();
@@ -73,7 +178,7 @@ impl<'a> TokenAnalyzer<'a> {
             .await
             .context("Failed to fetch token name")?;
 
-        AbiDecoder::decode_string(&result).context("Failed to decode token name")
+        AbiDecoder::decode_string_or_bytes32(&result).context("Failed to decode token name")
     }
 
     async fn get_symbol(&self, address: &str) -> Result<String> {
@@ -83,7 +188,7 @@ impl<'a> TokenAnalyzer<'a> {
             .await
             .context("Failed to fetch token symbol")?;
 
-        AbiDecoder::decode_string(&result).context("Failed to decode token symbol")
+        AbiDecoder::decode_string_or_bytes32(&result).context("Failed to decode token symbol")
     }
 
     async fn get_decimals(&self, address: &str) -> Result<u8> {
@@ -0,0 +1,165 @@
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// How a `FixtureStore` resolves requests.
+///
+/// `Record` serves every request live and caches the response as it comes back; `Replay`
+/// resolves purely from what was previously recorded and fails loudly on a miss, so a
+/// behavior change that starts issuing a request the fixture doesn't cover is caught
+/// immediately instead of silently falling through to a live network call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixtureMode {
+    Record,
+    Replay,
+}
+
+/// Capture/replay layer for the JSON-RPC (`EvmRpcClient`) and Etherscan-style HTTP
+/// (`HolderAnalyzer`) requests the analysis pipeline makes, so a full run can be
+/// regression-locked without live network access. Requests are keyed by a caller-chosen
+/// label (the RPC method name, or the request URL) plus their parameters, and the whole
+/// map round-trips to a single on-disk JSON file.
+///
+/// Note: this covers the two call sites named in the request that added it — live
+/// end-to-end golden-file tests asserting an exact `JsonOutput::format_analysis` string
+/// would additionally need this crate to expose a library target (a `src/lib.rs`) so
+/// `tests/*.rs` integration tests could reach `commands::run_scan` and friends; that's a
+/// separate, larger structural change and is left undone here. `call_batch`'s Multicall3
+/// path isn't covered either — a batch's sub-calls don't have individually meaningful keys
+/// the way a single `eth_call`/`eth_getLogs` does — so fixture-backed runs should prefer
+/// `EvmAnalyzer` paths that go through single calls.
+pub struct FixtureStore {
+    mode: FixtureMode,
+    path: PathBuf,
+    entries: Mutex<HashMap<String, Value>>,
+}
+
+impl FixtureStore {
+    /// Load an existing fixture file, or start empty (the common case when beginning a
+    /// fresh `Record` run).
+    pub fn load(path: impl Into<PathBuf>, mode: FixtureMode) -> Result<Self> {
+        let path = path.into();
+        let entries = if path.exists() {
+            let raw = std::fs::read_to_string(&path)
+                .with_context(|| format!("reading fixture file {}", path.display()))?;
+            serde_json::from_str(&raw)
+                .with_context(|| format!("parsing fixture file {}", path.display()))?
+        } else {
+            HashMap::new()
+        };
+        Ok(Self {
+            mode,
+            path,
+            entries: Mutex::new(entries),
+        })
+    }
+
+    /// Build a stable request key from a label (RPC method or request URL) and its
+    /// parameters.
+    pub fn key(label: &str, params: &Value) -> String {
+        format!("{}:{}", label, params)
+    }
+
+    /// Resolve `key` against the store. In `Replay` mode, a miss is an error rather than
+    /// `Ok(None)` — the whole point is to fail loudly instead of quietly reaching the
+    /// network. In `Record` mode a miss is `Ok(None)`, signaling the caller to make the
+    /// live request and `record` its result.
+    pub fn get(&self, key: &str) -> Result<Option<Value>> {
+        let entries = self.entries.lock().unwrap();
+        match entries.get(key) {
+            Some(value) => Ok(Some(value.clone())),
+            None if self.mode == FixtureMode::Replay => {
+                anyhow::bail!(
+                    "fixture miss for key '{}' in replay mode ({})",
+                    key,
+                    self.path.display()
+                )
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Cache a live response under `key` — a no-op outside `Record` mode.
+    pub fn record(&self, key: &str, value: Value) {
+        if self.mode != FixtureMode::Record {
+            return;
+        }
+        self.entries.lock().unwrap().insert(key.to_string(), value);
+    }
+
+    /// Persist everything recorded so far to the fixture file — a no-op outside `Record`
+    /// mode.
+    pub fn save(&self) -> Result<()> {
+        if self.mode != FixtureMode::Record {
+            return Ok(());
+        }
+        let entries = self.entries.lock().unwrap();
+        let json = serde_json::to_string_pretty(&*entries).context("serializing fixtures")?;
+        std::fs::write(&self.path, json)
+            .with_context(|| format!("writing fixture file {}", self.path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_then_replay_roundtrip() {
+        let dir = std::env::temp_dir().join(format!(
+            "daybreak-fixture-test-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("fixture.json");
+
+        let recorder = FixtureStore::load(&path, FixtureMode::Record).unwrap();
+        let key = FixtureStore::key("eth_call", &serde_json::json!(["0xabc", "0x123"]));
+        assert_eq!(recorder.get(&key).unwrap(), None);
+        recorder.record(&key, serde_json::json!("0xdeadbeef"));
+        recorder.save().unwrap();
+
+        let replayer = FixtureStore::load(&path, FixtureMode::Replay).unwrap();
+        assert_eq!(
+            replayer.get(&key).unwrap(),
+            Some(serde_json::json!("0xdeadbeef"))
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_replay_miss_errors_loudly() {
+        let dir = std::env::temp_dir().join(format!(
+            "daybreak-fixture-test-miss-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("fixture.json");
+        std::fs::write(&path, "{}").unwrap();
+
+        let replayer = FixtureStore::load(&path, FixtureMode::Replay).unwrap();
+        let key = FixtureStore::key("eth_call", &serde_json::json!(["0xabc"]));
+        assert!(replayer.get(&key).is_err());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_record_mode_miss_is_ok_none() {
+        let dir = std::env::temp_dir().join(format!(
+            "daybreak-fixture-test-record-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("fixture.json");
+
+        let recorder = FixtureStore::load(&path, FixtureMode::Record).unwrap();
+        let key = FixtureStore::key("eth_call", &serde_json::json!(["0xabc"]));
+        assert_eq!(recorder.get(&key).unwrap(), None);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
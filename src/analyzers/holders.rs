@@ -1,12 +1,27 @@
+use crate::analyzers::fixtures::FixtureStore;
 use crate::types::{Chain, HolderData, HolderInfo};
+use alloy::primitives::U256;
 use anyhow::{Context, Result};
+use async_trait::async_trait;
 use reqwest::Client;
 use serde::Deserialize;
+use std::sync::Arc;
+
+/// Where `HolderData` comes from — an Etherscan-style holder-list API (`HolderAnalyzer`,
+/// PRO-tier only on most explorers), or reconstructed directly from on-chain `Transfer`
+/// logs via RPC (`LogScanHolderAnalyzer`, see `analyzers::evm::logscan`). Letting callers
+/// depend on the trait rather than a concrete analyzer is what makes the log-scan
+/// fallback a drop-in replacement when no API key is configured.
+#[async_trait]
+pub trait HolderSource {
+    async fn get_holders(&self, address: &str, chain: Chain) -> Result<HolderData>;
+}
 
 /// Fetches holder data from block explorers
 pub struct HolderAnalyzer {
     client: Client,
     api_key: Option<String>,
+    fixtures: Option<Arc<FixtureStore>>,
 }
 
 #[derive(Deserialize)]
@@ -28,9 +43,16 @@ impl HolderAnalyzer {
         Self {
             client: Client::new(),
             api_key,
+            fixtures: None,
         }
     }
 
+    /// Attach a record/replay fixture store — see `analyzers::fixtures::FixtureStore`.
+    pub fn with_fixtures(mut self, fixtures: Arc<FixtureStore>) -> Self {
+        self.fixtures = Some(fixtures);
+        self
+    }
+
     /// Fetch top holders for a token
     pub async fn get_holders(&self, address: &str, chain: Chain) -> Result<HolderData> {
         let api_key = self
@@ -44,15 +66,7 @@ impl HolderAnalyzer {
             base_url, address, api_key
         );
 
-        let response: EtherscanResponse = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .context("Failed to fetch holder data")?
-            .json()
-            .await
-            .context("Failed to parse holder response")?;
+        let response: EtherscanResponse = self.fetch(&url).await?;
 
         if response.status != "1" {
             anyhow::bail!(
@@ -67,41 +81,89 @@ impl HolderAnalyzer {
         self.calculate_concentration(holders).await
     }
 
-    /// Calculate holder concentration from raw data
-    async fn calculate_concentration(&self, holders: Vec<EtherscanHolder>) -> Result<HolderData> {
-        // Parse balances and calculate percentages
-        let mut top_holders: Vec<HolderInfo> = Vec::new();
-        let mut total_balance: f64 = 0.0;
+    /// GET `url` and deserialize its JSON body, going through the fixture store first
+    /// (if one is attached) — `Replay` resolves purely from the fixture and errors loudly
+    /// on a miss; `Record` serves the request live and caches the raw JSON response.
+    async fn fetch<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T> {
+        let key = self
+            .fixtures
+            .as_ref()
+            .map(|f| FixtureStore::key(url, &serde_json::Value::Null));
 
-        for holder in &holders {
-            let balance: f64 = holder.balance.parse().unwrap_or(0.0);
-            total_balance += balance;
+        if let (Some(fixtures), Some(key)) = (&self.fixtures, &key) {
+            if let Some(cached) = fixtures.get(key)? {
+                return serde_json::from_value(cached).context("Failed to parse cached holder response");
+            }
         }
 
-        for holder in holders {
-            let balance: f64 = holder.balance.parse().unwrap_or(0.0);
-            let percentage = if total_balance > 0.0 {
-                (balance / total_balance) * 100.0
-            } else {
-                0.0
-            };
-
-            top_holders.push(HolderInfo {
-                address: holder.address,
-                balance: holder.balance,
-                percentage,
-            });
+        let body: serde_json::Value = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .context("Failed to fetch holder data")?
+            .json()
+            .await
+            .context("Failed to parse holder response")?;
+
+        if let (Some(fixtures), Some(key)) = (&self.fixtures, &key) {
+            fixtures.record(key, body.clone());
         }
 
+        serde_json::from_value(body).context("Failed to parse holder response")
+    }
+
+    /// Calculate holder concentration from raw data
+    ///
+    /// ERC-20 balances are 256-bit integers — an 18-decimal token with a large supply
+    /// easily exceeds `f64`'s 53-bit mantissa, so parsing balances straight into `f64`
+    /// silently loses precision right around the concentration thresholds that drive the
+    /// risk score. Sum and divide in `U256` instead, and only convert to a display `f64`
+    /// at the very end.
+    async fn calculate_concentration(&self, holders: Vec<EtherscanHolder>) -> Result<HolderData> {
+        let parsed: Vec<(String, String, U256)> = holders
+            .into_iter()
+            .map(|h| {
+                let balance = h.balance.parse::<U256>().unwrap_or(U256::ZERO);
+                (h.address, h.balance, balance)
+            })
+            .collect();
+
+        let total_balance = parsed
+            .iter()
+            .fold(U256::ZERO, |acc, (_, _, balance)| acc + balance);
+
+        let top_holders: Vec<HolderInfo> = parsed
+            .into_iter()
+            .map(|(address, balance_raw, balance)| HolderInfo {
+                address,
+                balance: balance_raw,
+                percentage: Self::percentage(balance, total_balance),
+            })
+            .collect();
+
         let top_10_concentration: f64 = top_holders.iter().map(|h| h.percentage).sum();
 
         Ok(HolderData {
             top_holders,
             top_10_concentration,
             total_holders: None, // Would require separate API call
+            scanned_window: None, // Full holder list, not a block-range reconstruction
         })
     }
 
+    /// Exact `balance / total * 100` computed in integer space — scaled by 10,000 for two
+    /// extra digits of headroom — then converted to a display `f64` rounded to one decimal.
+    fn percentage(balance: U256, total: U256) -> f64 {
+        if total.is_zero() {
+            return 0.0;
+        }
+
+        let scaled = balance.saturating_mul(U256::from(10_000u32)) / total;
+        let scaled: u64 = scaled.try_into().unwrap_or(u64::MAX);
+        ((scaled as f64 / 100.0) * 10.0).round() / 10.0
+    }
+
     /// Get the appropriate API URL for the chain
     fn get_api_url(chain: Chain) -> Result<&'static str> {
         match chain {
@@ -112,6 +174,90 @@ impl HolderAnalyzer {
             Chain::Base => Ok("https://api.basescan.org/api"),
             Chain::Bsc => Ok("https://api.bscscan.com/api"),
             Chain::Avalanche => Ok("https://api.snowtrace.io/api"),
+            Chain::Solana => anyhow::bail!("holder data via Etherscan-style APIs is not available for Solana"),
         }
     }
 }
+
+#[async_trait]
+impl HolderSource for HolderAnalyzer {
+    async fn get_holders(&self, address: &str, chain: Chain) -> Result<HolderData> {
+        HolderAnalyzer::get_holders(self, address, chain).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentage_zero_total_yields_zero() {
+        assert_eq!(HolderAnalyzer::percentage(U256::from(5u32), U256::ZERO), 0.0);
+    }
+
+    #[test]
+    fn test_percentage_exact_half() {
+        let total = U256::from(1_000_000u64);
+        let half = U256::from(500_000u64);
+        assert_eq!(HolderAnalyzer::percentage(half, total), 50.0);
+    }
+
+    #[test]
+    fn test_percentage_exact_past_2_64() {
+        // A supply just over 2^64 (beyond u64, well within f64's still-exact integer
+        // range, but close enough to its 53-bit mantissa that dividing via f64 starts
+        // rounding) — U256 division stays exact.
+        let total = U256::from(1u128 << 64) + U256::from(1u64);
+        let holder = U256::from(1u128 << 63); // ~50%
+        let percentage = HolderAnalyzer::percentage(holder, total);
+        assert!((percentage - 50.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_percentage_exact_past_2_96_diverges_from_f64() {
+        // A balance/supply pair around 2^96 — well past f64's 2^53 exact-integer limit.
+        // The naive `as f64` parse-then-divide path collapses distinct balances to the
+        // same float and so can't tell these two holders apart; U256 division can.
+        let total: U256 = U256::from(1u128) << 96;
+        let holder_a = (U256::from(1u128) << 96) / U256::from(3u32);
+        let holder_b = holder_a + U256::from(1u32);
+
+        let pct_a = HolderAnalyzer::percentage(holder_a, total);
+        let pct_b = HolderAnalyzer::percentage(holder_b, total);
+        // Both round to the same displayed one-decimal percentage (as expected at this
+        // scale), but the naive f64 path fails even sooner: parsing these balances
+        // straight into f64 collapses them to the exact same float well before rounding
+        // ever enters the picture, since the raw values exceed 2^53.
+        assert!((pct_a - 33.3).abs() < 0.1);
+        assert!((pct_b - 33.3).abs() < 0.1);
+
+        let holder_a_f64: f64 = holder_a.to_string().parse().unwrap();
+        let holder_b_f64: f64 = holder_b.to_string().parse().unwrap();
+        assert_eq!(
+            holder_a_f64, holder_b_f64,
+            "f64 parsing already can't distinguish these two balances"
+        );
+        assert_ne!(holder_a, holder_b, "U256 keeps them distinct");
+    }
+
+    #[tokio::test]
+    async fn test_calculate_concentration_sums_to_total_exactly() {
+        let analyzer = HolderAnalyzer::new(None);
+        let holders = vec![
+            EtherscanHolder {
+                address: "0x1".to_string(),
+                balance: (U256::from(1u128) << 96).to_string(),
+            },
+            EtherscanHolder {
+                address: "0x2".to_string(),
+                balance: (U256::from(1u128) << 96).to_string(),
+            },
+        ];
+
+        let data = analyzer.calculate_concentration(holders).await.unwrap();
+        assert_eq!(data.top_holders.len(), 2);
+        assert_eq!(data.top_holders[0].percentage, 50.0);
+        assert_eq!(data.top_holders[1].percentage, 50.0);
+        assert_eq!(data.top_10_concentration, 100.0);
+    }
+}
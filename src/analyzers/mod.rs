@@ -1,13 +1,127 @@
 pub mod bridges;
+pub mod cache;
+pub mod chain_checker;
 pub mod compatibility;
 pub mod discovery;
 pub mod evm;
+pub mod fixtures;
 pub mod holders;
+pub mod solana;
+pub mod solana_program;
 pub mod volume;
+pub mod wormhole;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use crate::types::{
+    AccessControl, BytecodeAnalysis, Chain, GovernanceProfile, TokenCapabilities, TokenInfo,
+};
 
 pub use bridges::BridgeDetector;
-pub use compatibility::CompatibilityChecker;
+pub use cache::{AnalysisCache, CachedAnalysis};
+pub use chain_checker::{ChainChecker, CheckerKind, DeployCost};
+pub use compatibility::{CompatibilityChecker, Rule};
 pub use discovery::TokenDiscovery;
-pub use evm::EvmAnalyzer;
-pub use holders::HolderAnalyzer;
+pub use evm::{EvmAnalyzer, LogScanHolderAnalyzer, MethodStats, RpcStats};
+pub use fixtures::{FixtureMode, FixtureStore};
+pub use holders::{HolderAnalyzer, HolderSource};
+pub use solana_program::SplAnalyzer;
 pub use volume::VolumeAnalyzer;
+
+/// A source-chain analyzer: given a token's address on its origin chain, produce the
+/// chain-agnostic facts (`TokenInfo`, `TokenCapabilities`, `BytecodeAnalysis`) that
+/// `CompatibilityChecker` and `RiskScorer` reason about. Adding a new origin chain is a
+/// matter of adding an impl here, not editing every downstream analyzer.
+#[async_trait]
+pub trait SourceChainAnalyzer {
+    async fn get_token_info(&self, address: &str) -> Result<TokenInfo>;
+    async fn get_capabilities(&self, address: &str) -> Result<TokenCapabilities>;
+    async fn analyze_program(&self, address: &str) -> Result<BytecodeAnalysis>;
+    async fn get_access_control(&self, address: &str) -> Result<AccessControl>;
+
+    /// Resolve and classify who controls this token's privileged powers (mint, pause,
+    /// upgrade) — see `EvmAnalyzer::get_governance_profile` and
+    /// `SplAnalyzer::get_governance_profile` for what each origin chain can and can't
+    /// resolve.
+    async fn get_governance_profile(
+        &self,
+        address: &str,
+        capabilities: &TokenCapabilities,
+        access_control: &AccessControl,
+    ) -> Result<GovernanceProfile>;
+
+    /// Downcast to the underlying EVM RPC client, for EVM-specific callers (e.g. live gas
+    /// price estimation) that don't have a chain-agnostic equivalent yet. `None` for
+    /// non-EVM origins.
+    fn as_evm_rpc(&self) -> Option<&evm::EvmRpcClient> {
+        None
+    }
+}
+
+#[async_trait]
+impl SourceChainAnalyzer for EvmAnalyzer {
+    async fn get_token_info(&self, address: &str) -> Result<TokenInfo> {
+        EvmAnalyzer::get_token_info(self, address).await
+    }
+
+    async fn get_capabilities(&self, address: &str) -> Result<TokenCapabilities> {
+        EvmAnalyzer::get_capabilities(self, address).await
+    }
+
+    async fn analyze_program(&self, address: &str) -> Result<BytecodeAnalysis> {
+        EvmAnalyzer::analyze_bytecode(self, address).await
+    }
+
+    async fn get_access_control(&self, address: &str) -> Result<AccessControl> {
+        EvmAnalyzer::get_access_control(self, address).await
+    }
+
+    async fn get_governance_profile(
+        &self,
+        address: &str,
+        capabilities: &TokenCapabilities,
+        access_control: &AccessControl,
+    ) -> Result<GovernanceProfile> {
+        EvmAnalyzer::get_governance_profile(self, address, capabilities, access_control).await
+    }
+
+    fn as_evm_rpc(&self) -> Option<&evm::EvmRpcClient> {
+        Some(self.rpc())
+    }
+}
+
+#[async_trait]
+impl SourceChainAnalyzer for SplAnalyzer {
+    async fn get_token_info(&self, address: &str) -> Result<TokenInfo> {
+        SplAnalyzer::get_token_info(self, address).await
+    }
+
+    async fn get_capabilities(&self, address: &str) -> Result<TokenCapabilities> {
+        SplAnalyzer::get_capabilities(self, address).await
+    }
+
+    async fn analyze_program(&self, address: &str) -> Result<BytecodeAnalysis> {
+        SplAnalyzer::analyze_program(self, address).await
+    }
+
+    async fn get_access_control(&self, address: &str) -> Result<AccessControl> {
+        SplAnalyzer::get_access_control(self, address).await
+    }
+
+    async fn get_governance_profile(
+        &self,
+        address: &str,
+        capabilities: &TokenCapabilities,
+        access_control: &AccessControl,
+    ) -> Result<GovernanceProfile> {
+        SplAnalyzer::get_governance_profile(self, address, capabilities, access_control).await
+    }
+}
+
+/// Resolve the `SourceChainAnalyzer` impl for a chain
+pub fn source_chain_analyzer(chain: Chain, rpc_url: Option<String>) -> Box<dyn SourceChainAnalyzer> {
+    match chain {
+        Chain::Solana => Box::new(SplAnalyzer::new(rpc_url)),
+        evm_chain => Box::new(EvmAnalyzer::new(evm_chain, rpc_url)),
+    }
+}
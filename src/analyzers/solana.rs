@@ -1,39 +1,170 @@
-use anyhow::Result;
+use crate::types::Chain;
+use anyhow::{Context, Result};
 use reqwest::Client;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::program_pack::Pack;
+use spl_token::state::{Account as TokenAccount, Mint};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
-/// Checks Solana-related information
+/// Rough NTT manager config account size — no Anchor IDL is vendored here to derive an
+/// exact figure, so this is a conservative estimate based on the account's known fields
+/// (owner, mint, mode, rate limits + window, transceiver registrations, threshold, paused
+/// flag) plus Anchor's 8-byte discriminator.
+const NTT_MANAGER_CONFIG_SIZE: usize = 400;
+
+/// Rough NTT transceiver config account size, same caveat as above
+const NTT_TRANSCEIVER_CONFIG_SIZE: usize = 300;
+
+/// SOL/USD price used when both live price sources are unreachable
+const FALLBACK_SOL_PRICE_USD: f64 = 150.0;
+
+/// Rent exemption for an SPL token account (165 bytes), used when the RPC is unreachable
+const FALLBACK_RENT_COST_SOL: f64 = 0.00203928;
+
+/// Summed rent exemption for a full NTT deployment (mint + manager + transceiver + token
+/// account), used when the RPC is unreachable
+const FALLBACK_NTT_DEPLOYMENT_COST_SOL: f64 = 2.5;
+
+/// How long a fetched SOL price is trusted before the next call re-fetches it
+const PRICE_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Checks Solana-related information: rent costs (live, via `getMinimumBalanceForRentExemption`)
+/// and the SOL/USD spot price (live, via CoinGecko with a Jupiter fallback), so deployment
+/// cost estimates track actual network and market conditions rather than a point-in-time
+/// guess. Every method degrades gracefully to a flat constant if its live source is
+/// unreachable, so callers never have to handle a hard failure here.
 pub struct SolanaChecker {
     client: Client,
+    rpc_url: String,
+    price_cache: Mutex<Option<(f64, Instant)>>,
 }
 
 impl SolanaChecker {
     pub fn new() -> Self {
+        Self::with_rpc_url(Chain::Solana.default_rpc_url().to_string())
+    }
+
+    pub fn with_rpc_url(rpc_url: String) -> Self {
         Self {
             client: Client::new(),
+            rpc_url,
+            price_cache: Mutex::new(None),
         }
     }
 
-    /// Estimate rent cost for SPL token account
+    /// Live rent-exemption minimum for an SPL token account (165 bytes), falling back to
+    /// the historical `FALLBACK_RENT_COST_SOL` figure if the RPC is unreachable
     pub fn estimate_rent_cost(&self) -> f64 {
-        // SPL Token account size is 165 bytes
-        // Rent exemption is ~0.00203928 SOL (as of 2024)
-        0.00203928
+        self.rent_exempt_sol(TokenAccount::LEN)
+            .unwrap_or(FALLBACK_RENT_COST_SOL)
     }
 
-    /// Estimate NTT deployment costs on Solana
+    /// Sum of live rent-exemption minimums for every account an NTT deployment creates:
+    /// the token mint, the NTT manager config, the transceiver config, and the token
+    /// account the manager custodies funds in. Falls back to a flat
+    /// `FALLBACK_NTT_DEPLOYMENT_COST_SOL` estimate if the RPC is unreachable.
     pub fn estimate_ntt_deployment_cost(&self) -> f64 {
-        // NTT Manager program account rent
-        // NTT Transceiver account rent
-        // Token mint account rent
-        // Estimated total: ~2-3 SOL
-        2.5
+        let sizes = [
+            Mint::LEN,
+            NTT_MANAGER_CONFIG_SIZE,
+            NTT_TRANSCEIVER_CONFIG_SIZE,
+            TokenAccount::LEN,
+        ];
+        sizes
+            .iter()
+            .map(|&size| self.rent_exempt_sol(size))
+            .sum::<Result<f64>>()
+            .unwrap_or(FALLBACK_NTT_DEPLOYMENT_COST_SOL)
+    }
+
+    /// Rent-exemption minimum for an account of `data_len` bytes, in SOL
+    fn rent_exempt_sol(&self, data_len: usize) -> Result<f64> {
+        let client =
+            RpcClient::new_with_commitment(self.rpc_url.clone(), CommitmentConfig::confirmed());
+        let lamports = client
+            .get_minimum_balance_for_rent_exemption(data_len)
+            .context("Failed to get rent exemption — is the Solana RPC reachable?")?;
+        Ok(lamports as f64 / 1_000_000_000.0)
     }
 
-    /// Get current SOL price (placeholder - would use real API)
+    /// The combined USD cost to stand up a full NTT deployment on Solana: the summed
+    /// rent-exemption lamports from `estimate_ntt_deployment_cost`, priced in USD via
+    /// `get_sol_price`.
+    pub async fn estimate_ntt_deployment_cost_usd(&self) -> f64 {
+        self.estimate_ntt_deployment_cost() * self.get_sol_price().await.unwrap_or(FALLBACK_SOL_PRICE_USD)
+    }
+
+    /// Get the current SOL/USD spot price, preferring Jupiter's price API (Solana-native,
+    /// no rate-limit key needed) and falling back to CoinGecko, with a short in-memory
+    /// cache so a burst of calls (e.g. pricing several NTT legs in one report) doesn't
+    /// hit either API more than once every `PRICE_CACHE_TTL`.
     pub async fn get_sol_price(&self) -> Result<f64> {
-        // In production, fetch from CoinGecko/Jupiter
-        // For demo, use a reasonable estimate
-        Ok(150.0)
+        if let Some((price, fetched_at)) = *self.price_cache.lock().unwrap() {
+            if fetched_at.elapsed() < PRICE_CACHE_TTL {
+                return Ok(price);
+            }
+        }
+
+        let price = match self.fetch_price_jupiter().await {
+            Ok(price) => price,
+            Err(_) => self.fetch_price_coingecko().await?,
+        };
+
+        *self.price_cache.lock().unwrap() = Some((price, Instant::now()));
+        Ok(price)
+    }
+
+    async fn fetch_price_jupiter(&self) -> Result<f64> {
+        #[derive(serde::Deserialize)]
+        struct JupiterPriceResponse {
+            data: std::collections::HashMap<String, JupiterPriceEntry>,
+        }
+        #[derive(serde::Deserialize)]
+        struct JupiterPriceEntry {
+            price: String,
+        }
+
+        let resp: JupiterPriceResponse = self
+            .client
+            .get("https://price.jup.ag/v6/price?ids=SOL")
+            .send()
+            .await
+            .context("Jupiter price request failed")?
+            .json()
+            .await
+            .context("Jupiter price response was not valid JSON")?;
+
+        resp.data
+            .get("SOL")
+            .context("Jupiter response missing SOL entry")?
+            .price
+            .parse()
+            .context("Jupiter SOL price was not a number")
+    }
+
+    async fn fetch_price_coingecko(&self) -> Result<f64> {
+        #[derive(serde::Deserialize)]
+        struct CoinGeckoPriceResponse {
+            solana: CoinGeckoUsdPrice,
+        }
+        #[derive(serde::Deserialize)]
+        struct CoinGeckoUsdPrice {
+            usd: f64,
+        }
+
+        let resp: CoinGeckoPriceResponse = self
+            .client
+            .get("https://api.coingecko.com/api/v3/simple/price?ids=solana&vs_currencies=usd")
+            .send()
+            .await
+            .context("CoinGecko price request failed")?
+            .json()
+            .await
+            .context("CoinGecko price response was not valid JSON")?;
+
+        Ok(resp.solana.usd)
     }
 }
 
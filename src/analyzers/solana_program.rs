@@ -0,0 +1,142 @@
+use crate::types::{
+    AccessControl, BytecodeAnalysis, BytecodeComplexity, Chain, ControllerType, GovernanceProfile,
+    TokenCapabilities, TokenInfo,
+};
+use anyhow::{Context, Result};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{bpf_loader_upgradeable, program_pack::Pack, pubkey::Pubkey, system_program};
+use spl_token::state::Mint;
+use std::str::FromStr;
+
+/// Analyzes an SPL mint as an NTT migration source, mirroring `EvmAnalyzer` for non-EVM
+/// origins. Mint authority, freeze authority, and owning-program upgradeability map onto
+/// the same `has_mint`/`has_blacklist`/`is_upgradeable` fields EVM contracts populate, so
+/// `CompatibilityChecker` and `RiskScorer` don't need to know the origin chain.
+pub struct SplAnalyzer {
+    client: RpcClient,
+}
+
+impl SplAnalyzer {
+    pub fn new(rpc_url: Option<String>) -> Self {
+        let url = rpc_url.unwrap_or_else(|| Chain::Solana.default_rpc_url().to_string());
+        Self {
+            client: RpcClient::new(url),
+        }
+    }
+
+    fn fetch_mint(&self, address: &str) -> Result<(Pubkey, Mint, usize)> {
+        let pubkey = Pubkey::from_str(address).context("invalid Solana mint address")?;
+        let account = self
+            .client
+            .get_account(&pubkey)
+            .context("failed to fetch mint account — is the Solana RPC reachable?")?;
+        let mint = Mint::unpack(&account.data).context("account is not an SPL token mint")?;
+        Ok((pubkey, mint, account.data.len()))
+    }
+
+    /// Fetch basic token information (decimals, supply). SPL mints don't carry a name or
+    /// symbol on-chain, so those are left blank for the caller to fill in if needed.
+    pub async fn get_token_info(&self, address: &str) -> Result<TokenInfo> {
+        let (pubkey, mint, _) = self.fetch_mint(address)?;
+        Ok(TokenInfo {
+            address: pubkey.to_string(),
+            chain: Chain::Solana,
+            name: String::new(),
+            symbol: String::new(),
+            decimals: mint.decimals,
+            total_supply: mint.supply.to_string(),
+        })
+    }
+
+    /// Detect token capabilities from the mint's authorities and its owning program
+    pub async fn get_capabilities(&self, address: &str) -> Result<TokenCapabilities> {
+        let (_, mint, _) = self.fetch_mint(address)?;
+        Ok(TokenCapabilities {
+            has_mint: mint.mint_authority.is_some(),
+            has_burn: true, // the SPL token program always exposes Burn to any holder
+            has_pause: false,
+            has_blacklist: mint.freeze_authority.is_some(),
+            has_permit: false,
+            is_upgradeable: self.owning_program_is_upgradeable(address)?,
+            // `approve` doesn't exist as a concept on SPL mints (delegation is a separate,
+            // always-revocable instruction) — the EVM approve race has no Solana analog.
+            has_unmitigated_approve_race: false,
+            // The base SPL Token program has no transfer-hook concept (Token-2022's
+            // TransferHook extension is a distinct mint type this analyzer doesn't parse).
+            has_transfer_hook: false,
+        })
+    }
+
+    /// Detect who can administer the mint. Unlike EVM, there's no ownership pattern to
+    /// guess at: the mint authority itself is an on-chain field, reassignable directly via
+    /// `set_authority` (the SPL analog of `transferOwnership`), so its mere presence means
+    /// mint authority is externally controllable.
+    pub async fn get_access_control(&self, address: &str) -> Result<AccessControl> {
+        let (_, mint, _) = self.fetch_mint(address)?;
+        Ok(AccessControl {
+            has_owner: mint.mint_authority.is_some(),
+            has_role_based_access: false,
+        })
+    }
+
+    /// Analyze the mint account as the Solana analog of `BytecodeAnalysis`. There's no
+    /// proxy/opcode analysis to do on an SPL mint, so this mostly reports size.
+    pub async fn analyze_program(&self, address: &str) -> Result<BytecodeAnalysis> {
+        let (_, _, data_len) = self.fetch_mint(address)?;
+        Ok(BytecodeAnalysis {
+            size_bytes: data_len,
+            complexity: BytecodeComplexity::Simple,
+            ..Default::default()
+        })
+    }
+
+    /// Resolve who controls the mint by inspecting the mint authority account itself.
+    /// Unlike EVM there's no registry of known multisig/timelock program IDs that could be
+    /// verified from this sandbox (e.g. Squads) — guessing at one risks misclassifying a
+    /// centralized signer as a safe multisig, so this only distinguishes a plain wallet
+    /// (owned by the System Program) from some other program-owned account, which is left
+    /// `Unknown` rather than guessed at.
+    pub async fn get_governance_profile(
+        &self,
+        address: &str,
+        capabilities: &TokenCapabilities,
+        _access_control: &AccessControl,
+    ) -> Result<GovernanceProfile> {
+        let (_, mint, _) = self.fetch_mint(address)?;
+        let authority: Option<Pubkey> = mint.mint_authority.into();
+        let Some(authority) = authority else {
+            return Ok(GovernanceProfile::default());
+        };
+
+        let controller_type = match self.client.get_account(&authority) {
+            Ok(account) if account.owner == system_program::id() => ControllerType::Eoa,
+            Ok(_) => ControllerType::Unknown,
+            // No account at this address yet — an unfunded wallet is still an EOA.
+            Err(_) => ControllerType::Eoa,
+        };
+
+        Ok(GovernanceProfile {
+            controller: Some(authority.to_string()),
+            controller_type,
+            controls_mint: capabilities.has_mint,
+            controls_pause: false,
+            controls_upgrade: capabilities.is_upgradeable,
+        })
+    }
+
+    /// Whether the program that owns this mint (the SPL Token or Token-2022 program) is
+    /// itself deployed via the upgradeable BPF loader — the Solana analog of an EVM proxy
+    fn owning_program_is_upgradeable(&self, address: &str) -> Result<bool> {
+        let pubkey = Pubkey::from_str(address).context("invalid Solana mint address")?;
+        let mint_account = self
+            .client
+            .get_account(&pubkey)
+            .context("failed to fetch mint account")?;
+        let is_upgradeable = self
+            .client
+            .get_account(&mint_account.owner)
+            .map(|program_account| program_account.owner == bpf_loader_upgradeable::id())
+            .unwrap_or(false);
+        Ok(is_upgradeable)
+    }
+}
@@ -3,21 +3,40 @@ use anyhow::{Context, Result};
 use reqwest::Client;
 use serde::Deserialize;
 
-/// Fetches 24h transfer volume from Etherscan to calculate NTT rate limits
+/// Fetches transfer history from Etherscan to calculate NTT rate limits
 pub struct VolumeAnalyzer {
     client: Client,
     api_key: Option<String>,
 }
 
-/// Rate limit recommendation based on on-chain transfer volume
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+/// Number of transfers requested per Etherscan page
+const PAGE_SIZE: u64 = 1000;
+/// Cap on pages fetched, so a high-activity token doesn't turn one `analyze` call into an
+/// unbounded crawl of its entire transfer history
+const MAX_PAGES: u64 = 5;
+
+/// Rate limit recommendation based on a token-bucket model of on-chain transfer volume.
+/// NTT's rate limiter is a token bucket: `recommended_daily_limit` is the bucket capacity,
+/// which refills linearly back to full over 24h, and `recommended_per_tx_limit` bounds any
+/// single transfer so one whale withdrawal can't exhaust the bucket outright.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
 pub struct RateLimitRecommendation {
     /// 24h transfer count from Etherscan
     pub daily_transfers: u64,
-    /// Recommended daily inbound limit (tokens)
+    /// Recommended daily inbound limit (tokens) — the token-bucket capacity
     pub recommended_daily_limit: u64,
-    /// Recommended per-transaction limit (tokens)
+    /// Recommended per-transaction limit (tokens) — set to the p99 transfer size so normal
+    /// transfers pass through untouched while outliers are throttled
     pub recommended_per_tx_limit: u64,
+    /// Median transfer size in the sample (tokens)
+    pub p50_transfer_size: u64,
+    /// 95th-percentile transfer size in the sample (tokens)
+    pub p95_transfer_size: u64,
+    /// 99th-percentile transfer size in the sample (tokens)
+    pub p99_transfer_size: u64,
+    /// Capacity refill rate implied by `recommended_daily_limit`, in tokens/second — how
+    /// quickly a drained bucket recovers
+    pub implied_refill_per_second: f64,
     /// Human-readable reasoning
     pub reasoning: String,
     /// Whether the token has high volatility (needs tighter limits)
@@ -30,6 +49,13 @@ struct EtherscanTokenTxResponse {
     result: serde_json::Value,
 }
 
+/// A single transfer pulled from the Etherscan `tokentx` sample: its on-chain timestamp and
+/// its value in whole tokens (already divided by `decimals`)
+struct Transfer {
+    timestamp: u64,
+    amount: f64,
+}
+
 impl VolumeAnalyzer {
     pub fn new(api_key: Option<String>) -> Self {
         Self {
@@ -53,45 +79,107 @@ impl VolumeAnalyzer {
 
         let base_url = Self::get_api_url(chain)?;
 
-        // Fetch recent token transfers (last 100 transactions gives us activity level)
-        let url = format!(
-            "{}?module=account&action=tokentx&contractaddress={}&page=1&offset=100&sort=desc&apikey={}",
-            base_url, address, api_key
-        );
-
-        let response: EtherscanTokenTxResponse = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .context("Failed to fetch token transfers")?
-            .json()
-            .await
-            .context("Failed to parse transfer response")?;
-
-        if response.status != "1" {
-            // API error or no transfers — fall back to supply-based estimate
-            return Ok(Self::fallback_recommendation(decimals, total_supply_raw));
-        }
-
-        let transfers: Vec<serde_json::Value> = match serde_json::from_value(response.result) {
-            Ok(t) => t,
-            Err(_) => return Ok(Self::fallback_recommendation(decimals, total_supply_raw)),
-        };
+        let transfers = self
+            .fetch_transfers(base_url, address, &api_key, decimals)
+            .await?;
 
         if transfers.is_empty() {
             return Ok(Self::fallback_recommendation(decimals, total_supply_raw));
         }
 
-        // Estimate daily volume from the transfer timestamps
-        let (daily_transfers, daily_volume) =
-            Self::estimate_daily_activity(&transfers, decimals);
+        // Estimate daily transfer count/volume from the sample
+        let (daily_transfers, daily_volume) = Self::estimate_daily_activity(&transfers);
+
+        // Observed peak hourly outflow, extrapolated to a day — captures bursty activity
+        // that a flat daily average would smooth over
+        let peak_hourly_outflow = Self::peak_hourly_outflow(&transfers);
+
+        let mut amounts: Vec<f64> = transfers.iter().map(|t| t.amount).collect();
+        // `amount` is parsed from an explorer API's string field, which can carry "NaN"/
+        // "inf" for a malformed or adversarial response — `total_cmp` gives those a total
+        // order instead of panicking the way `partial_cmp(..).unwrap()` would.
+        amounts.sort_by(|a, b| a.total_cmp(b));
+        let p50 = Self::percentile(&amounts, 0.50);
+        let p95 = Self::percentile(&amounts, 0.95);
+        let p99 = Self::percentile(&amounts, 0.99);
+
+        Self::calculate_recommendation(
+            daily_transfers,
+            daily_volume,
+            peak_hourly_outflow,
+            p50,
+            p95,
+            p99,
+            total_supply_raw,
+        )
+    }
+
+    /// Paginate through `tokentx` so the size distribution is representative of more than
+    /// just the most recent page — a single page of 100 can be dominated by one burst of
+    /// small transfers and badly understate the tail
+    async fn fetch_transfers(
+        &self,
+        base_url: &str,
+        address: &str,
+        api_key: &str,
+        decimals: u8,
+    ) -> Result<Vec<Transfer>> {
+        let divisor = 10f64.powi(decimals as i32);
+        let mut transfers = Vec::new();
+
+        for page in 1..=MAX_PAGES {
+            let url = format!(
+                "{}?module=account&action=tokentx&contractaddress={}&page={}&offset={}&sort=desc&apikey={}",
+                base_url, address, page, PAGE_SIZE, api_key
+            );
+
+            let response: EtherscanTokenTxResponse = self
+                .client
+                .get(&url)
+                .send()
+                .await
+                .context("Failed to fetch token transfers")?
+                .json()
+                .await
+                .context("Failed to parse transfer response")?;
+
+            if response.status != "1" {
+                break;
+            }
+
+            let page_txs: Vec<serde_json::Value> = match serde_json::from_value(response.result) {
+                Ok(t) => t,
+                Err(_) => break,
+            };
+
+            let page_len = page_txs.len();
+            for tx in &page_txs {
+                let timestamp = tx
+                    .get("timeStamp")
+                    .and_then(|t| t.as_str())
+                    .and_then(|t| t.parse::<u64>().ok())
+                    .unwrap_or(0);
+                let amount = tx
+                    .get("value")
+                    .and_then(|v| v.as_str())
+                    .and_then(|v| v.parse::<f64>().ok())
+                    .unwrap_or(0.0)
+                    / divisor;
+                transfers.push(Transfer { timestamp, amount });
+            }
+
+            // Fewer results than requested means we've reached the end of history
+            if (page_len as u64) < PAGE_SIZE {
+                break;
+            }
+        }
 
-        Self::calculate_recommendation(daily_transfers, daily_volume, decimals, total_supply_raw)
+        Ok(transfers)
     }
 
-    /// Estimate 24h transfer count and volume from recent transactions
-    fn estimate_daily_activity(transfers: &[serde_json::Value], decimals: u8) -> (u64, f64) {
+    /// Estimate 24h transfer count and volume from the fetched sample, extrapolating from
+    /// the full sample span when it covers less than (or more than) exactly one day
+    fn estimate_daily_activity(transfers: &[Transfer]) -> (u64, f64) {
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .unwrap_or_default()
@@ -101,58 +189,73 @@ impl VolumeAnalyzer {
 
         let mut count_24h = 0u64;
         let mut volume_24h = 0.0f64;
-        let divisor = 10f64.powi(decimals as i32);
-
-        for tx in transfers {
-            let timestamp = tx
-                .get("timeStamp")
-                .and_then(|t| t.as_str())
-                .and_then(|t| t.parse::<u64>().ok())
-                .unwrap_or(0);
-
-            if timestamp >= one_day_ago {
+        for t in transfers {
+            if t.timestamp >= one_day_ago {
                 count_24h += 1;
-                let value = tx
-                    .get("value")
-                    .and_then(|v| v.as_str())
-                    .and_then(|v| v.parse::<f64>().ok())
-                    .unwrap_or(0.0);
-                volume_24h += value / divisor;
+                volume_24h += t.amount;
             }
         }
 
-        // If we have < 24h of data in the 100 transfers, extrapolate
+        // If the sample doesn't span a full day (or has no timestamp in the last 24h),
+        // extrapolate the daily rate from the whole sample instead
         if count_24h == 0 && !transfers.is_empty() {
-            // Use the full sample to estimate daily rate
             let oldest = transfers
-                .last()
-                .and_then(|t| t.get("timeStamp"))
-                .and_then(|t| t.as_str())
-                .and_then(|t| t.parse::<u64>().ok())
+                .iter()
+                .map(|t| t.timestamp)
+                .min()
                 .unwrap_or(now);
             let span_secs = now.saturating_sub(oldest).max(1);
             let rate = transfers.len() as f64 / span_secs as f64;
             count_24h = (rate * 86400.0) as u64;
 
-            let total_volume: f64 = transfers
-                .iter()
-                .filter_map(|tx| {
-                    tx.get("value")
-                        .and_then(|v| v.as_str())
-                        .and_then(|v| v.parse::<f64>().ok())
-                })
-                .sum::<f64>()
-                / divisor;
+            let total_volume: f64 = transfers.iter().map(|t| t.amount).sum();
             volume_24h = total_volume * (86400.0 / span_secs as f64);
         }
 
         (count_24h, volume_24h)
     }
 
+    /// Bucket the sample into 1-hour windows and return the busiest bucket's outflow,
+    /// extrapolated to a full day — a single-hour spike that a flat daily average smooths
+    /// away is exactly the burst the rate limiter needs to size for
+    fn peak_hourly_outflow(transfers: &[Transfer]) -> f64 {
+        use std::collections::HashMap;
+
+        let mut by_hour: HashMap<u64, f64> = HashMap::new();
+        for t in transfers {
+            *by_hour.entry(t.timestamp / 3600).or_insert(0.0) += t.amount;
+        }
+
+        let peak_hour = by_hour.values().cloned().fold(0.0f64, f64::max);
+        peak_hour * 24.0
+    }
+
+    /// Linear-interpolation percentile over an already-sorted slice
+    fn percentile(sorted: &[f64], q: f64) -> f64 {
+        if sorted.is_empty() {
+            return 0.0;
+        }
+        if sorted.len() == 1 {
+            return sorted[0];
+        }
+
+        let rank = q * (sorted.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+        if lower == upper {
+            return sorted[lower];
+        }
+        let weight = rank - lower as f64;
+        sorted[lower] * (1.0 - weight) + sorted[upper] * weight
+    }
+
     fn calculate_recommendation(
         daily_transfers: u64,
         daily_volume: f64,
-        _decimals: u8,
+        peak_hourly_outflow: f64,
+        p50: f64,
+        p95: f64,
+        p99: f64,
         total_supply_raw: &str,
     ) -> Result<RateLimitRecommendation> {
         // Parse total supply as whole tokens
@@ -160,24 +263,36 @@ impl VolumeAnalyzer {
             .parse::<f64>()
             .unwrap_or(1_000_000_000.0);
 
-        // Conservative rate limit: 10% of daily volume, floored at 0.1% of supply
-        let volume_based_limit = (daily_volume * 0.1).max(1.0);
+        // Capacity sized for the worse of: the busiest hour observed, extrapolated to a
+        // full day, or a typical (p95) transfer repeated at the expected daily tx count —
+        // whichever implies the larger bucket, floored at 0.1% of supply
+        let expected_daily_tx_count = daily_transfers.max(1) as f64;
+        let volume_based_limit = peak_hourly_outflow.max(p95 * expected_daily_tx_count);
         let supply_floor = supply_tokens * 0.001; // 0.1% of supply as absolute minimum
-        let recommended_daily = volume_based_limit.max(supply_floor) as u64;
+        let recommended_daily = volume_based_limit.max(supply_floor).max(1.0) as u64;
+
+        // Per-tx limit: the p99 transfer size, so ordinary transfers pass through untouched
+        // and only outlier-sized transfers get throttled
+        let per_tx = p99.max(1.0) as u64;
 
-        // Per-tx limit: 1% of daily limit (prevent single large drains)
-        let per_tx = (recommended_daily as f64 * 0.01).max(1.0) as u64;
+        let implied_refill_per_second = recommended_daily as f64 / 86400.0;
 
         let high_volume = daily_transfers > 1000;
 
         let reasoning = if daily_volume > 0.0 {
             format!(
-                "Token moves ~{:.0} tokens/day across ~{} transfers. \
-                 Recommended limit: {:.0} tokens/day (10% of volume). \
+                "Token moves ~{:.0} tokens/day across ~{} transfers (p50/p95/p99 transfer \
+                 size: {:.0}/{:.0}/{:.0}). Recommended bucket capacity: {:.0} tokens/day, \
+                 refilling at ~{:.2} tokens/sec, with a {:.0}-token per-tx cap. \
                  {}",
                 daily_volume,
                 daily_transfers,
+                p50,
+                p95,
+                p99,
                 recommended_daily,
+                implied_refill_per_second,
+                per_tx,
                 if high_volume {
                     "High activity — consider tighter per-tx limits."
                 } else {
@@ -196,6 +311,10 @@ impl VolumeAnalyzer {
             daily_transfers,
             recommended_daily_limit: recommended_daily,
             recommended_per_tx_limit: per_tx,
+            p50_transfer_size: p50 as u64,
+            p95_transfer_size: p95 as u64,
+            p99_transfer_size: p99 as u64,
+            implied_refill_per_second,
             reasoning,
             high_volume_warning: high_volume,
         })
@@ -215,6 +334,10 @@ impl VolumeAnalyzer {
             daily_transfers: 0,
             recommended_daily_limit: daily_limit,
             recommended_per_tx_limit: per_tx,
+            p50_transfer_size: 0,
+            p95_transfer_size: 0,
+            p99_transfer_size: 0,
+            implied_refill_per_second: daily_limit as f64 / 86400.0,
             reasoning: format!(
                 "No Etherscan API key — using supply-based estimate: \
                  {:.0} tokens/day (0.1% of supply). \
@@ -225,7 +348,7 @@ impl VolumeAnalyzer {
         }
     }
 
-    fn get_api_url(chain: Chain) -> Result<&'static str> {
+    pub(crate) fn get_api_url(chain: Chain) -> Result<&'static str> {
         match chain {
             Chain::Ethereum => Ok("https://api.etherscan.io/api"),
             Chain::Polygon => Ok("https://api.polygonscan.com/api"),
@@ -234,6 +357,7 @@ impl VolumeAnalyzer {
             Chain::Base => Ok("https://api.basescan.org/api"),
             Chain::Bsc => Ok("https://api.bscscan.com/api"),
             Chain::Avalanche => Ok("https://api.snowtrace.io/api"),
+            Chain::Solana => anyhow::bail!("volume data via Etherscan-style APIs is not available for Solana"),
         }
     }
 }
@@ -0,0 +1,301 @@
+use alloy::primitives::{keccak256, Address, Signature, B256, U256};
+use anyhow::{bail, Context, Result};
+use crate::types::AttestationStatus;
+
+/// The current mainnet guardian set (index 4, 19 guardians) as published by Wormhole
+/// governance. Bundled rather than fetched from the Core Bridge contract on every check —
+/// if a VAA references a newer index, `verify` reports it as present-but-unverified
+/// instead of silently trusting an unknown set.
+const MAINNET_GUARDIAN_SET_INDEX: u32 = 4;
+const MAINNET_GUARDIAN_SET: &[&str] = &[
+    "0x58CC3AE5C097b213cE3c81979e1B9f9570746AA5",
+    "0xfF6CB952589BDE862c25Ef4392132fb9D4A42157",
+    "0x114De8460193bdf3A2fCf81f86a09765F4762fD1",
+    "0x107A0086b32d7A0977926A205131d8731D39cbEB",
+    "0x8C82B2fd82FaeD2711d59AF0F0736DC055982199",
+    "0x11b39756C042441BE6D8650b69b54EbE715E2343",
+    "0x54Ce5B4D348fb74B958e8966e2ec3dBd4958a7cd",
+    "0x15e7cAF07C4e3DC8e7C469f92C8Cd88FB8005a20",
+    "0x74a3bf913953D695260D88BC1aA25A4eeE363ef0",
+    "0x000aC0076727b35FBea2dAc28fEE5cCB0fEA768e",
+    "0xAF45Ced136b9D9e24903464AE889F5C8a723FC14",
+    "0xf93124b7c738843CBB89E864c862c38cddCccF95",
+    "0xD2CC37A4dc036a8D232b48f62cDD4731412f4890",
+    "0xDA798F6896A3331F64b48c12D1D57Fd9cbe70811",
+    "0x71AA1BE1D36CaFE3867910F99C09e347899C19C3",
+    "0x8192b6E7387CCd768277c17DAb1b7a5027c0b3Cf",
+    "0x178e21ad2E77AE06711549CFBB1f9c7a9d8096e8",
+    "0x5E1487F35515d02A92753504a8D75471b9f49EdB",
+    "0x6FbEBc898F403E4773E95feB15E80C9A99c8348d",
+];
+
+/// A single guardian's signature over a VAA digest, tagged with its index into the
+/// guardian set that produced it
+#[derive(Debug, Clone)]
+pub struct GuardianSignature {
+    pub guardian_index: u8,
+    pub signature: Signature,
+}
+
+/// A parsed Wormhole Verified Action Approval: a quorum of guardian signatures over a
+/// digest of the body, which carries the actual cross-chain message
+#[derive(Debug, Clone)]
+pub struct Vaa {
+    pub version: u8,
+    pub guardian_set_index: u32,
+    pub signatures: Vec<GuardianSignature>,
+    pub timestamp: u32,
+    pub nonce: u32,
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub consistency_level: u8,
+    pub payload: Vec<u8>,
+}
+
+impl Vaa {
+    /// Parse the VAA wire format: a 1-byte version, 4-byte guardian set index, a
+    /// signature list (1-byte count, then 66 bytes per signature), followed by the body
+    pub fn parse(bytes: &[u8]) -> Result<Self> {
+        let mut cursor = 0usize;
+        let mut take = |n: usize| -> Result<&[u8]> {
+            let slice = bytes.get(cursor..cursor + n).context("VAA truncated")?;
+            cursor += n;
+            Ok(slice)
+        };
+
+        let version = take(1)?[0];
+        let guardian_set_index = u32::from_be_bytes(take(4)?.try_into().unwrap());
+        let sig_count = take(1)?[0];
+
+        let mut signatures = Vec::with_capacity(sig_count as usize);
+        for _ in 0..sig_count {
+            let guardian_index = take(1)?[0];
+            let r = U256::from_be_slice(take(32)?);
+            let s = U256::from_be_slice(take(32)?);
+            let v = take(1)?[0];
+            signatures.push(GuardianSignature {
+                guardian_index,
+                signature: Signature::new(r, s, v != 0),
+            });
+        }
+
+        let timestamp = u32::from_be_bytes(take(4)?.try_into().unwrap());
+        let nonce = u32::from_be_bytes(take(4)?.try_into().unwrap());
+        let emitter_chain = u16::from_be_bytes(take(2)?.try_into().unwrap());
+        let emitter_address: [u8; 32] = take(32)?.try_into().unwrap();
+        let sequence = u64::from_be_bytes(take(8)?.try_into().unwrap());
+        let consistency_level = take(1)?[0];
+        let payload = bytes[cursor..].to_vec();
+
+        Ok(Self {
+            version,
+            guardian_set_index,
+            signatures,
+            timestamp,
+            nonce,
+            emitter_chain,
+            emitter_address,
+            sequence,
+            consistency_level,
+            payload,
+        })
+    }
+
+    /// Re-serialize the body (everything after the signature list) for digesting
+    fn body_bytes(&self) -> Vec<u8> {
+        let mut body = Vec::with_capacity(51 + self.payload.len());
+        body.extend_from_slice(&self.timestamp.to_be_bytes());
+        body.extend_from_slice(&self.nonce.to_be_bytes());
+        body.extend_from_slice(&self.emitter_chain.to_be_bytes());
+        body.extend_from_slice(&self.emitter_address);
+        body.extend_from_slice(&self.sequence.to_be_bytes());
+        body.push(self.consistency_level);
+        body.extend_from_slice(&self.payload);
+        body
+    }
+
+    /// The digest guardians actually sign: keccak256 applied twice to the body
+    pub fn digest(&self) -> B256 {
+        keccak256(keccak256(self.body_bytes()))
+    }
+
+    /// Verify guardian signatures against the bundled mainnet guardian set, requiring at
+    /// least ⌊2/3·N⌋+1 valid, correctly-indexed signatures (13 of 19 on mainnet). If the
+    /// VAA was signed by a different guardian set index than the one bundled here, the
+    /// signatures can't be checked and `verified` comes back false.
+    pub fn verify(&self) -> AttestationStatus {
+        if self.guardian_set_index != MAINNET_GUARDIAN_SET_INDEX {
+            return AttestationStatus {
+                verified: false,
+                guardian_set_index: Some(self.guardian_set_index),
+                signatures_present: self.signatures.len() as u32,
+                quorum_met: false,
+                attested_decimals: None,
+                emitter_chain: Some(self.emitter_chain),
+                emitter_address: Some(self.emitter_address),
+                sequence: Some(self.sequence),
+            };
+        }
+
+        let guardian_set: Vec<Address> = MAINNET_GUARDIAN_SET
+            .iter()
+            .map(|addr| addr.parse().expect("bundled guardian address is well-formed"))
+            .collect();
+        let digest = self.digest();
+        let quorum = guardian_set.len() * 2 / 3 + 1;
+
+        // Dedup by guardian_index: a VAA with one real signature repeated under the same
+        // index several times must not count as several independent attestations.
+        let valid = self
+            .signatures
+            .iter()
+            .filter(|sig| {
+                guardian_set
+                    .get(sig.guardian_index as usize)
+                    .is_some_and(|expected| {
+                        sig.signature
+                            .recover_address_from_prehash(&digest)
+                            .map(|recovered| recovered == *expected)
+                            .unwrap_or(false)
+                    })
+            })
+            .map(|sig| sig.guardian_index)
+            .collect::<std::collections::HashSet<u8>>()
+            .len();
+
+        let quorum_met = valid >= quorum;
+        AttestationStatus {
+            verified: quorum_met,
+            guardian_set_index: Some(self.guardian_set_index),
+            signatures_present: self.signatures.len() as u32,
+            quorum_met,
+            attested_decimals: None,
+            emitter_chain: Some(self.emitter_chain),
+            emitter_address: Some(self.emitter_address),
+            sequence: Some(self.sequence),
+        }
+    }
+}
+
+/// Token Bridge "attestation" payload (type 2): the origin chain's canonical token
+/// metadata, so a mismatch against what `EvmAnalyzer`/`SplAnalyzer` observed directly
+/// (e.g. decimal trimming already applied) is visible rather than silently assumed.
+#[derive(Debug, Clone)]
+pub struct TokenBridgeAttestation {
+    pub token_address: [u8; 32],
+    pub token_chain: u16,
+    pub decimals: u8,
+    pub symbol: String,
+    pub name: String,
+}
+
+impl TokenBridgeAttestation {
+    const PAYLOAD_ID: u8 = 2;
+
+    pub fn parse(payload: &[u8]) -> Result<Self> {
+        if payload.first() != Some(&Self::PAYLOAD_ID) {
+            bail!("not a token bridge attestation payload (expected type 2)");
+        }
+        if payload.len() < 1 + 32 + 2 + 1 + 32 + 32 {
+            bail!("attestation payload truncated");
+        }
+
+        let token_address: [u8; 32] = payload[1..33].try_into().unwrap();
+        let token_chain = u16::from_be_bytes(payload[33..35].try_into().unwrap());
+        let decimals = payload[35];
+        let symbol = decode_fixed_bytes32_string(&payload[36..68]);
+        let name = decode_fixed_bytes32_string(&payload[68..100]);
+
+        Ok(Self {
+            token_address,
+            token_chain,
+            decimals,
+            symbol,
+            name,
+        })
+    }
+}
+
+/// Decode a right-padded, NUL-terminated bytes32 string field
+fn decode_fixed_bytes32_string(field: &[u8]) -> String {
+    let end = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a minimal VAA wire payload: header with `sig_count` zeroed-out signatures,
+    /// then the given body/payload bytes
+    fn build_vaa_bytes(guardian_set_index: u32, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.push(1); // version
+        bytes.extend_from_slice(&guardian_set_index.to_be_bytes());
+        bytes.push(0); // zero signatures — just exercises header/body parsing
+        bytes.extend_from_slice(&0u32.to_be_bytes()); // timestamp
+        bytes.extend_from_slice(&0u32.to_be_bytes()); // nonce
+        bytes.extend_from_slice(&2u16.to_be_bytes()); // emitter_chain (Ethereum)
+        bytes.extend_from_slice(&[0u8; 32]); // emitter_address
+        bytes.extend_from_slice(&1u64.to_be_bytes()); // sequence
+        bytes.push(1); // consistency_level
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    #[test]
+    fn test_parse_roundtrips_header_and_body_fields() {
+        let bytes = build_vaa_bytes(4, &[0xde, 0xad, 0xbe, 0xef]);
+        let vaa = Vaa::parse(&bytes).unwrap();
+        assert_eq!(vaa.guardian_set_index, 4);
+        assert_eq!(vaa.emitter_chain, 2);
+        assert_eq!(vaa.sequence, 1);
+        assert_eq!(vaa.payload, vec![0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn test_verify_fails_without_quorum() {
+        let bytes = build_vaa_bytes(4, &[0x02]);
+        let vaa = Vaa::parse(&bytes).unwrap();
+        let status = vaa.verify();
+        assert!(!status.verified);
+        assert!(!status.quorum_met);
+        assert_eq!(status.signatures_present, 0);
+    }
+
+    #[test]
+    fn test_verify_reports_unknown_guardian_set_as_unverified() {
+        let bytes = build_vaa_bytes(99, &[0x02]);
+        let vaa = Vaa::parse(&bytes).unwrap();
+        let status = vaa.verify();
+        assert!(!status.verified);
+        assert_eq!(status.guardian_set_index, Some(99));
+    }
+
+    #[test]
+    fn test_token_bridge_attestation_parses_symbol_and_name() {
+        let mut payload = vec![2u8]; // payload id
+        payload.extend_from_slice(&[0xAA; 32]); // token_address
+        payload.extend_from_slice(&1u16.to_be_bytes()); // token_chain (Ethereum)
+        payload.push(18); // decimals
+        let mut symbol = vec![0u8; 32];
+        symbol[..3].copy_from_slice(b"ABC");
+        payload.extend_from_slice(&symbol);
+        let mut name = vec![0u8; 32];
+        name[..7].copy_from_slice(b"ABC Tok");
+        payload.extend_from_slice(&name);
+
+        let attestation = TokenBridgeAttestation::parse(&payload).unwrap();
+        assert_eq!(attestation.decimals, 18);
+        assert_eq!(attestation.symbol, "ABC");
+        assert_eq!(attestation.name, "ABC Tok");
+        assert_eq!(attestation.token_chain, 1);
+    }
+
+    #[test]
+    fn test_token_bridge_attestation_rejects_wrong_payload_type() {
+        let payload = vec![1u8, 0, 0, 0];
+        assert!(TokenBridgeAttestation::parse(&payload).is_err());
+    }
+}
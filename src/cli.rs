@@ -1,4 +1,47 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
+
+/// Which `HolderSource` to use for holder distribution data. `Auto` (the default) uses
+/// Etherscan's holder-list API when a key is configured, falling back to reconstructing
+/// balances from `Transfer` logs via RPC when it isn't — see
+/// `analyzers::evm::logscan::LogScanHolderAnalyzer`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum HolderSourceArg {
+    Auto,
+    Etherscan,
+    Logscan,
+}
+
+impl HolderSourceArg {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HolderSourceArg::Auto => "auto",
+            HolderSourceArg::Etherscan => "etherscan",
+            HolderSourceArg::Logscan => "logscan",
+        }
+    }
+}
+
+/// Output serialization target for commands that offer more than one renderer. Replaces
+/// a plain `json: bool` with a single flag that can grow further targets (this adds
+/// `Markdown` and `Html`) without stacking up mutually-exclusive booleans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormatArg {
+    Terminal,
+    Json,
+    Markdown,
+    Html,
+}
+
+impl OutputFormatArg {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutputFormatArg::Terminal => "terminal",
+            OutputFormatArg::Json => "json",
+            OutputFormatArg::Markdown => "markdown",
+            OutputFormatArg::Html => "html",
+        }
+    }
+}
 
 #[derive(Parser)]
 #[command(name = "daybreak")]
@@ -34,9 +77,23 @@ pub enum Commands {
         #[arg(long)]
         skip_holders: bool,
 
+        /// Where to source holder distribution data from
+        #[arg(long, value_enum, default_value = "auto")]
+        holder_source: HolderSourceArg,
+
         /// Output as JSON
         #[arg(long)]
         json: bool,
+
+        /// Print RPC request counts, retries, and per-method latency percentiles
+        #[arg(long)]
+        stats: bool,
+
+        /// Print the JSON Schema for this command's `--json` output instead of running
+        /// the scan. `ADDRESS` is still required but ignored — the schema describes the
+        /// output shape, not any particular token.
+        #[arg(long)]
+        emit_schema: bool,
     },
 
     /// Generate migration report and deployment config
@@ -56,6 +113,10 @@ pub enum Commands {
         /// Skip holder data fetch
         #[arg(long)]
         skip_holders: bool,
+
+        /// Where to source holder distribution data from
+        #[arg(long, value_enum, default_value = "auto")]
+        holder_source: HolderSourceArg,
     },
 
     /// Compare migration paths: NTT vs Neon EVM vs native rewrite
@@ -68,6 +129,40 @@ pub enum Commands {
         #[arg(short, long, default_value = "ethereum")]
         chain: String,
 
+        /// How to render the comparison
+        #[arg(long, value_enum, default_value = "terminal")]
+        format: OutputFormatArg,
+
+        /// Diff this run's analysis against a previously saved snapshot (see
+        /// `--save-snapshot`) instead of printing the usual comparison output — surfaces
+        /// capability, risk-score, bridge-status, and path-recommendation changes since
+        /// the snapshot was taken
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// Save this run's analysis as a JSON snapshot, for a later `--baseline` diff
+        #[arg(long)]
+        save_snapshot: Option<String>,
+
+        /// Print the JSON Schema for this command's `--format json` output instead of
+        /// running the comparison. `ADDRESS` is still required but ignored — the schema
+        /// describes the output shape, not any particular token.
+        #[arg(long)]
+        emit_schema: bool,
+    },
+
+    /// Analyze a batch of tokens concurrently and print a ranked summary. Reads
+    /// `address,chain` pairs, one per line, from a file or stdin.
+    BatchCompare {
+        /// Path to a file of `address,chain` pairs, one per line. Reads from stdin when
+        /// not given.
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Maximum number of tokens to analyze concurrently
+        #[arg(long, default_value_t = 4)]
+        concurrency: usize,
+
         /// Output as JSON
         #[arg(long)]
         json: bool,
@@ -87,13 +182,59 @@ pub enum Commands {
         #[arg(long, default_value = "devnet")]
         network: String,
 
-        /// Path to Solana keypair JSON file
-        #[arg(long, default_value = "~/.config/solana/id.json")]
-        keypair: String,
+        /// Path to Solana keypair JSON file. Falls back to `daybreak.toml`'s
+        /// `payer_keypair`, then `$DAYBREAK_PAYER_KEYPAIR`, then
+        /// `~/.config/solana/id.json` when not given.
+        #[arg(long)]
+        keypair: Option<String>,
 
-        /// Transfer mint authority to a specified address (for NTT manager)
+        /// Transfer mint authority to a specified address (for NTT manager). Falls back
+        /// to `daybreak.toml`'s `ntt_manager_address`, then
+        /// `$DAYBREAK_NTT_MANAGER_ADDRESS`, when not given.
         #[arg(long)]
         transfer_authority: Option<String>,
+
+        /// Drive the NTT manager setup after the SPL mint is created: register the source
+        /// and Solana chains via the `ntt` CLI, transfer mint authority to the deployed
+        /// manager, and write a machine-readable deployment manifest
+        #[arg(long)]
+        auto_ntt: bool,
+
+        /// SOL to request from the devnet/testnet faucet when the wallet balance is too
+        /// low to deploy (ignored on mainnet)
+        #[arg(long, default_value_t = 2.0)]
+        airdrop: f64,
+
+        /// Amount of tokens (in whole units, not base units) to mint into the payer's
+        /// associated token account after deployment. Defaults to mirroring the source
+        /// EVM token's total supply, scaled to the Solana mint's decimals.
+        #[arg(long)]
+        mint_supply: Option<f64>,
+
+        /// Set the mint authority to none after minting, making the supply fixed forever
+        #[arg(long)]
+        revoke_mint_authority: bool,
+
+        /// Off-chain metadata JSON URI (image, description, etc.) to attach to the
+        /// on-chain Metaplex Token Metadata account. Falls back to `daybreak.toml`'s
+        /// `token_metadata_uri`, then `$DAYBREAK_TOKEN_METADATA_URI`. Left empty when
+        /// none of those are set — the mint still gets a name/symbol, just no off-chain
+        /// JSON to point to.
+        #[arg(long)]
+        metadata_uri: Option<String>,
+
+        /// Force deployment under the Token-2022 program with the TransferFee extension,
+        /// even if bytecode analysis didn't detect a fee-on-transfer pattern. Implied when
+        /// `--transfer-fee-bps` is given, or when the source token has a detected fee
+        /// pattern.
+        #[arg(long)]
+        token_2022: bool,
+
+        /// Basis-point transfer fee to configure on the Token-2022 TransferFee extension
+        /// (implies `--token-2022`). Defaults to 0 when a fee pattern was detected but no
+        /// rate could be pulled from bytecode analysis alone.
+        #[arg(long)]
+        transfer_fee_bps: Option<u16>,
     },
 
     /// Pre-migration readiness check — verify tools, wallet, and config
@@ -155,6 +296,14 @@ pub enum Commands {
         /// Skip NTT CLI steps (deploy SPL token only)
         #[arg(long)]
         skip_ntt: bool,
+
+        /// Discard any in-progress migration state and start fresh
+        #[arg(long)]
+        restart: bool,
+
+        /// Print RPC request counts, retries, and per-method latency percentiles
+        #[arg(long)]
+        stats: bool,
     },
 
     /// Post-migration bridge health monitor
@@ -166,5 +315,15 @@ pub enum Commands {
         /// Solana network: devnet or mainnet
         #[arg(long, default_value = "devnet")]
         network: String,
+
+        /// EVM chain this token is claimed to have migrated from (e.g. "ethereum") —
+        /// cross-checked against the origin recovered from the wrapped-asset metadata
+        #[arg(long)]
+        source_chain: Option<String>,
+
+        /// Keep polling WormholeScan after the initial report, printing new transfers
+        /// and pending→completed transitions as they happen
+        #[arg(long)]
+        watch: bool,
     },
 }
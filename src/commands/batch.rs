@@ -0,0 +1,168 @@
+use crate::analyzers::{BridgeDetector, EvmAnalyzer};
+use crate::commands::analyze_full;
+use crate::output::JsonOutput;
+use crate::types::{Chain, FullAnalysis};
+use anyhow::{Context, Result};
+use colored::Colorize;
+use futures::stream::{self, StreamExt};
+use std::io::Read;
+
+/// One `address,chain` pair read from a batch input file or stdin.
+struct BatchTarget {
+    address: String,
+    chain: Chain,
+}
+
+/// Run the `compare` analysis pipeline concurrently over a whole list of tokens, so a
+/// team can triage a candidate list of bridge targets in one invocation instead of
+/// calling `scan`/`compare` once per address in a shell loop. `concurrency` bounds how
+/// many `EvmAnalyzer` pipelines run at once, so a long list doesn't stampede the RPC node.
+pub async fn run_batch_compare(
+    input: Option<String>,
+    rpc_url: Option<String>,
+    concurrency: usize,
+    json_output: bool,
+) -> Result<()> {
+    let targets = read_targets(input)?;
+    if targets.is_empty() {
+        anyhow::bail!("No `address,chain` pairs found in the batch input");
+    }
+
+    let concurrency = concurrency.max(1);
+    eprintln!(
+        "{} {} tokens (concurrency: {})...\n",
+        "Analyzing".bold(),
+        targets.len(),
+        concurrency
+    );
+
+    let results: Vec<(String, Result<FullAnalysis>)> = stream::iter(targets)
+        .map(|target| {
+            let rpc_url = rpc_url.clone();
+            async move {
+                let evm = EvmAnalyzer::new(target.chain, rpc_url);
+                let bridge_detector = BridgeDetector::new();
+                let result =
+                    analyze_full(&evm, &bridge_detector, &target.address, target.chain).await;
+                (target.address, result)
+            }
+        })
+        .buffer_unordered(concurrency)
+        .collect()
+        .await;
+
+    let mut analyses = Vec::new();
+    for (address, result) in results {
+        match result {
+            Ok(analysis) => analyses.push(analysis),
+            Err(e) => eprintln!("  {} {}: {}", "✗".red(), address, e),
+        }
+    }
+
+    // Ranked by risk score ascending — lowest risk (strongest migration candidate) first
+    analyses.sort_by_key(|a| a.risk_score.total);
+
+    if json_output {
+        println!("{}", JsonOutput::format_batch(&analyses)?);
+    } else {
+        print_summary(&analyses);
+    }
+
+    Ok(())
+}
+
+/// Read `address,chain` pairs from a file (or stdin when no path is given). Blank lines
+/// and `#`-prefixed comment lines are skipped; a malformed line is warned about and
+/// skipped rather than failing the whole batch.
+fn read_targets(input: Option<String>) -> Result<Vec<BatchTarget>> {
+    let raw = match input {
+        Some(path) => std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read batch input '{path}'"))?,
+        None => {
+            let mut buf = String::new();
+            std::io::stdin()
+                .read_to_string(&mut buf)
+                .context("Failed to read batch input from stdin")?;
+            buf
+        }
+    };
+
+    let mut targets = Vec::new();
+    for (i, line) in raw.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((address, chain)) = line.split_once(',') else {
+            eprintln!(
+                "  {} line {}: expected `address,chain`, got '{}' — skipping",
+                "!".yellow(),
+                i + 1,
+                line
+            );
+            continue;
+        };
+
+        match chain.trim().parse::<Chain>() {
+            Ok(chain) => targets.push(BatchTarget {
+                address: address.trim().to_string(),
+                chain,
+            }),
+            Err(e) => eprintln!("  {} line {}: {} — skipping", "!".yellow(), i + 1, e),
+        }
+    }
+
+    Ok(targets)
+}
+
+/// Print a ranked summary table, lowest risk first
+fn print_summary(analyses: &[FullAnalysis]) {
+    let header_line = "═".repeat(79);
+    let divider = "─".repeat(79);
+
+    println!("{}", header_line.bold());
+    println!(
+        "  {:<10} {:<10} {:<12} {:<12} {}",
+        "Symbol".bold(),
+        "Risk".bold(),
+        "Compatible".bold(),
+        "Mode".bold(),
+        "Bridge".bold(),
+    );
+    println!("{}", divider);
+
+    for analysis in analyses {
+        let risk_str = format!("{}/100", analysis.risk_score.total);
+        let risk_colored = match analysis.risk_score.total {
+            0..=33 => risk_str.green(),
+            34..=66 => risk_str.yellow(),
+            _ => risk_str.red(),
+        };
+
+        let compat_str = if analysis.compatibility.is_compatible {
+            "✓".green()
+        } else {
+            "✗".red()
+        };
+
+        let bridge_str = if analysis.bridge_status.already_on_solana {
+            "Already on Solana".dimmed().to_string()
+        } else {
+            "Not bridged".to_string()
+        };
+
+        println!(
+            "  {:<10} {:<10} {:<12} {:<12} {}",
+            analysis.token.symbol.bold(),
+            risk_colored,
+            compat_str,
+            analysis.compatibility.recommended_mode.to_string(),
+            bridge_str,
+        );
+    }
+
+    println!("{}", header_line.bold());
+    println!();
+    println!("  {} tokens analyzed.", analyses.len());
+}
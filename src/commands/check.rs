@@ -1,10 +1,11 @@
 use anyhow::{Context, Result};
 use colored::Colorize;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::read_keypair_file;
 use solana_sdk::signer::Signer;
 use std::process::Command;
 
-use crate::analyzers::{CompatibilityChecker, EvmAnalyzer};
+use crate::analyzers::{BridgeDetector, CompatibilityChecker, EvmAnalyzer};
 use crate::solana::SolanaDeployer;
 use crate::types::Chain;
 
@@ -25,7 +26,6 @@ pub async fn run_check(
     keypair_path: &str,
 ) -> Result<()> {
     let chain: Chain = chain.parse()?;
-    let mut checks: Vec<CheckItem> = Vec::new();
 
     println!();
     println!("{}", "═".repeat(60).bright_blue());
@@ -33,58 +33,8 @@ pub async fn run_check(
     println!("{}", "═".repeat(60).bright_blue());
     println!();
 
-    // 1. Check Solana CLI
-    let solana_installed = Command::new("solana").arg("--version").output().is_ok();
-    checks.push(CheckItem {
-        name: "Solana CLI installed".to_string(),
-        passed: solana_installed,
-        detail: if solana_installed {
-            "solana CLI found in PATH".to_string()
-        } else {
-            "solana CLI not found".to_string()
-        },
-        fix: if solana_installed {
-            None
-        } else {
-            Some("sh -c \"$(curl -sSfL https://release.anza.xyz/stable/install)\"".to_string())
-        },
-    });
-
-    // 2. Check SPL Token CLI
-    let spl_installed = Command::new("spl-token").arg("--version").output().is_ok();
-    checks.push(CheckItem {
-        name: "SPL Token CLI installed".to_string(),
-        passed: spl_installed,
-        detail: if spl_installed {
-            "spl-token CLI found in PATH".to_string()
-        } else {
-            "spl-token CLI not found".to_string()
-        },
-        fix: if spl_installed {
-            None
-        } else {
-            Some("cargo install spl-token-cli".to_string())
-        },
-    });
-
-    // 3. Check NTT CLI
-    let ntt_installed = Command::new("ntt").arg("--version").output().is_ok();
-    checks.push(CheckItem {
-        name: "NTT CLI installed".to_string(),
-        passed: ntt_installed,
-        detail: if ntt_installed {
-            "ntt CLI found in PATH".to_string()
-        } else {
-            "ntt CLI not found (needed for bridge deployment)".to_string()
-        },
-        fix: if ntt_installed {
-            None
-        } else {
-            Some("npm install -g @wormhole-foundation/ntt-cli".to_string())
-        },
-    });
-
-    // 4. Check keypair exists and loads
+    // Resolve the keypair up front — the balance check below needs it, and it's a cheap
+    // local file read, not worth running concurrently with anything
     let expanded_path = if keypair_path.starts_with("~/") {
         let home = std::env::var("HOME").context("HOME not set")?;
         keypair_path.replacen('~', &home, 1)
@@ -93,7 +43,7 @@ pub async fn run_check(
     };
     let keypair_result = read_keypair_file(&expanded_path);
     let keypair_ok = keypair_result.is_ok();
-    checks.push(CheckItem {
+    let keypair_check = CheckItem {
         name: "Solana keypair loaded".to_string(),
         passed: keypair_ok,
         detail: if keypair_ok {
@@ -106,57 +56,225 @@ pub async fn run_check(
         } else {
             Some("solana-keygen new -o ~/.config/solana/id.json".to_string())
         },
-    });
+    };
 
-    // 5. Check wallet balance (need ~2 SOL for NTT deployment)
+    let pubkey = keypair_result.as_ref().ok().map(|k| k.pubkey());
     let min_balance = if network == "mainnet" { 2.0 } else { 0.5 };
-    if let Ok(ref payer) = keypair_result {
-        let deployer = SolanaDeployer::new(network);
-        match deployer.get_balance(&payer.pubkey()) {
-            Ok(balance) => {
-                let enough = balance >= min_balance;
-                checks.push(CheckItem {
-                    name: format!("Wallet balance (>= {:.1} SOL)", min_balance),
-                    passed: enough,
-                    detail: format!("{:.4} SOL on {}", balance, network),
-                    fix: if enough {
-                        None
-                    } else if network != "mainnet" {
-                        Some(format!(
-                            "solana airdrop 2 {} --url {}",
-                            payer.pubkey(),
-                            network
-                        ))
-                    } else {
-                        Some("Fund your wallet with SOL".to_string())
-                    },
-                });
-            }
-            Err(e) => {
-                checks.push(CheckItem {
-                    name: format!("Wallet balance (>= {:.1} SOL)", min_balance),
-                    passed: false,
-                    detail: format!("Failed to check: {}", e),
-                    fix: Some("Check your Solana RPC connection".to_string()),
-                });
+    let network = network.to_string();
+
+    // These three groups share no state — the toolchain probes are local `Command`
+    // spawns, the balance check is one Solana RPC call, and the EVM check is its own
+    // chain of RPC/API calls — so run them concurrently instead of blocking on each in
+    // turn.
+    let (toolchain_checks, balance_check, evm_checks) = tokio::join!(
+        check_toolchain(),
+        check_balance(pubkey, network.clone(), min_balance),
+        check_evm(address, chain, rpc_url),
+    );
+    let evm_checks = evm_checks?;
+
+    let mut checks: Vec<CheckItem> = Vec::new();
+    checks.extend(toolchain_checks);
+    checks.push(keypair_check);
+    checks.push(balance_check);
+    checks.extend(evm_checks);
+
+    // Print results
+    let total = checks.len();
+    let passed = checks.iter().filter(|c| c.passed).count();
+
+    for check in &checks {
+        let icon = if check.passed {
+            "PASS".green().bold()
+        } else {
+            "FAIL".red().bold()
+        };
+        println!("  [{}] {}", icon, check.name.bold());
+        println!("         {}", check.detail.dimmed());
+        if let Some(ref fix) = check.fix {
+            println!("         Fix: {}", fix.cyan());
+        }
+    }
+
+    // Summary
+    println!();
+    println!("{}", "─".repeat(60));
+
+    let pct = (passed as f64 / total as f64 * 100.0) as u8;
+    let summary = format!("{}/{} checks passed ({}%)", passed, total, pct);
+    if passed == total {
+        println!(
+            "  {} {}",
+            "Ready for migration!".green().bold(),
+            summary.green()
+        );
+        println!();
+        println!(
+            "  Next step: {}",
+            "daybreak report <ADDRESS> -o ./output".cyan()
+        );
+        println!("  Then run:  {}", "ntt init && ntt deploy".cyan());
+    } else {
+        println!(
+            "  {} {}",
+            "Not ready yet.".yellow().bold(),
+            summary.yellow()
+        );
+        println!();
+        println!(
+            "  Fix the failing checks above, then re-run {}",
+            "daybreak check".cyan()
+        );
+    }
+
+    println!();
+    println!("{}", "═".repeat(60).bright_blue());
+
+    Ok(())
+}
+
+/// Probe for every CLI binary the migration flow depends on, concurrently: the three
+/// Solana-side tools, plus the Node.js/npm runtime and Anchor CLI the NTT CLI and
+/// on-chain manager deployment actually need but that weren't previously checked for.
+async fn check_toolchain() -> Vec<CheckItem> {
+    let (solana, spl_token, ntt, node, npm, anchor) = tokio::join!(
+        check_binary(
+            "solana",
+            "Solana CLI installed",
+            "sh -c \"$(curl -sSfL https://release.anza.xyz/stable/install)\"",
+        ),
+        check_binary(
+            "spl-token",
+            "SPL Token CLI installed",
+            "cargo install spl-token-cli",
+        ),
+        check_binary(
+            "ntt",
+            "NTT CLI installed",
+            "npm install -g @wormhole-foundation/ntt-cli",
+        ),
+        check_binary(
+            "node",
+            "Node.js runtime installed",
+            "Install Node.js (https://nodejs.org) — required by the NTT CLI",
+        ),
+        check_binary(
+            "npm",
+            "npm installed",
+            "Reinstall Node.js, which bundles npm",
+        ),
+        check_binary(
+            "anchor",
+            "Anchor CLI installed",
+            "cargo install --git https://github.com/coral-xyz/anchor avm --locked --force \
+                && avm install latest && avm use latest",
+        ),
+    );
+
+    vec![solana, spl_token, ntt, node, npm, anchor]
+}
+
+/// Check whether `binary --version` runs successfully, off the async executor since
+/// `std::process::Command` blocks the calling thread
+async fn check_binary(binary: &'static str, name: &'static str, fix: &'static str) -> CheckItem {
+    let installed = tokio::task::spawn_blocking(move || {
+        Command::new(binary).arg("--version").output().is_ok()
+    })
+    .await
+    .unwrap_or(false);
+
+    CheckItem {
+        name: name.to_string(),
+        passed: installed,
+        detail: if installed {
+            format!("{} found in PATH", binary)
+        } else {
+            format!("{} not found", binary)
+        },
+        fix: if installed { None } else { Some(fix.to_string()) },
+    }
+}
+
+/// Check wallet balance (need ~2 SOL for NTT deployment), off the async executor since
+/// `SolanaDeployer::get_balance` is a synchronous RPC call
+async fn check_balance(pubkey: Option<Pubkey>, network: String, min_balance: f64) -> CheckItem {
+    let name = format!("Wallet balance (>= {:.1} SOL)", min_balance);
+
+    let Some(pubkey) = pubkey else {
+        return CheckItem {
+            name,
+            passed: false,
+            detail: "No keypair loaded".to_string(),
+            fix: Some("Fix the keypair check above first".to_string()),
+        };
+    };
+
+    let balance_network = network.clone();
+    let result =
+        tokio::task::spawn_blocking(move || SolanaDeployer::new(&balance_network).get_balance(&pubkey))
+            .await;
+
+    match result {
+        Ok(Ok(balance)) => {
+            let enough = balance >= min_balance;
+            CheckItem {
+                name,
+                passed: enough,
+                detail: format!("{:.4} SOL on {}", balance, network),
+                fix: if enough {
+                    None
+                } else if network != "mainnet" {
+                    Some(format!("solana airdrop 2 {} --url {}", pubkey, network))
+                } else {
+                    Some("Fund your wallet with SOL".to_string())
+                },
             }
         }
+        Ok(Err(e)) => CheckItem {
+            name,
+            passed: false,
+            detail: format!("Failed to check: {}", e),
+            fix: Some("Check your Solana RPC connection".to_string()),
+        },
+        Err(e) => CheckItem {
+            name,
+            passed: false,
+            detail: format!("Balance check task panicked: {}", e),
+            fix: Some("Check your Solana RPC connection".to_string()),
+        },
     }
+}
 
-    // 6. Analyze the EVM token
+/// Analyze the EVM token: basic info, NTT compatibility, and whether it's already
+/// bridged to Solana. Kept as one chain of awaits (each step depends on the last) rather
+/// than split further, but runs concurrently with the toolchain/balance checks above.
+async fn check_evm(address: &str, chain: Chain, rpc_url: Option<String>) -> Result<Vec<CheckItem>> {
     eprintln!(
         "  {} Analyzing token on {}...",
         "→".dimmed(),
         chain.to_string().cyan()
     );
+
     let evm = EvmAnalyzer::new(chain, rpc_url);
+    let mut items = Vec::new();
+
     match evm.get_token_info(address).await {
         Ok(token) => {
             let capabilities = evm.get_capabilities(address).await?;
             let bytecode = evm.analyze_bytecode(address).await?;
-            let compatibility = CompatibilityChecker::check(&token, &capabilities, &bytecode);
+            let access_control = evm.get_access_control(address).await?;
+            let governance = evm
+                .get_governance_profile(address, &capabilities, &access_control)
+                .await?;
+            let compatibility = CompatibilityChecker::new().check(
+                &token,
+                &capabilities,
+                &bytecode,
+                &access_control,
+                &governance,
+            );
 
-            checks.push(CheckItem {
+            items.push(CheckItem {
                 name: "Token found on EVM".to_string(),
                 passed: true,
                 detail: format!(
@@ -166,7 +284,7 @@ pub async fn run_check(
                 fix: None,
             });
 
-            checks.push(CheckItem {
+            items.push(CheckItem {
                 name: "NTT compatible".to_string(),
                 passed: compatibility.is_compatible,
                 detail: if compatibility.is_compatible {
@@ -193,9 +311,53 @@ pub async fn run_check(
                     ))
                 },
             });
+
+            // Guard against a redundant deployment: a token that's already live on
+            // Solana (Native/Portal/NTT) should be integrated, not redeployed from scratch
+            match BridgeDetector::new().check(address, chain).await {
+                Ok(bridge_status) if bridge_status.already_on_solana => {
+                    let provider = bridge_status
+                        .bridge_provider
+                        .as_deref()
+                        .unwrap_or("unknown bridge");
+                    let solana_address = bridge_status
+                        .solana_address
+                        .as_deref()
+                        .unwrap_or("unknown address");
+                    items.push(CheckItem {
+                        name: "Not already on Solana".to_string(),
+                        passed: false,
+                        detail: format!(
+                            "Already bridged via {} at {}",
+                            provider, solana_address
+                        ),
+                        fix: Some(format!(
+                            "Integrate the existing mint ({}) instead of deploying a new one — \
+                                re-deploying creates a second, disconnected token",
+                            solana_address
+                        )),
+                    });
+                }
+                Ok(_) => {
+                    items.push(CheckItem {
+                        name: "Not already on Solana".to_string(),
+                        passed: true,
+                        detail: "No existing Solana presence found".to_string(),
+                        fix: None,
+                    });
+                }
+                Err(e) => {
+                    items.push(CheckItem {
+                        name: "Not already on Solana".to_string(),
+                        passed: false,
+                        detail: format!("Failed to check for existing bridges: {}", e),
+                        fix: Some("Check your network connection and retry".to_string()),
+                    });
+                }
+            }
         }
         Err(e) => {
-            checks.push(CheckItem {
+            items.push(CheckItem {
                 name: "Token found on EVM".to_string(),
                 passed: false,
                 detail: format!("Failed: {}", e),
@@ -204,56 +366,5 @@ pub async fn run_check(
         }
     }
 
-    // Print results
-    let total = checks.len();
-    let passed = checks.iter().filter(|c| c.passed).count();
-
-    for check in &checks {
-        let icon = if check.passed {
-            "PASS".green().bold()
-        } else {
-            "FAIL".red().bold()
-        };
-        println!("  [{}] {}", icon, check.name.bold());
-        println!("         {}", check.detail.dimmed());
-        if let Some(ref fix) = check.fix {
-            println!("         Fix: {}", fix.cyan());
-        }
-    }
-
-    // Summary
-    println!();
-    println!("{}", "─".repeat(60));
-
-    let pct = (passed as f64 / total as f64 * 100.0) as u8;
-    let summary = format!("{}/{} checks passed ({}%)", passed, total, pct);
-    if passed == total {
-        println!(
-            "  {} {}",
-            "Ready for migration!".green().bold(),
-            summary.green()
-        );
-        println!();
-        println!(
-            "  Next step: {}",
-            "daybreak report <ADDRESS> -o ./output".cyan()
-        );
-        println!("  Then run:  {}", "ntt init && ntt deploy".cyan());
-    } else {
-        println!(
-            "  {} {}",
-            "Not ready yet.".yellow().bold(),
-            summary.yellow()
-        );
-        println!();
-        println!(
-            "  Fix the failing checks above, then re-run {}",
-            "daybreak check".cyan()
-        );
-    }
-
-    println!();
-    println!("{}", "═".repeat(60).bright_blue());
-
-    Ok(())
+    Ok(items)
 }
@@ -1,8 +1,9 @@
-use crate::analyzers::{BridgeDetector, CompatibilityChecker, EvmAnalyzer};
-use crate::output::{JsonOutput, TerminalOutput};
-use crate::report::PathComparator;
-use crate::scoring::RiskScorer;
-use crate::types::{Chain, FullAnalysis};
+use crate::analyzers::{BridgeDetector, EvmAnalyzer};
+use crate::cli::OutputFormatArg;
+use crate::commands::analyze_full;
+use crate::output::{HtmlOutput, JsonOutput, MarkdownGenerator, TerminalOutput};
+use crate::report::{PathComparator, SnapshotDiffer};
+use crate::types::Chain;
 use anyhow::Result;
 
 /// Run the compare command
@@ -10,47 +11,48 @@ pub async fn run_compare(
     address: &str,
     chain: &str,
     rpc_url: Option<String>,
-    json_output: bool,
+    format: OutputFormatArg,
+    baseline: Option<String>,
+    save_snapshot: Option<String>,
+    emit_schema: bool,
 ) -> Result<()> {
+    if emit_schema {
+        println!("{}", JsonOutput::schema_for_comparison()?);
+        return Ok(());
+    }
+
     let chain: Chain = chain.parse()?;
 
     // Initialize analyzers
     let evm = EvmAnalyzer::new(chain, rpc_url);
     let bridge_detector = BridgeDetector::new();
 
-    // Fetch token info
-    let token = evm.get_token_info(address).await?;
-    let capabilities = evm.get_capabilities(address).await?;
-    let bytecode = evm.analyze_bytecode(address).await?;
-
-    // Check compatibility
-    let compatibility = CompatibilityChecker::check(&token, &capabilities, &bytecode);
+    let analysis = analyze_full(&evm, &bridge_detector, address, chain).await?;
 
-    // Check existing bridges
-    let bridge_status = bridge_detector.check(address, chain).await?;
-
-    // Calculate risk score (no holder data for compare)
-    let risk_score = RiskScorer::calculate(&token, &capabilities, &bytecode, &bridge_status, None);
+    if let Some(path) = &save_snapshot {
+        SnapshotDiffer::save(path, &analysis)?;
+        println!("Saved snapshot to {path}");
+    }
 
-    let analysis = FullAnalysis {
-        token,
-        capabilities,
-        bytecode,
-        compatibility,
-        bridge_status,
-        risk_score,
-        holder_data: None,
-        rate_limit: None,
-    };
+    // A baseline diff replaces the usual comparison output — the whole point of
+    // `--baseline` is to see what changed, not to re-print the current state
+    if let Some(path) = &baseline {
+        let baseline_analysis = SnapshotDiffer::load(path)?;
+        let baseline_text = SnapshotDiffer::render(&baseline_analysis)?;
+        let current_text = SnapshotDiffer::render(&analysis)?;
+        TerminalOutput::print_diff(&baseline_text, &current_text);
+        return Ok(());
+    }
 
     // Compare paths
     let paths = PathComparator::compare(&analysis);
 
     // Output
-    if json_output {
-        println!("{}", JsonOutput::format_comparison(&paths)?);
-    } else {
-        TerminalOutput::print_comparison(&paths);
+    match format {
+        OutputFormatArg::Terminal => TerminalOutput::print_comparison(&paths),
+        OutputFormatArg::Json => println!("{}", JsonOutput::format_comparison(&paths)?),
+        OutputFormatArg::Markdown => println!("{}", MarkdownGenerator::generate_comparison(&paths)),
+        OutputFormatArg::Html => println!("{}", HtmlOutput::generate(&analysis, &paths)),
     }
 
     Ok(())
@@ -1,27 +1,216 @@
 use anyhow::{bail, Context, Result};
 use colored::Colorize;
-use solana_sdk::signature::{read_keypair_file, Signer};
+use serde::Serialize;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
 use std::io::{self, Write};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::analyzers::{CompatibilityChecker, EvmAnalyzer};
+use crate::analyzers::bridges::{wormhole_chain_id, wormhole_chain_name};
+use crate::analyzers::{BridgeDetector, CompatibilityChecker, EvmAnalyzer};
+use crate::config::{self, DaybreakConfig};
 use crate::solana::SolanaDeployer;
-use crate::types::Chain;
+use crate::types::{Chain, IssueSeverity};
+
+/// Built-in fallback when no keypair path is given on the CLI, in `daybreak.toml`, or
+/// via `$DAYBREAK_PAYER_KEYPAIR`
+const DEFAULT_KEYPAIR_PATH: &str = "~/.config/solana/id.json";
 
 /// Print a progress step to stderr
 fn progress(msg: &str) {
     eprintln!("  {} {}", "→".dimmed(), msg.dimmed());
 }
 
+/// Check that the `ntt` CLI is installed — required for `--auto-ntt`
+fn check_ntt_installed() -> Result<()> {
+    if Command::new("ntt").arg("--version").output().is_err() {
+        bail!(
+            "NTT CLI not found. Install it with:\n  {}",
+            "npm install -g @wormhole-foundation/ntt-cli".cyan()
+        );
+    }
+    Ok(())
+}
+
+/// Prompt for mainnet confirmation. Returns `false` if the user declined, in which case
+/// the caller should abort the deploy with `Ok(())` rather than an error.
+fn confirm_mainnet_or_abort(deployer: &SolanaDeployer) -> Result<bool> {
+    if !deployer.is_mainnet() {
+        return Ok(true);
+    }
+    eprint!(
+        "  {} {} This will deploy to Solana {}. Proceed? [y/N] ",
+        "⚠".yellow().bold(),
+        "WARNING:".yellow().bold(),
+        "MAINNET".red().bold()
+    );
+    io::stderr().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().eq_ignore_ascii_case("y"))
+}
+
+/// Load a Solana keypair from a file path, expanding a leading `~/` to `$HOME`
+fn load_keypair(keypair_path: &str) -> Result<Keypair> {
+    let expanded_path = if keypair_path.starts_with("~/") {
+        let home = std::env::var("HOME").context("HOME not set")?;
+        keypair_path.replacen('~', &home, 1)
+    } else {
+        keypair_path.to_string()
+    };
+    read_keypair_file(&expanded_path)
+        .map_err(|e| anyhow::anyhow!("Failed to load keypair from {}: {}", expanded_path, e))
+}
+
+/// Ensure the payer has at least 0.01 SOL, requesting a faucet airdrop on devnet/testnet
+/// if not (mainnet has no faucet, so a low balance there is a hard error). Returns the
+/// (possibly updated) balance.
+fn ensure_funded(
+    deployer: &SolanaDeployer,
+    payer: &Keypair,
+    network: &str,
+    airdrop: f64,
+) -> Result<f64> {
+    progress("Checking wallet balance...");
+    let mut balance = deployer.get_balance(&payer.pubkey())?;
+    if balance < 0.01 {
+        if deployer.is_mainnet() {
+            bail!(
+                "Insufficient balance: {:.4} SOL. Need at least 0.01 SOL.",
+                balance
+            );
+        }
+
+        progress(&format!(
+            "Balance too low — requesting {} SOL from the {} faucet...",
+            airdrop, network
+        ));
+        deployer
+            .request_airdrop(&payer.pubkey(), airdrop)
+            .with_context(|| {
+                format!(
+                    "Insufficient balance ({:.4} SOL) and the faucet airdrop failed",
+                    balance
+                )
+            })?;
+        balance = deployer.get_balance(&payer.pubkey())?;
+        eprintln!("  {} Airdropped {} SOL", "✓".green(), airdrop);
+    }
+    eprintln!("  {} Balance: {:.4} SOL", "✓".green(), balance);
+    Ok(balance)
+}
+
+/// Run an NTT CLI command, capturing output
+fn run_ntt_command(args: &[&str]) -> Result<()> {
+    let output = Command::new("ntt")
+        .args(args)
+        .output()
+        .context("Failed to execute ntt CLI")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("ntt {} failed: {}", args.join(" "), stderr.trim());
+    }
+
+    Ok(())
+}
+
+/// Machine-readable record of what `--auto-ntt` did, modeled on a Wormhole VAA's emitter
+/// fields (`emitter_chain`/`emitter_address` — see `analyzers::wormhole::Vaa`) so a later
+/// `daybreak status` run can reconcile an actual bridge transfer against the chain
+/// registrations made here.
+#[derive(Serialize)]
+struct NttAutoDeployManifest {
+    generated_at_unix: u64,
+    network: String,
+    mint: MintRecord,
+    source_chain: ChainRegistration,
+    destination_chain: ChainRegistration,
+    mint_authority_transfer: Option<MintAuthorityTransfer>,
+    /// Sunrise listing fields pulled from `daybreak.toml`, if the project checked one in —
+    /// saves re-entering them by hand when applying at `https://www.sunrise.wtf`
+    sunrise: Option<crate::config::SunriseConfig>,
+}
+
+#[derive(Serialize)]
+struct MintRecord {
+    address: String,
+    decimals: u8,
+    create_tx: String,
+}
+
+/// One side of the bridge: its Wormhole chain id, the address registered with `ntt
+/// add-chain` for it, and the mode it was registered in. `manager_address` is the NTT
+/// manager `ntt push` deploys for this chain — left `None` here, since the CLI doesn't
+/// expose it through a stable, parseable stdout contract; a future pass could read it
+/// back from `ntt`'s own deployment.json once its post-push schema is pinned down.
+#[derive(Serialize)]
+struct ChainRegistration {
+    chain: String,
+    emitter_chain: u16,
+    registered_address: String,
+    mode: String,
+    manager_address: Option<String>,
+}
+
+#[derive(Serialize, Clone)]
+struct MintAuthorityTransfer {
+    new_authority: String,
+    tx: String,
+}
+
 /// Run the deploy command
 pub async fn run_deploy(
     address: &str,
     chain: &str,
     rpc_url: Option<String>,
     network: &str,
-    keypair_path: &str,
+    keypair_path: Option<&str>,
     transfer_authority: Option<&str>,
+    auto_ntt: bool,
+    airdrop: f64,
+    mint_supply: Option<f64>,
+    revoke_mint_authority: bool,
+    metadata_uri: Option<&str>,
+    token_2022: bool,
+    transfer_fee_bps: Option<u16>,
 ) -> Result<()> {
     let chain: Chain = chain.parse()?;
+    let project_config = DaybreakConfig::load();
+    let metadata_uri = config::resolve_opt(
+        metadata_uri,
+        project_config.token_metadata_uri.as_deref(),
+        "DAYBREAK_TOKEN_METADATA_URI",
+    );
+    let metadata_uri = metadata_uri.as_deref();
+
+    // Resolution order: CLI flag, then daybreak.toml, then environment, then default —
+    // so a team's migration config doesn't have to be re-typed as flags on every run.
+    let rpc_url = rpc_url.or_else(|| project_config.rpc_url(chain.display_name()).map(String::from));
+    let keypair_path = config::resolve(
+        keypair_path,
+        project_config.payer_keypair.as_deref(),
+        "DAYBREAK_PAYER_KEYPAIR",
+        DEFAULT_KEYPAIR_PATH,
+    );
+    let transfer_authority = config::resolve_opt(
+        transfer_authority,
+        project_config.ntt_manager_address.as_deref(),
+        "DAYBREAK_NTT_MANAGER_ADDRESS",
+    );
+    let transfer_authority = transfer_authority.as_deref();
+
+    let evm = EvmAnalyzer::new(chain, rpc_url);
+
+    // ERC-721 collections get a dedicated migration path — they have no decimals, no
+    // NttMode, and mint a single Metaplex collection NFT rather than a fungible SPL mint.
+    if evm.is_erc721(address).await.unwrap_or(false) {
+        return run_deploy_nft(address, chain, network, &keypair_path, airdrop, &evm).await;
+    }
+
+    if auto_ntt {
+        check_ntt_installed()?;
+    }
 
     // Analyze the EVM token first
     eprintln!(
@@ -31,14 +220,22 @@ pub async fn run_deploy(
         chain.to_string().cyan()
     );
 
-    let evm = EvmAnalyzer::new(chain, rpc_url);
-
     progress("Fetching token metadata...");
     let token = evm.get_token_info(address).await?;
     let capabilities = evm.get_capabilities(address).await?;
     let bytecode = evm.analyze_bytecode(address).await?;
+    let access_control = evm.get_access_control(address).await?;
+    let governance = evm
+        .get_governance_profile(address, &capabilities, &access_control)
+        .await?;
 
-    let compatibility = CompatibilityChecker::check(&token, &capabilities, &bytecode);
+    let compatibility = CompatibilityChecker::new().check(
+        &token,
+        &capabilities,
+        &bytecode,
+        &access_control,
+        &governance,
+    );
 
     eprintln!(
         "  {} Found: {} ({}) — {} decimals\n",
@@ -55,18 +252,46 @@ pub async fn run_deploy(
         );
     }
 
-    // Calculate Solana decimals (capped at 9)
-    let spl_decimals = token.decimals.min(9);
+    // A detected fee pattern with no rate anywhere to back it up — neither an explicit
+    // `--transfer-fee-bps` nor one bytecode analysis itself recovered — would otherwise
+    // silently fall through to `unwrap_or(0)` further down and deploy a fee-on-transfer
+    // token with no fee at all. Check this right away, before the wrapped-mint prompt,
+    // mainnet confirmation, and airdrop funding below waste the operator's time on a
+    // deploy that's going to get rejected anyway.
+    if bytecode.has_fee_pattern && transfer_fee_bps.is_none() && bytecode.fee_bps.is_none() {
+        bail!(
+            "Detected a fee-on-transfer pattern in this token's bytecode, but couldn't \
+             determine the fee rate from bytecode analysis alone. Deploying without it \
+             would silently lose the fee on every transfer — pass --transfer-fee-bps \
+             <bps> with the source token's actual rate to proceed."
+        );
+    }
 
-    // Mainnet confirmation
-    let deployer = SolanaDeployer::new(network);
-    if deployer.is_mainnet() {
-        eprint!(
-            "  {} {} This will deploy to Solana {}. Proceed? [y/N] ",
-            "⚠".yellow().bold(),
-            "WARNING:".yellow().bold(),
-            "MAINNET".red().bold()
+    // Check for a pre-existing wrapped representation before minting a second, competing
+    // token — a fresh deploy would otherwise fragment liquidity across two Solana mints
+    // for the same source asset.
+    progress("Checking for an existing Wormhole wrapped mint...");
+    let bridge_status = BridgeDetector::new().check(address, chain).await?;
+    if bridge_status.already_on_solana {
+        eprintln!();
+        eprintln!(
+            "  {} {} already exists on Solana via {}",
+            "!".yellow().bold(),
+            token.symbol.bold(),
+            bridge_status.bridge_provider.as_deref().unwrap_or("unknown bridge")
         );
+        if let Some(mint) = &bridge_status.solana_address {
+            eprintln!("    Existing mint: {}", mint.cyan());
+        }
+        if let Some(origin) = &bridge_status.wrapped_origin {
+            eprintln!(
+                "    Recorded origin: {} ({} decimals) on {}",
+                origin.token_address_hex(),
+                origin.original_decimals,
+                wormhole_chain_name(origin.chain)
+            );
+        }
+        eprint!("  Deploy a second, competing mint anyway? [y/N] ");
         io::stderr().flush()?;
         let mut input = String::new();
         io::stdin().read_line(&mut input)?;
@@ -76,32 +301,18 @@ pub async fn run_deploy(
         }
     }
 
-    // Load keypair
-    // Expand ~ to home directory
-    let expanded_path = if keypair_path.starts_with("~/") {
-        let home = std::env::var("HOME").context("HOME not set")?;
-        keypair_path.replacen('~', &home, 1)
-    } else {
-        keypair_path.to_string()
-    };
-    let payer = read_keypair_file(&expanded_path)
-        .map_err(|e| anyhow::anyhow!("Failed to load keypair from {}: {}", expanded_path, e))?;
+    // Calculate Solana decimals (capped at 9)
+    let spl_decimals = token.decimals.min(9);
 
-    // Check balance
-    progress("Checking wallet balance...");
-    let balance = deployer.get_balance(&payer.pubkey())?;
-    if balance < 0.01 {
-        bail!(
-            "Insufficient balance: {:.4} SOL. Need at least 0.01 SOL. {}",
-            balance,
-            if !deployer.is_mainnet() {
-                "Run `solana airdrop 2` to fund your devnet wallet."
-            } else {
-                ""
-            }
-        );
+    // Mainnet confirmation
+    let deployer = SolanaDeployer::new(network);
+    if !confirm_mainnet_or_abort(&deployer)? {
+        eprintln!("  Aborted.");
+        return Ok(());
     }
-    eprintln!("  {} Balance: {:.4} SOL", "✓".green(), balance);
+
+    let payer = load_keypair(&keypair_path)?;
+    ensure_funded(&deployer, &payer, network, airdrop)?;
 
     // Deploy
     eprintln!();
@@ -111,39 +322,183 @@ pub async fn run_deploy(
         network.cyan()
     );
 
-    let result = deployer.create_spl_token(&payer, spl_decimals)?;
+    // A fee-on-transfer source token can't be reproduced by a vanilla SPL mint — the fee
+    // would silently vanish on migration — so route it to Token-2022's TransferFee
+    // extension instead. `--token-2022`/`--transfer-fee-bps` let a caller force the same
+    // path for a token bytecode analysis didn't flag.
+    let use_token_2022 = token_2022 || transfer_fee_bps.is_some() || bytecode.has_fee_pattern;
+
+    let result = if use_token_2022 {
+        let fee_bps = transfer_fee_bps
+            .or(bytecode.fee_bps)
+            .unwrap_or(0);
+        let max_fee = bytecode.max_fee.unwrap_or(u64::MAX);
+        progress(&format!(
+            "Fee-on-transfer migration target — deploying under Token-2022 ({}bps transfer fee)...",
+            fee_bps
+        ));
+        deployer.create_spl_token_2022(
+            &payer,
+            spl_decimals,
+            &token.name,
+            &token.symbol,
+            metadata_uri,
+            fee_bps,
+            max_fee,
+        )?
+    } else {
+        deployer.create_spl_token(&payer, spl_decimals, &token.name, &token.symbol, metadata_uri)?
+    };
 
     let mint_str = result.mint_address.to_string();
+    eprintln!(
+        "  {} Mint created with on-chain metadata: {} ({}){}",
+        "✓".green(),
+        token.name,
+        token.symbol,
+        if use_token_2022 { " on Token-2022" } else { "" }
+    );
 
-    // Create on-chain metadata so the token shows up in wallets (Phantom, Solflare, etc.)
-    progress("Creating on-chain metadata (Metaplex)...");
-    match deployer.create_metadata(&payer, &result.mint_address, &token.name, &token.symbol) {
-        Ok(_sig) => {
-            eprintln!(
-                "  {} Metadata created: {} ({})",
-                "✓".green(),
-                token.name,
-                token.symbol
-            );
+    // Mint the initial supply so the token has a real balance rather than being a
+    // zero-supply placeholder. Default to mirroring the EVM token's total supply (scaled
+    // from its on-chain decimals down to the Solana mint's decimals) when `--mint-supply`
+    // isn't given.
+    let mint_supply_tokens = mint_supply.unwrap_or_else(|| {
+        token.total_supply.parse::<f64>().unwrap_or(0.0) / 10f64.powi(token.decimals as i32)
+    });
+    let mut mint_ata = None;
+    if mint_supply_tokens > 0.0 {
+        progress(&format!(
+            "Minting {} {} to your wallet...",
+            mint_supply_tokens, token.symbol
+        ));
+        let amount_raw = (mint_supply_tokens * 10f64.powi(spl_decimals as i32)).round() as u64;
+        match deployer.mint_initial_supply(&payer, &result.mint_address, amount_raw, &result.token_program) {
+            Ok((ata, _sig)) => {
+                eprintln!(
+                    "  {} Minted {} {} to {}",
+                    "✓".green(),
+                    mint_supply_tokens,
+                    token.symbol,
+                    ata
+                );
+                mint_ata = Some(ata);
+            }
+            Err(e) => {
+                eprintln!(
+                    "  {} Minting initial supply failed: {} (token still usable)",
+                    "⚠".yellow(),
+                    e
+                );
+            }
         }
-        Err(e) => {
-            eprintln!(
-                "  {} Metadata creation failed: {} (token still usable)",
-                "⚠".yellow(),
-                e
-            );
+    }
+
+    if revoke_mint_authority {
+        progress("Revoking mint authority (fixed supply)...");
+        match deployer.revoke_mint_authority(&payer, &result.mint_address, &result.token_program) {
+            Ok(_sig) => eprintln!(
+                "  {} Mint authority revoked — supply is now fixed",
+                "✓".green()
+            ),
+            Err(e) => eprintln!("  {} Failed to revoke mint authority: {}", "⚠".yellow(), e),
         }
     }
 
     // Transfer mint authority if requested
+    let mut mint_authority_transfer = None;
     if let Some(new_authority) = transfer_authority {
         progress("Transferring mint authority...");
-        deployer.transfer_mint_authority(&payer, &result.mint_address, new_authority)?;
+        let sig = deployer.transfer_mint_authority(&payer, &result.mint_address, new_authority)?;
         eprintln!(
             "  {} Mint authority transferred to {}",
             "✓".green(),
             new_authority.cyan()
         );
+        mint_authority_transfer = Some(MintAuthorityTransfer {
+            new_authority: new_authority.to_string(),
+            tx: sig.to_string(),
+        });
+    }
+
+    // Drive the NTT manager setup end-to-end instead of leaving it to manual CLI steps
+    let mut auto_ntt_manifest = None;
+    if auto_ntt {
+        let source_chain_name = chain.to_string().to_lowercase();
+        let mode = compatibility.recommended_mode.to_string().to_lowercase();
+
+        progress("Initializing NTT project...");
+        run_ntt_command(&["init"])?;
+
+        progress(&format!("Registering source chain ({})...", source_chain_name));
+        run_ntt_command(&[
+            "add-chain",
+            &source_chain_name,
+            "--mode",
+            &mode,
+            "--token",
+            address,
+        ])?;
+
+        progress("Registering Solana as a burning destination...");
+        run_ntt_command(&[
+            "add-chain",
+            "solana",
+            "--mode",
+            "burning",
+            "--token",
+            &mint_str,
+            "--decimals",
+            &spl_decimals.to_string(),
+        ])?;
+
+        progress("Deploying NTT contracts (this may take a few minutes)...");
+        run_ntt_command(&["push"])?;
+        eprintln!("  {} NTT manager setup complete", "✓".green());
+
+        let generated_at_unix = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        auto_ntt_manifest = Some(NttAutoDeployManifest {
+            generated_at_unix,
+            network: network.to_string(),
+            mint: MintRecord {
+                address: mint_str.clone(),
+                decimals: spl_decimals,
+                create_tx: result.signature.to_string(),
+            },
+            source_chain: ChainRegistration {
+                chain: source_chain_name,
+                emitter_chain: wormhole_chain_id(chain),
+                registered_address: address.to_string(),
+                mode,
+                manager_address: None,
+            },
+            destination_chain: ChainRegistration {
+                chain: "solana".to_string(),
+                emitter_chain: wormhole_chain_id(Chain::Solana),
+                registered_address: mint_str.clone(),
+                mode: "burning".to_string(),
+                manager_address: None,
+            },
+            mint_authority_transfer: mint_authority_transfer.clone(),
+            sunrise: (project_config.sunrise.project_name.is_some()
+                || project_config.sunrise.contact_email.is_some()
+                || project_config.sunrise.website.is_some())
+            .then(|| project_config.sunrise.clone()),
+        });
+
+        let manifest_path = "ntt-manifest.json";
+        let manifest_json = serde_json::to_string_pretty(&auto_ntt_manifest)?;
+        std::fs::write(manifest_path, &manifest_json)
+            .with_context(|| format!("Failed to write {}", manifest_path))?;
+        eprintln!(
+            "  {} Deployment manifest written to {}",
+            "✓".green(),
+            manifest_path.cyan()
+        );
     }
 
     // Print results
@@ -165,11 +520,47 @@ pub async fn run_deploy(
         println!("  Decimals: {}", spl_decimals);
     }
     println!("  Network:  {}", network);
+    println!(
+        "  Program:  {}",
+        if result.token_program == spl_token_2022::id() {
+            "Token-2022 (TransferFee extension)".yellow()
+        } else {
+            "SPL Token".normal()
+        }
+    );
     println!("  Cost:     {:.5} SOL", result.cost_sol);
     println!("  Explorer: {}", result.explorer_url().cyan());
+    println!("  Metadata: {}", result.metadata_pda.to_string().dimmed());
+    if let Some(ata) = mint_ata {
+        println!("  Supply:   {} {}", mint_supply_tokens, token.symbol);
+        println!("  ATA:      {}", ata.to_string().cyan());
+    }
 
     // Post-deploy instructions
     println!();
+    if auto_ntt {
+        println!("{}", "── NTT Deployment ──".bright_white());
+        println!();
+        println!("  {} Source chain and Solana destination registered, manager deployed", "✓".green());
+        println!("  Manifest: {}", "ntt-manifest.json".cyan());
+        if transfer_authority.is_none() {
+            println!();
+            println!(
+                "  {} {}",
+                "⚠".yellow(),
+                "Mint authority not transferred — pass --transfer-authority <NTT_MANAGER> to finish.".yellow()
+            );
+        }
+        println!();
+        println!(
+            "  {} Apply for Sunrise listing: {}",
+            "→".bright_white(),
+            "https://www.sunrise.wtf".cyan()
+        );
+        println!();
+        println!("{}", "═".repeat(60).bright_blue());
+        return Ok(());
+    }
     println!("{}", "── Post-Deploy Steps ──".bright_white());
     println!();
 
@@ -263,3 +654,125 @@ pub async fn run_deploy(
 
     Ok(())
 }
+
+/// Migrate an ERC-721 collection: mint a sized Metaplex collection NFT on Solana whose
+/// on-chain metadata mirrors the EVM collection's name/symbol/base URI, rather than
+/// deploying a fungible SPL mint like `run_deploy` does.
+async fn run_deploy_nft(
+    address: &str,
+    chain: Chain,
+    network: &str,
+    keypair_path: &str,
+    airdrop: f64,
+    evm: &EvmAnalyzer,
+) -> Result<()> {
+    eprintln!(
+        "\n{} NFT collection {} on {}\n",
+        "Analyzing".bold(),
+        &address[..std::cmp::min(10, address.len())].cyan(),
+        chain.to_string().cyan()
+    );
+
+    progress("Fetching collection metadata...");
+    let collection = evm.get_collection_info(address).await?;
+    eprintln!(
+        "  {} Found: {} ({}){}\n",
+        "✓".green(),
+        collection.name.bold(),
+        collection.symbol,
+        collection
+            .total_supply
+            .map(|n| format!(" — {} items", n))
+            .unwrap_or_default()
+    );
+
+    let nft_compat = CompatibilityChecker::new().check_nft(&collection);
+    if !nft_compat.issues.is_empty() {
+        eprintln!("  Issues:");
+        for issue in &nft_compat.issues {
+            let severity = match issue.severity {
+                IssueSeverity::Info => "[INFO]".dimmed(),
+                IssueSeverity::Warning => "[WARN]".yellow(),
+                IssueSeverity::Error => "[ERROR]".red(),
+            };
+            eprintln!("    {} {}", severity, issue.title);
+        }
+        eprintln!();
+    }
+    if !nft_compat.is_compatible {
+        bail!(
+            "Collection {} is not compatible with NFT migration. See issues above.",
+            collection.symbol
+        );
+    }
+
+    let deployer = SolanaDeployer::new(network);
+    if !confirm_mainnet_or_abort(&deployer)? {
+        eprintln!("  Aborted.");
+        return Ok(());
+    }
+
+    let payer = load_keypair(keypair_path)?;
+    ensure_funded(&deployer, &payer, network, airdrop)?;
+
+    eprintln!();
+    eprintln!(
+        "{} Metaplex collection NFT on Solana {}...",
+        "Creating".bold(),
+        network.cyan()
+    );
+
+    // ERC721Enumerable gives an exact item count; without it, `create_collection_nft`
+    // is told the collection is unbounded (size 0) rather than guessing.
+    let max_size = collection.total_supply.unwrap_or(0);
+    let base_uri = collection.base_uri.clone().unwrap_or_default();
+    let result = deployer.create_collection_nft(
+        &payer,
+        &collection.name,
+        &collection.symbol,
+        &base_uri,
+        max_size,
+    )?;
+
+    println!();
+    println!("{}", "═".repeat(60).bright_blue());
+    println!("{}", " Collection NFT Created".bold());
+    println!("{}", "═".repeat(60).bright_blue());
+    println!();
+    println!("  {} Collection mint created", "✅".green());
+    println!("  Mint:     {}", result.mint_address.to_string().cyan());
+    println!("  Tx:       {}", result.signature.to_string().dimmed());
+    println!(
+        "  Items:    {}",
+        if max_size > 0 {
+            max_size.to_string()
+        } else {
+            "unbounded".to_string()
+        }
+    );
+    println!("  Network:  {}", network);
+    println!("  Cost:     {:.5} SOL", result.cost_sol);
+    println!("  Explorer: {}", result.explorer_url().cyan());
+
+    println!();
+    println!("{}", "── Post-Deploy Steps (NFT) ──".bright_white());
+    println!();
+    println!(
+        "  {} Mint each collection item, then link it with setAndVerifyCollection:",
+        "1.".bright_white()
+    );
+    println!(
+        "     {}",
+        "https://developers.metaplex.com/token-metadata/collections".cyan()
+    );
+    println!(
+        "  {} NTT doesn't bridge NFTs directly — each item needs its own cross-chain \
+        record (e.g. a Wormhole NFT Bridge transfer, or an off-chain mapping the \
+        marketplace trusts).",
+        "2.".bright_white()
+    );
+    println!();
+    println!("{}", "═".repeat(60).bright_blue());
+
+    Ok(())
+}
@@ -4,9 +4,10 @@ use serde::Serialize;
 use std::time::Duration;
 use tokio::time::sleep;
 
-use crate::analyzers::{BridgeDetector, CompatibilityChecker, EvmAnalyzer};
-use crate::scoring::RiskScorer;
-use crate::types::{Chain, FullAnalysis};
+use crate::analyzers::{BridgeDetector, EvmAnalyzer};
+use crate::commands::analyze_full;
+use crate::config::DaybreakConfig;
+use crate::types::Chain;
 
 /// A curated token entry for the list scan
 struct TokenEntry {
@@ -253,6 +254,7 @@ struct ListRow {
     recommended_mode: String,
     already_on_solana: bool,
     bridge_provider: Option<String>,
+    bridge_verified: bool,
 }
 
 /// Run the list command — scan curated tokens and display a ranked table
@@ -264,6 +266,11 @@ pub async fn run_list(
 ) -> Result<()> {
     let chain: Chain = chain.parse()?;
 
+    // A project's daybreak.toml can pin a preferred RPC endpoint per chain — consulted
+    // when `--rpc-url` isn't given, same resolution order as `run_deploy`.
+    let rpc_url =
+        rpc_url.or_else(|| DaybreakConfig::load().rpc_url(chain.display_name()).map(String::from));
+
     let tokens: &[TokenEntry] = match limit {
         Some(n) => &TOKEN_LIST[..n.min(TOKEN_LIST.len())],
         None => TOKEN_LIST,
@@ -291,7 +298,7 @@ pub async fn run_list(
             &entry.address[..10],
         );
 
-        match analyze_token(&evm, &bridge_detector, entry.address, chain).await {
+        match analyze_full(&evm, &bridge_detector, entry.address, chain).await {
             Ok(analysis) => {
                 rows.push(ListRow {
                     symbol: analysis.token.symbol.clone(),
@@ -302,6 +309,7 @@ pub async fn run_list(
                     recommended_mode: analysis.compatibility.recommended_mode.to_string(),
                     already_on_solana: analysis.bridge_status.already_on_solana,
                     bridge_provider: analysis.bridge_status.bridge_provider.clone(),
+                    bridge_verified: analysis.bridge_status.bridge_verified,
                 });
             }
             Err(e) => {
@@ -333,32 +341,6 @@ pub async fn run_list(
     Ok(())
 }
 
-/// Analyze a single token (like scan, but without holder data)
-async fn analyze_token(
-    evm: &EvmAnalyzer,
-    bridge_detector: &BridgeDetector,
-    address: &str,
-    chain: Chain,
-) -> Result<FullAnalysis> {
-    let token = evm.get_token_info(address).await?;
-    let capabilities = evm.get_capabilities(address).await?;
-    let bytecode = evm.analyze_bytecode(address).await?;
-    let compatibility = CompatibilityChecker::check(&token, &capabilities, &bytecode);
-    let bridge_status = bridge_detector.check(address, chain).await?;
-    let risk_score = RiskScorer::calculate(&token, &capabilities, &bytecode, &bridge_status, None);
-
-    Ok(FullAnalysis {
-        token,
-        capabilities,
-        bytecode,
-        compatibility,
-        bridge_status,
-        risk_score,
-        holder_data: None,
-        rate_limit: None,
-    })
-}
-
 /// Print the results as a formatted table
 fn print_table(rows: &[ListRow]) {
     let header_line = "═".repeat(79);
@@ -394,7 +376,12 @@ fn print_table(rows: &[ListRow]) {
 
         let mode_str = &row.recommended_mode;
 
-        let status = if row.already_on_solana {
+        let status = if row.already_on_solana && !row.bridge_verified {
+            let provider = row.bridge_provider.as_deref().unwrap_or("Unknown");
+            format!("Already on Solana ({}) — UNVERIFIED, quorum not confirmed", provider)
+                .red()
+                .to_string()
+        } else if row.already_on_solana {
             let provider = row.bridge_provider.as_deref().unwrap_or("Unknown");
             format!("Already on Solana ({})", provider)
                 .dimmed()
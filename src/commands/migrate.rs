@@ -6,8 +6,11 @@ use std::io::{self, Write};
 use std::process::Command;
 use std::time::Duration;
 
+use crate::analyzers::bridges::wormhole_chain_name;
 use crate::analyzers::{BridgeDetector, CompatibilityChecker, EvmAnalyzer};
-use crate::report::NttConfigGenerator;
+use crate::migration_state::MigrationState;
+use crate::output::TerminalOutput;
+use crate::report::{NttConfigGenerator, NttDestination, NttNetwork};
 use crate::solana::SolanaDeployer;
 use crate::types::{Chain, FullAnalysis};
 
@@ -55,9 +58,15 @@ pub async fn run_migrate(
     network: &str,
     keypair_path: &str,
     skip_ntt: bool,
+    restart: bool,
+    stats: bool,
 ) -> Result<()> {
     let chain: Chain = chain.parse()?;
 
+    if restart {
+        MigrationState::discard();
+    }
+
     println!();
     println!("{}", "═".repeat(60).bright_blue());
     println!("{}", "  DAYBREAK — End-to-End NTT Migration".bold());
@@ -89,10 +98,23 @@ pub async fn run_migrate(
     // ── Step 2: Analyze token ──
     let pb = spinner("Analyzing EVM token...");
     let evm = EvmAnalyzer::new(chain, rpc_url);
+    if stats {
+        evm.rpc().enable_stats();
+    }
     let token = evm.get_token_info(address).await?;
     let capabilities = evm.get_capabilities(address).await?;
     let bytecode = evm.analyze_bytecode(address).await?;
-    let compatibility = CompatibilityChecker::check(&token, &capabilities, &bytecode);
+    let access_control = evm.get_access_control(address).await?;
+    let governance = evm
+        .get_governance_profile(address, &capabilities, &access_control)
+        .await?;
+    let compatibility = CompatibilityChecker::new().check(
+        &token,
+        &capabilities,
+        &bytecode,
+        &access_control,
+        &governance,
+    );
     let bridge_detector = BridgeDetector::new();
     let bridge_status = bridge_detector.check(address, chain).await?;
     let risk_score = crate::scoring::RiskScorer::calculate(
@@ -110,12 +132,14 @@ pub async fn run_migrate(
     let analysis = FullAnalysis {
         token,
         capabilities,
+        access_control,
         bytecode,
         compatibility,
         bridge_status,
         risk_score,
         holder_data: None,
         rate_limit: None,
+        migration_cost: None,
     };
 
     if !analysis.compatibility.is_compatible {
@@ -149,6 +173,17 @@ pub async fn run_migrate(
                 .as_deref()
                 .unwrap_or("unknown bridge")
         );
+        if let Some(mint) = &analysis.bridge_status.solana_address {
+            println!("    Existing mint: {}", mint.cyan());
+        }
+        if let Some(origin) = &analysis.bridge_status.wrapped_origin {
+            println!(
+                "    Recorded origin: {} ({} decimals) on {}",
+                origin.token_address_hex(),
+                origin.original_decimals,
+                wormhole_chain_name(origin.chain)
+            );
+        }
         eprint!("  Continue anyway? [y/N] ");
         io::stderr().flush()?;
         let mut input = String::new();
@@ -161,76 +196,107 @@ pub async fn run_migrate(
 
     let spl_decimals = analysis.token.decimals.min(9);
 
-    // ── Step 3: Deploy SPL token ──
-    let pb = spinner("Loading Solana keypair...");
-    let expanded_path = if keypair_path.starts_with("~/") {
-        let home = std::env::var("HOME").context("HOME not set")?;
-        keypair_path.replacen('~', &home, 1)
-    } else {
-        keypair_path.to_string()
-    };
-    let payer = read_keypair_file(&expanded_path)
-        .map_err(|e| anyhow::anyhow!("Failed to load keypair from {}: {}", expanded_path, e))?;
-    pb.finish_with_message(format!("Keypair loaded: {} ✓", payer.pubkey()));
-
-    let deployer = SolanaDeployer::new(network);
-
-    // Mainnet confirmation
-    if deployer.is_mainnet() {
-        eprint!(
-            "\n  {} {} This will deploy to Solana {}. Proceed? [y/N] ",
-            "⚠".yellow().bold(),
-            "WARNING:".yellow().bold(),
-            "MAINNET".red().bold()
+    let existing_state = MigrationState::load_matching(address, network);
+
+    // ── Step 3: Deploy SPL token (skipped when resuming — the mint already exists) ──
+    let (mint_str, spl_decimals, cost_sol, mut state) = if let Some(state) = existing_state {
+        println!();
+        println!(
+            "  {} Resuming migration — SPL mint {} already deployed.",
+            "↻".cyan().bold(),
+            state.mint_address.cyan()
         );
-        io::stderr().flush()?;
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        if !input.trim().eq_ignore_ascii_case("y") {
-            println!("  Aborted.");
-            return Ok(());
+        let mint_str = state.mint_address.clone();
+        let decimals = state.decimals;
+        (mint_str, decimals, None, state)
+    } else {
+        let pb = spinner("Loading Solana keypair...");
+        let expanded_path = if keypair_path.starts_with("~/") {
+            let home = std::env::var("HOME").context("HOME not set")?;
+            keypair_path.replacen('~', &home, 1)
+        } else {
+            keypair_path.to_string()
+        };
+        let payer = read_keypair_file(&expanded_path).map_err(|e| {
+            anyhow::anyhow!("Failed to load keypair from {}: {}", expanded_path, e)
+        })?;
+        pb.finish_with_message(format!("Keypair loaded: {} ✓", payer.pubkey()));
+
+        let deployer = SolanaDeployer::new(network);
+
+        // Mainnet confirmation
+        if deployer.is_mainnet() {
+            eprint!(
+                "\n  {} {} This will deploy to Solana {}. Proceed? [y/N] ",
+                "⚠".yellow().bold(),
+                "WARNING:".yellow().bold(),
+                "MAINNET".red().bold()
+            );
+            io::stderr().flush()?;
+            let mut input = String::new();
+            io::stdin().read_line(&mut input)?;
+            if !input.trim().eq_ignore_ascii_case("y") {
+                println!("  Aborted.");
+                return Ok(());
+            }
         }
-    }
 
-    let pb = spinner("Checking wallet balance...");
-    let balance = deployer.get_balance(&payer.pubkey())?;
-    if balance < 0.01 {
-        pb.finish_with_message("Insufficient balance ✗".to_string());
-        bail!(
-            "Insufficient balance: {:.4} SOL. Need at least 0.01 SOL. {}",
-            balance,
-            if !deployer.is_mainnet() {
-                "Run `solana airdrop 2` to fund your devnet wallet."
-            } else {
-                ""
-            }
-        );
-    }
-    pb.finish_with_message(format!("Balance: {:.4} SOL ✓", balance));
-
-    let pb = spinner("Creating SPL token mint...");
-    let result = deployer.create_spl_token(&payer, spl_decimals)?;
-    let mint_str = result.mint_address.to_string();
-    pb.finish_with_message(format!("SPL token created: {} ✓", mint_str));
-
-    // Create metadata
-    let pb = spinner("Creating on-chain metadata (Metaplex)...");
-    match deployer.create_metadata(
-        &payer,
-        &result.mint_address,
-        &analysis.token.name,
-        &analysis.token.symbol,
-    ) {
-        Ok(_) => pb.finish_with_message(format!(
-            "Metadata created: {} ({}) ✓",
-            analysis.token.name, analysis.token.symbol
-        )),
-        Err(e) => pb.finish_with_message(format!("Metadata failed (non-blocking): {} ⚠", e)),
-    }
+        let pb = spinner("Checking wallet balance...");
+        let balance = deployer.get_balance(&payer.pubkey())?;
+        if balance < 0.01 {
+            pb.finish_with_message("Insufficient balance ✗".to_string());
+            bail!(
+                "Insufficient balance: {:.4} SOL. Need at least 0.01 SOL. {}",
+                balance,
+                if !deployer.is_mainnet() {
+                    "Run `solana airdrop 2` to fund your devnet wallet."
+                } else {
+                    ""
+                }
+            );
+        }
+        pb.finish_with_message(format!("Balance: {:.4} SOL ✓", balance));
+
+        let pb = spinner("Creating SPL token mint with on-chain metadata...");
+        let result = deployer.create_spl_token(
+            &payer,
+            spl_decimals,
+            &analysis.token.name,
+            &analysis.token.symbol,
+            None,
+        )?;
+        let mint_str = result.mint_address.to_string();
+        pb.finish_with_message(format!(
+            "SPL token created: {} ({}/{}) ✓",
+            mint_str, analysis.token.name, analysis.token.symbol
+        ));
+
+        // Record progress now — if the process dies anywhere after this, the next run
+        // picks up from here instead of minting a second SPL token for the same source.
+        let state = MigrationState::new(address, network, &mint_str, spl_decimals);
+        state
+            .save()
+            .context("Failed to write migration state file")?;
+
+        (mint_str, spl_decimals, Some(result.cost_sol), state)
+    };
 
     // ── Step 4: Write deployment.json ──
     let pb = spinner("Writing deployment.json...");
-    let deployment_json = NttConfigGenerator::generate_deployment_json(&analysis)?;
+    let ntt_network = if network == "mainnet" {
+        NttNetwork::Mainnet
+    } else {
+        NttNetwork::Testnet
+    };
+    let mut solana_destination = NttDestination::solana(&analysis);
+    solana_destination.decimals = spl_decimals;
+    solana_destination.token_address = Some(mint_str.clone());
+    let destinations = vec![solana_destination];
+    let deployment_json = NttConfigGenerator::generate_deployment_json(
+        &analysis,
+        ntt_network,
+        &destinations,
+    )?;
     let config_path = "deployment.json";
     std::fs::write(config_path, &deployment_json)?;
     pb.finish_with_message("deployment.json written ✓".to_string());
@@ -244,41 +310,67 @@ pub async fn run_migrate(
             .to_string()
             .to_lowercase();
 
-        // ntt init
-        let pb = spinner("Initializing NTT project...");
-        run_ntt_command(&["init"])?;
-        pb.finish_with_message("NTT initialized ✓".to_string());
-
-        // ntt add-chain source
-        let pb = spinner(&format!("Adding source chain ({})...", source_chain));
-        run_ntt_command(&[
-            "add-chain",
-            &source_chain,
-            "--mode",
-            &mode,
-            "--token",
-            address,
-        ])?;
-        pb.finish_with_message(format!("Source chain {} added ✓", source_chain));
-
-        // ntt add-chain solana
-        let pb = spinner("Adding Solana as destination...");
-        run_ntt_command(&[
-            "add-chain",
-            "solana",
-            "--mode",
-            "burning",
-            "--token",
-            &mint_str,
-            "--decimals",
-            &spl_decimals.to_string(),
-        ])?;
-        pb.finish_with_message("Solana destination added ✓".to_string());
-
-        // ntt push
-        let pb = spinner("Deploying NTT contracts (this may take a few minutes)...");
-        run_ntt_command(&["push"])?;
-        pb.finish_with_message("NTT contracts deployed ✓".to_string());
+        // Each phase is only run if it didn't already succeed in a prior (crashed or
+        // interrupted) attempt, and the state file is updated immediately after, so a
+        // second crash resumes at the next incomplete phase rather than redoing work.
+        if !state.ntt_phases.init {
+            let pb = spinner("Initializing NTT project...");
+            run_ntt_command(&["init"])?;
+            pb.finish_with_message("NTT initialized ✓".to_string());
+            state.ntt_phases.init = true;
+            state.save().context("Failed to update migration state file")?;
+        } else {
+            println!("  {} NTT project already initialized (resumed) ✓", "↻".cyan());
+        }
+
+        if !state.ntt_phases.add_chain_source {
+            let pb = spinner(&format!("Adding source chain ({})...", source_chain));
+            run_ntt_command(&[
+                "add-chain",
+                &source_chain,
+                "--mode",
+                &mode,
+                "--token",
+                address,
+            ])?;
+            pb.finish_with_message(format!("Source chain {} added ✓", source_chain));
+            state.ntt_phases.add_chain_source = true;
+            state.save().context("Failed to update migration state file")?;
+        } else {
+            println!("  {} Source chain already added (resumed) ✓", "↻".cyan());
+        }
+
+        if !state.ntt_phases.add_chain_solana {
+            let pb = spinner("Adding Solana as destination...");
+            run_ntt_command(&[
+                "add-chain",
+                "solana",
+                "--mode",
+                "burning",
+                "--token",
+                &mint_str,
+                "--decimals",
+                &spl_decimals.to_string(),
+            ])?;
+            pb.finish_with_message("Solana destination added ✓".to_string());
+            state.ntt_phases.add_chain_solana = true;
+            state.save().context("Failed to update migration state file")?;
+        } else {
+            println!("  {} Solana destination already added (resumed) ✓", "↻".cyan());
+        }
+
+        if !state.ntt_phases.push {
+            let pb = spinner("Deploying NTT contracts (this may take a few minutes)...");
+            run_ntt_command(&["push"])?;
+            pb.finish_with_message("NTT contracts deployed ✓".to_string());
+            state.ntt_phases.push = true;
+            state.save().context("Failed to update migration state file")?;
+        } else {
+            println!("  {} NTT contracts already deployed (resumed) ✓", "↻".cyan());
+        }
+
+        // Every phase succeeded — nothing left to resume.
+        MigrationState::discard();
     }
 
     // ── Step 6: Print summary ──
@@ -299,15 +391,26 @@ pub async fn run_migrate(
     );
     println!("  SPL Mint: {}", mint_str.cyan());
     println!("  Network:  {}", network);
-    println!("  Cost:     {:.5} SOL", result.cost_sol);
+    match cost_sol {
+        Some(cost) => println!("  Cost:     {:.5} SOL", cost),
+        None => println!("  Cost:     (mint deployed in a previous run)"),
+    }
     println!();
-    println!("  Explorer: {}", result.explorer_url().cyan());
+    let explorer_url = if network == "mainnet" {
+        format!("https://explorer.solana.com/address/{}", mint_str)
+    } else {
+        format!(
+            "https://explorer.solana.com/address/{}?cluster={}",
+            mint_str, network
+        )
+    };
+    println!("  Explorer: {}", explorer_url.cyan());
 
     if skip_ntt {
         println!();
         println!("{}", "── Remaining Steps (NTT CLI) ──".bright_white());
         println!();
-        let cli_commands = NttConfigGenerator::generate_cli_commands(&analysis);
+        let cli_commands = NttConfigGenerator::generate_cli_commands(&analysis, &destinations);
         for cmd in &cli_commands {
             if cmd.starts_with('#') || cmd.is_empty() {
                 println!("  {}", cmd.dimmed());
@@ -349,6 +452,10 @@ pub async fn run_migrate(
     println!();
     println!("{}", "═".repeat(60).bright_blue());
 
+    if stats {
+        TerminalOutput::print_rpc_stats(&evm.rpc().stats_summary());
+    }
+
     Ok(())
 }
 
@@ -1,3 +1,4 @@
+pub mod batch;
 pub mod check;
 pub mod compare;
 pub mod deploy;
@@ -5,9 +6,91 @@ pub mod list;
 pub mod report;
 pub mod scan;
 
+pub use batch::run_batch_compare;
 pub use check::run_check;
 pub use compare::run_compare;
 pub use deploy::run_deploy;
 pub use list::run_list;
 pub use report::run_report;
 pub use scan::run_scan;
+
+use crate::analyzers::evm::EvmRpcClient;
+use crate::analyzers::{BridgeDetector, CompatibilityChecker, EvmAnalyzer};
+use crate::analyzers::{HolderAnalyzer, HolderSource, LogScanHolderAnalyzer};
+use crate::scoring::RiskScorer;
+use crate::types::{Chain, FullAnalysis, HolderData};
+use anyhow::Result;
+
+/// Run the full analyze+compatibility+bridge+risk pipeline for a single token, without
+/// holder data (holder data needs its own opt-in flag per caller, via `fetch_holder_data`
+/// above). Shared by `list`, `compare`, and `batch`, which all build the same bare
+/// `FullAnalysis` before layering on their own holder-data/cost/snapshot handling.
+pub(crate) async fn analyze_full(
+    evm: &EvmAnalyzer,
+    bridge_detector: &BridgeDetector,
+    address: &str,
+    chain: Chain,
+) -> Result<FullAnalysis> {
+    let token = evm.get_token_info(address).await?;
+    let capabilities = evm.get_capabilities(address).await?;
+    let bytecode = evm.analyze_bytecode(address).await?;
+    let access_control = evm.get_access_control(address).await?;
+    let governance = evm
+        .get_governance_profile(address, &capabilities, &access_control)
+        .await?;
+    let compatibility = CompatibilityChecker::new().check(
+        &token,
+        &capabilities,
+        &bytecode,
+        &access_control,
+        &governance,
+    );
+    let bridge_status = bridge_detector.check(address, chain).await?;
+    let risk_score = RiskScorer::calculate(&token, &capabilities, &bytecode, &bridge_status, None);
+
+    Ok(FullAnalysis {
+        token,
+        capabilities,
+        access_control,
+        bytecode,
+        compatibility,
+        bridge_status,
+        risk_score,
+        holder_data: None,
+        rate_limit: None,
+        migration_cost: None,
+    })
+}
+
+/// Resolve holder distribution data per `--holder-source`. `"etherscan"`/`"logscan"`
+/// force a specific source; anything else (`"auto"`, the default) uses Etherscan when a
+/// key is configured, falling back to reconstructing balances from on-chain `Transfer`
+/// logs otherwise — removing the PRO-key barrier `HolderAnalyzer` alone has. `rpc` is
+/// `None` for non-EVM origins; callers should gate on `chain.is_evm()` before calling
+/// this at all, same as they already do for `HolderAnalyzer`.
+pub(crate) async fn fetch_holder_data(
+    holder_source: &str,
+    etherscan_key: &Option<String>,
+    rpc: Option<&EvmRpcClient>,
+    address: &str,
+    chain: Chain,
+) -> Option<HolderData> {
+    let use_logscan = match holder_source {
+        "etherscan" => false,
+        "logscan" => true,
+        _ => etherscan_key.is_none(),
+    };
+
+    if use_logscan {
+        let rpc = rpc?;
+        LogScanHolderAnalyzer::new(rpc)
+            .get_holders(address, chain)
+            .await
+            .ok()
+    } else {
+        HolderAnalyzer::new(etherscan_key.clone())
+            .get_holders(address, chain)
+            .await
+            .ok()
+    }
+}
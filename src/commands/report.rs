@@ -1,10 +1,18 @@
-use anyhow::Result;
+use alloy::primitives::Address;
+use anyhow::{Context, Result};
 use std::path::Path;
-use crate::analyzers::{BridgeDetector, CompatibilityChecker, EvmAnalyzer, HolderAnalyzer};
+use crate::analyzers::solana::SolanaChecker;
+use crate::analyzers::{
+    source_chain_analyzer, AnalysisCache, BridgeDetector, CachedAnalysis, CompatibilityChecker,
+    VolumeAnalyzer,
+};
+use crate::commands::fetch_holder_data;
 use crate::output::MarkdownGenerator;
-use crate::report::{MigrationPlanGenerator, NttConfigGenerator};
+use crate::report::{
+    CostEstimator, MigrationPlanGenerator, NttConfigGenerator, NttDestination, NttNetwork,
+};
 use crate::scoring::RiskScorer;
-use crate::types::{Chain, FullAnalysis};
+use crate::types::{AttestationStatus, BridgeStatus, Chain, FullAnalysis};
 
 /// Run the report command
 pub async fn run_report(
@@ -14,37 +22,120 @@ pub async fn run_report(
     etherscan_key: Option<String>,
     output_dir: &str,
     skip_holders: bool,
+    holder_source: &str,
 ) -> Result<()> {
     let chain: Chain = chain.parse()?;
+
+    // Fail fast on a malformed address rather than letting it flow into RPC calls and a
+    // garbage report
+    if chain.is_evm() {
+        Address::parse_checksummed(address, None)
+            .with_context(|| format!("'{address}' is not a valid EIP-55 checksummed address"))?;
+    } else {
+        address
+            .parse::<solana_sdk::pubkey::Pubkey>()
+            .with_context(|| format!("'{address}' is not a valid Solana address"))?;
+    }
+
     let output_path = Path::new(output_dir);
 
     // Create output directory if needed
     tokio::fs::create_dir_all(output_path).await?;
 
     // Initialize analyzers
-    let evm = EvmAnalyzer::new(chain, rpc_url);
+    let source = source_chain_analyzer(chain, rpc_url.clone());
     let bridge_detector = BridgeDetector::new();
-    let holder_analyzer = HolderAnalyzer::new(etherscan_key);
 
     println!("Analyzing token {}...", address);
 
-    // Fetch token info
-    let token = evm.get_token_info(address).await?;
-    println!("  Found: {} ({})", token.name, token.symbol);
-
-    let capabilities = evm.get_capabilities(address).await?;
-    let bytecode = evm.analyze_bytecode(address).await?;
-    println!("  Bytecode: {} bytes", bytecode.size_bytes);
+    // Reuse a prior analysis when the on-chain code hash hasn't changed, so a repeat
+    // report skips re-fetching and re-analyzing bytecode. Only EVM origins expose a cheap
+    // code-hash check (via `eth_getProof`); Solana mints are re-analyzed every time.
+    let mut cache = AnalysisCache::load();
+    let code_hash = match source.as_evm_rpc() {
+        Some(rpc) => rpc.get_code_hash(address).await.ok(),
+        None => None,
+    };
+    let cached = code_hash
+        .as_deref()
+        .and_then(|hash| cache.get(chain, address, hash));
+
+    let (token, capabilities, bytecode, access_control) = if let Some(cached) = cached {
+        println!("  Found: {} ({}) [cached]", cached.token.name, cached.token.symbol);
+        println!("  Bytecode: {} bytes [cached]", cached.bytecode.size_bytes);
+        (
+            cached.token.clone(),
+            cached.capabilities.clone(),
+            cached.bytecode.clone(),
+            cached.access_control.clone(),
+        )
+    } else {
+        let token = source.get_token_info(address).await?;
+        println!("  Found: {} ({})", token.name, token.symbol);
+
+        let capabilities = source.get_capabilities(address).await?;
+        let bytecode = source.analyze_program(address).await?;
+        println!("  Bytecode: {} bytes", bytecode.size_bytes);
+
+        let access_control = source.get_access_control(address).await?;
+
+        if let Some(hash) = code_hash {
+            cache.put(
+                chain,
+                address,
+                CachedAnalysis {
+                    code_hash: hash,
+                    token: token.clone(),
+                    capabilities: capabilities.clone(),
+                    bytecode: bytecode.clone(),
+                    access_control: access_control.clone(),
+                },
+            );
+            cache.save().ok();
+        }
+
+        (token, capabilities, bytecode, access_control)
+    };
 
     // Check compatibility
-    let compatibility = CompatibilityChecker::check(&token, &capabilities, &bytecode);
+    let governance = source
+        .get_governance_profile(address, &capabilities, &access_control)
+        .await?;
+    let compatibility = CompatibilityChecker::new().check(
+        &token,
+        &capabilities,
+        &bytecode,
+        &access_control,
+        &governance,
+    );
 
-    // Check existing bridges
-    let bridge_status = bridge_detector.check(address, chain).await?;
+    // Check existing bridges — only meaningful for an EVM-origin token, since a
+    // Solana-origin token is already there
+    let bridge_status = if chain.is_evm() {
+        bridge_detector.check(address, chain).await?
+    } else {
+        BridgeStatus {
+            already_on_solana: true,
+            solana_address: Some(address.to_string()),
+            bridge_provider: None,
+            bridge_type: None,
+            // The address given IS the Solana mint itself, not an inferred match
+            bridge_verified: true,
+            wormhole_attestation: AttestationStatus::default(),
+            wrapped_origin: None,
+        }
+    };
 
     // Fetch holder data (optional)
-    let holder_data = if !skip_holders {
-        holder_analyzer.get_holders(address, chain).await.ok()
+    let holder_data = if !skip_holders && chain.is_evm() {
+        fetch_holder_data(
+            holder_source,
+            &etherscan_key,
+            source.as_evm_rpc(),
+            address,
+            chain,
+        )
+        .await
     } else {
         None
     };
@@ -58,15 +149,68 @@ pub async fn run_report(
         holder_data.as_ref(),
     );
 
-    let analysis = FullAnalysis {
+    // Estimate migration cost: live EIP-1559 gas price on the source chain (EVM origins
+    // only), SOL price for the Solana-side deployment rent
+    let gas_estimate = match source.as_evm_rpc() {
+        Some(rpc) => CostEstimator::estimate_gas_fee(rpc, chain).await.ok(),
+        None => None,
+    };
+    // Fall back to a gas-oracle + bytecode-size projection when the live feeHistory
+    // estimate above isn't available (e.g. the RPC doesn't support it) but we have an
+    // Etherscan-family API key to query the gas oracle with.
+    let bytecode_deployment_cost_usd = if gas_estimate.is_none() && chain.is_evm() {
+        match &etherscan_key {
+            Some(key) => CostEstimator::estimate_evm_deployment_cost(
+                chain,
+                bytecode.size_bytes as u64,
+                key,
+            )
+            .await
+            .ok(),
+            None => None,
+        }
+    } else {
+        None
+    };
+    // Rate limit recommendation, so the generated deployment.json's NTT bucket limits
+    // reflect real transfer volume instead of always being left empty
+    let rate_limit = if chain.is_evm() {
+        VolumeAnalyzer::new(etherscan_key.clone())
+            .analyze(address, chain, token.decimals, &token.total_supply)
+            .await
+            .ok()
+    } else {
+        None
+    };
+
+    let solana_checker = SolanaChecker::new();
+    let sol_price = solana_checker.get_sol_price().await.unwrap_or(150.0);
+    let sol_deployment = solana_checker.estimate_ntt_deployment_cost();
+    // Median (p50) is a reasonable default for a report meant to reflect typical cost;
+    // callers wanting a conservative budget can sample a higher percentile directly.
+    let solana_priority_fee =
+        CostEstimator::estimate_solana_priority_fee(None, 50.0, None).ok();
+
+    let mut analysis = FullAnalysis {
         token: token.clone(),
         capabilities,
+        access_control,
         bytecode,
         compatibility,
         bridge_status,
         risk_score,
         holder_data,
+        rate_limit,
+        migration_cost: None,
     };
+    analysis.migration_cost = Some(CostEstimator::estimate_ntt_costs(
+        &analysis,
+        sol_price,
+        sol_deployment,
+        gas_estimate,
+        bytecode_deployment_cost_usd,
+        solana_priority_fee,
+    ));
 
     // Generate migration plan
     let plan = MigrationPlanGenerator::generate(&analysis);
@@ -77,14 +221,17 @@ pub async fn run_report(
     tokio::fs::write(&report_path, &report_content).await?;
     println!("  Generated: {}", report_path.display());
 
-    // Generate deployment.json
-    let deployment_json = NttConfigGenerator::generate_deployment_json(&analysis)?;
+    // Generate deployment.json — Solana is the destination Sunrise cares about, but NTT
+    // is hub-and-spoke, so the generator takes an arbitrary set of destination peers
+    let destinations = vec![NttDestination::solana(&analysis)];
+    let deployment_json =
+        NttConfigGenerator::generate_deployment_json(&analysis, NttNetwork::Mainnet, &destinations)?;
     let deployment_path = output_path.join("deployment.json");
     tokio::fs::write(&deployment_path, &deployment_json).await?;
     println!("  Generated: {}", deployment_path.display());
 
     // Generate CLI commands
-    let commands = NttConfigGenerator::generate_cli_commands(&analysis);
+    let commands = NttConfigGenerator::generate_cli_commands(&analysis, &destinations);
     let commands_path = output_path.join("ntt-commands.sh");
     tokio::fs::write(&commands_path, commands.join("\n")).await?;
     println!("  Generated: {}", commands_path.display());
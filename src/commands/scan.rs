@@ -1,9 +1,9 @@
-use crate::analyzers::{
-    BridgeDetector, CompatibilityChecker, EvmAnalyzer, HolderAnalyzer, VolumeAnalyzer,
-};
+use crate::analyzers::{BridgeDetector, CompatibilityChecker, EvmAnalyzer, VolumeAnalyzer};
+use crate::commands::fetch_holder_data;
 use crate::output::{JsonOutput, TerminalOutput};
+use crate::report::PathComparator;
 use crate::scoring::RiskScorer;
-use crate::types::{Chain, FullAnalysis};
+use crate::types::{Chain, FullAnalysis, NftScanResult};
 use anyhow::Result;
 use colored::Colorize;
 
@@ -19,8 +19,16 @@ pub async fn run_scan(
     rpc_url: Option<String>,
     etherscan_key: Option<String>,
     skip_holders: bool,
+    holder_source: &str,
     json_output: bool,
+    stats: bool,
+    emit_schema: bool,
 ) -> Result<()> {
+    if emit_schema {
+        println!("{}", JsonOutput::schema_for_analysis()?);
+        return Ok(());
+    }
+
     let chain: Chain = chain.parse()?;
 
     eprintln!(
@@ -32,9 +40,18 @@ pub async fn run_scan(
 
     // Initialize analyzers
     let evm = EvmAnalyzer::new(chain, rpc_url);
+    if stats {
+        evm.rpc().enable_stats();
+    }
+
+    // ERC-721/1155 collections have no decimals/supply-in-the-ERC20-sense to analyze —
+    // route them to a dedicated NFT scan instead of failing at `get_token_info`
+    if evm.is_erc721(address).await.unwrap_or(false) || evm.is_erc1155(address).await.unwrap_or(false) {
+        return run_scan_nft(address, &evm, json_output).await;
+    }
+
     let bridge_detector = BridgeDetector::new();
-    let holder_analyzer = HolderAnalyzer::new(etherscan_key.clone());
-    let volume_analyzer = VolumeAnalyzer::new(etherscan_key);
+    let volume_analyzer = VolumeAnalyzer::new(etherscan_key.clone());
 
     // Fetch token info
     progress("Fetching token metadata...");
@@ -43,10 +60,21 @@ pub async fn run_scan(
     let capabilities = evm.get_capabilities(address).await?;
     progress("Scanning bytecode for patterns...");
     let bytecode = evm.analyze_bytecode(address).await?;
+    progress("Checking access control...");
+    let access_control = evm.get_access_control(address).await?;
+    let governance = evm
+        .get_governance_profile(address, &capabilities, &access_control)
+        .await?;
 
     // Check compatibility
     progress("Checking NTT compatibility...");
-    let compatibility = CompatibilityChecker::check(&token, &capabilities, &bytecode);
+    let compatibility = CompatibilityChecker::new().check(
+        &token,
+        &capabilities,
+        &bytecode,
+        &access_control,
+        &governance,
+    );
 
     // Check existing bridges
     progress("Searching for existing bridges...");
@@ -55,7 +83,14 @@ pub async fn run_scan(
     // Fetch holder data (optional)
     let holder_data = if !skip_holders {
         progress("Fetching holder distribution...");
-        holder_analyzer.get_holders(address, chain).await.ok()
+        fetch_holder_data(
+            holder_source,
+            &etherscan_key,
+            Some(evm.rpc()),
+            address,
+            chain,
+        )
+        .await
     } else {
         None
     };
@@ -82,12 +117,14 @@ pub async fn run_scan(
     let analysis = FullAnalysis {
         token,
         capabilities,
+        access_control,
         bytecode,
         compatibility,
         bridge_status,
         risk_score,
         holder_data,
         rate_limit,
+        migration_cost: None,
     };
 
     // Output
@@ -97,5 +134,39 @@ pub async fn run_scan(
         TerminalOutput::print_scan(&analysis);
     }
 
+    if stats {
+        TerminalOutput::print_rpc_stats(&evm.rpc().stats_summary());
+    }
+
+    Ok(())
+}
+
+/// Scan an ERC-721/1155 collection. This reports collection-level compatibility and the
+/// (sole, for now) NFT bridge migration path — bridge *status* is per-token-id, so unlike
+/// the fungible scan's `BridgeStatus` this doesn't report whether any specific token has
+/// already been bridged (use `derive_nft_bridge_wrapped_mint` directly for that, once a
+/// token id is known).
+async fn run_scan_nft(address: &str, evm: &EvmAnalyzer, json_output: bool) -> Result<()> {
+    progress("Fetching NFT collection metadata...");
+    let collection = evm.get_collection_info(address).await?;
+
+    progress("Checking NFT bridge compatibility...");
+    let compatibility = CompatibilityChecker::new().check_nft(&collection);
+    let migration_path = PathComparator::evaluate_nft_bridge(&compatibility);
+
+    eprintln!("  {} {}\n", "✓".green(), "Analysis complete.".green());
+
+    let result = NftScanResult {
+        collection,
+        compatibility,
+        migration_path,
+    };
+
+    if json_output {
+        println!("{}", JsonOutput::format_nft_scan(&result)?);
+    } else {
+        TerminalOutput::print_nft_scan(&result);
+    }
+
     Ok(())
 }
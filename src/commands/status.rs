@@ -1,3 +1,8 @@
+use crate::analyzers::bridges::{token_bridge_custody_address, wormhole_chain_id, wormhole_chain_name};
+use crate::analyzers::{BridgeDetector, ChainChecker, CheckerKind, EvmAnalyzer};
+use crate::commands::analyze_full;
+use crate::scoring::SupplyReconciler;
+use crate::types::{AttestationStatus, Chain, ReconciliationReport, TokenInfo};
 use anyhow::{Context, Result};
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
@@ -9,6 +14,43 @@ use spl_token::state::Mint;
 use std::str::FromStr;
 use std::time::Duration;
 
+/// Wormhole Token Bridge program on Solana mainnet-beta
+const TOKEN_BRIDGE_PROGRAM_MAINNET: &str = "wormDTUJ6AWPNvk59vGQbDvGJmqbDTdgWgAqcLBCgUb";
+/// Wormhole Token Bridge program on Solana devnet
+const TOKEN_BRIDGE_PROGRAM_DEVNET: &str = "DZnkkTmCiFWfYTfT41X3Rd1kDgozqzxWaHqsw6W4x2oe";
+
+/// Wormhole's origin-decimals cap — the Token Bridge always mints wrapped assets at
+/// `min(original_decimals, 8)`, so a mint's own decimals alone can't tell you whether the
+/// source asset's precision was actually reduced in the process.
+const WORMHOLE_MAX_DECIMALS: u8 = 8;
+
+/// Resolved Token Bridge wrapped-asset metadata for a mint — where it actually came from,
+/// not just that it's an SPL token.
+struct WrappedAssetInfo {
+    origin_chain_name: String,
+    origin_chain_id: u16,
+    origin_token_address: String,
+    original_decimals: u8,
+    decimals_truncated: bool,
+}
+
+/// Result of cross-checking a user's claimed EVM source chain against on-chain reality.
+struct SourceChainCheck {
+    claimed_chain: Chain,
+    /// `None` if the RPC call itself failed rather than returning a mismatched id.
+    observed_chain_id: Option<u64>,
+    source_token: Option<TokenInfo>,
+    /// Guardian-verified attestation for the claimed source token's most recent Wormhole
+    /// transfer — `None` if no VAA could be fetched at all, distinct from a VAA that was
+    /// fetched but failed to reach quorum (`AttestationStatus::quorum_met == false`).
+    attestation: Option<AttestationStatus>,
+    /// Source-chain locked balance vs. Solana-minted supply, reconciled against whatever
+    /// `REBASING`/`FEE_ON_TRANSFER` issues the source token's own compatibility check
+    /// already flagged. `None` when the source token, a Token Bridge custody address for
+    /// this chain, or the custody balance read itself couldn't be resolved.
+    reconciliation: Option<ReconciliationReport>,
+}
+
 /// Create a styled progress spinner
 fn spinner(msg: &str) -> ProgressBar {
     let pb = ProgressBar::new_spinner();
@@ -37,18 +79,164 @@ struct WormholeTransfersResponse {
     operations: Option<Vec<WormholeOperation>>,
 }
 
+/// One leg (source or target chain side) of a Wormhole transfer
 #[derive(Deserialize)]
 #[allow(dead_code)]
+struct WormholeChainLeg {
+    #[serde(rename = "chainId")]
+    chain_id: Option<u16>,
+    timestamp: Option<String>,
+    status: Option<String>,
+}
+
+/// The fields WormholeScan normalizes across every transfer type, regardless of the
+/// underlying payload (token transfer, NTT, etc.)
+#[derive(Deserialize)]
+struct WormholeStandardizedProperties {
+    amount: Option<String>,
+    #[serde(rename = "fromChain")]
+    from_chain: Option<u16>,
+    #[serde(rename = "toChain")]
+    to_chain: Option<u16>,
+}
+
+#[derive(Deserialize)]
+struct WormholeOperationContent {
+    #[serde(rename = "standardizedProperties")]
+    standardized_properties: Option<WormholeStandardizedProperties>,
+}
+
+#[derive(Deserialize)]
 struct WormholeOperation {
+    id: Option<String>,
+    #[serde(rename = "emitterChain")]
+    emitter_chain: Option<u16>,
+    sequence: Option<String>,
+    content: Option<WormholeOperationContent>,
     #[serde(rename = "sourceChain")]
-    source_chain: Option<serde_json::Value>,
+    source_chain: Option<WormholeChainLeg>,
     #[serde(rename = "targetChain")]
-    target_chain: Option<serde_json::Value>,
-    status: Option<String>,
+    target_chain: Option<WormholeChainLeg>,
+    /// Present once the guardian network has signed the VAA for this transfer — absent
+    /// means the transfer is still awaiting guardian attestation.
+    vaa: Option<serde_json::Value>,
+}
+
+/// Where a transfer is in its lifecycle: attested on the source chain but not yet
+/// redeemed, or fully redeemed on the target chain. WormholeScan's own `status` strings
+/// vary by chain/payload type, so this collapses them to the two states operators
+/// actually care about rather than surfacing every raw string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TransferState {
+    Pending,
+    Completed,
 }
 
-/// Run the status command — post-migration bridge health monitoring
-pub async fn run_status(mint_address: &str, network: &str) -> Result<()> {
+impl std::fmt::Display for TransferState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransferState::Pending => write!(f, "pending"),
+            TransferState::Completed => write!(f, "completed"),
+        }
+    }
+}
+
+/// A decoded, display-ready transfer — the fields `WormholeOperation` captures, rendered
+/// into the shapes `run_status`'s table and `--watch` diffing actually need.
+struct TransferRecord {
+    id: String,
+    source_chain_name: String,
+    target_chain_name: String,
+    amount_display: Option<String>,
+    sequence: Option<String>,
+    vaa_signed: bool,
+    state: TransferState,
+}
+
+/// Decode raw WormholeScan operations into display-ready records — resolving chain ids
+/// to names, normalizing the transfer amount with the wrapped token's decimals, and
+/// collapsing WormholeScan's per-chain status strings into `TransferState`.
+fn decode_transfers(ops: &[WormholeOperation], mint_decimals: u8) -> Vec<TransferRecord> {
+    ops.iter()
+        .enumerate()
+        .map(|(i, op)| {
+            let standardized = op
+                .content
+                .as_ref()
+                .and_then(|c| c.standardized_properties.as_ref());
+
+            let from_chain_id = standardized
+                .and_then(|s| s.from_chain)
+                .or(op.emitter_chain)
+                .or_else(|| op.source_chain.as_ref().and_then(|leg| leg.chain_id));
+            let to_chain_id = standardized
+                .and_then(|s| s.to_chain)
+                .or_else(|| op.target_chain.as_ref().and_then(|leg| leg.chain_id));
+
+            let amount_display = standardized
+                .and_then(|s| s.amount.as_ref())
+                .and_then(|raw| raw.parse::<u64>().ok())
+                .map(|raw| format_supply(raw, mint_decimals));
+
+            let target_completed = op
+                .target_chain
+                .as_ref()
+                .and_then(|leg| leg.status.as_deref())
+                .map(|status| status.eq_ignore_ascii_case("completed") || status.eq_ignore_ascii_case("redeemed"))
+                .unwrap_or(false);
+            let state = if target_completed {
+                TransferState::Completed
+            } else {
+                TransferState::Pending
+            };
+
+            TransferRecord {
+                id: op.id.clone().unwrap_or_else(|| format!("#{}", i)),
+                source_chain_name: from_chain_id
+                    .map(wormhole_chain_name)
+                    .unwrap_or_else(|| "unknown".to_string()),
+                target_chain_name: to_chain_id
+                    .map(wormhole_chain_name)
+                    .unwrap_or_else(|| "unknown".to_string()),
+                amount_display,
+                sequence: op.sequence.clone(),
+                vaa_signed: op.vaa.is_some(),
+                state,
+            }
+        })
+        .collect()
+}
+
+/// Render a decoded transfer as one table row
+fn format_transfer_row(record: &TransferRecord) -> String {
+    let state_colored = match record.state {
+        TransferState::Completed => record.state.to_string().green().to_string(),
+        TransferState::Pending => record.state.to_string().yellow().to_string(),
+    };
+    format!(
+        "{} → {}  {}  seq {}  vaa {}  [{}]",
+        record.source_chain_name,
+        record.target_chain_name,
+        record.amount_display.as_deref().unwrap_or("?"),
+        record.sequence.as_deref().unwrap_or("?"),
+        if record.vaa_signed { "signed" } else { "pending" },
+        state_colored
+    )
+}
+
+/// Run the status command — post-migration bridge health monitoring.
+///
+/// `source_chain`, if given, is the EVM chain the user claims this token migrated from
+/// (e.g. `"ethereum"`) — it's reconciled against the origin chain actually recovered from
+/// the wrapped-asset metadata, so a misremembered or misconfigured source chain gets
+/// flagged rather than silently trusted.
+pub async fn run_status(
+    mint_address: &str,
+    network: &str,
+    source_chain: Option<&str>,
+    watch: bool,
+) -> Result<()> {
+    let source_chain: Option<Chain> = source_chain.map(|s| s.parse()).transpose()?;
     println!();
     println!("{}", "═".repeat(60).bright_blue());
     println!("{}", "  DAYBREAK — Bridge Status Monitor".bold());
@@ -60,7 +248,28 @@ pub async fn run_status(mint_address: &str, network: &str) -> Result<()> {
     let token_info = get_spl_token_info(mint_address, network)?;
     pb.finish_with_message("Token info retrieved ✓".to_string());
 
-    // ── Step 2: Query WormholeScan for recent bridge activity ──
+    // ── Step 2: Resolve wrapped-asset origin via the Token Bridge "wrapped meta" PDA ──
+    let pb = spinner("Resolving wrapped-asset origin...");
+    let wrapped_info = get_wrapped_asset_info(mint_address, network, token_info.decimals);
+    pb.finish_with_message("Origin check complete ✓".to_string());
+
+    // ── Step 3: If the user claims a source EVM chain, cross-check it ──
+    let source_check = match source_chain {
+        Some(chain) => {
+            let pb = spinner(&format!("Verifying claimed source chain ({chain})..."));
+            let origin_address = wrapped_info
+                .as_ref()
+                .ok()
+                .and_then(|info| info.as_ref())
+                .map(|info| info.origin_token_address.as_str());
+            let check = check_source_chain(chain, origin_address, token_info.supply as u128).await;
+            pb.finish_with_message("Source chain check complete ✓".to_string());
+            Some(check)
+        }
+        None => None,
+    };
+
+    // ── Step 4: Query WormholeScan for recent bridge activity ──
     let pb = spinner("Checking WormholeScan for bridge activity...");
     let transfers = get_wormhole_activity(mint_address).await;
     pb.finish_with_message("Bridge activity checked ✓".to_string());
@@ -87,19 +296,207 @@ pub async fn run_status(mint_address: &str, network: &str) -> Result<()> {
         println!("  Freeze Authority: {}", authority.cyan());
     }
 
-    let explorer_url = if network == "mainnet" {
-        format!(
-            "https://explorer.solana.com/address/{}",
-            token_info.mint_address
-        )
-    } else {
-        format!(
-            "https://explorer.solana.com/address/{}?cluster={}",
-            token_info.mint_address, network
-        )
+    let solana_checker = CheckerKind::Solana {
+        network: network.to_string(),
     };
+    let explorer_url = solana_checker.explorer_url(&token_info.mint_address);
     println!("  Explorer:        {}", explorer_url.cyan());
 
+    // Wrapped-asset origin
+    println!();
+    println!("{}", "── Origin ──".bright_white());
+    match &wrapped_info {
+        Ok(Some(info)) => {
+            println!("  Wrapped via:     {}", "Wormhole Token Bridge".cyan());
+            println!("  Origin Chain:    {}", info.origin_chain_name.cyan());
+            println!("  Origin Token:    {}", info.origin_token_address.cyan());
+            println!("  Origin Decimals: {}", info.original_decimals);
+            if info.decimals_truncated {
+                println!(
+                    "  {} Decimals truncated to {} on Solana (Wormhole caps wrapped assets at {})",
+                    "⚠".yellow(),
+                    WORMHOLE_MAX_DECIMALS,
+                    WORMHOLE_MAX_DECIMALS
+                );
+            }
+        }
+        Ok(None) => {
+            println!("  {} Native SPL token (not bridged via Wormhole)", "·".dimmed());
+        }
+        Err(_) => {
+            println!(
+                "  {} Could not resolve wrapped-asset origin",
+                "⚠".yellow()
+            );
+        }
+    }
+
+    // Claimed source-chain verification
+    if let Some(check) = &source_check {
+        println!();
+        println!("{}", "── Source Chain Verification ──".bright_white());
+        println!("  Claimed Chain:   {}", check.claimed_chain.to_string().cyan());
+
+        match check.observed_chain_id {
+            Some(observed) if observed == check.claimed_chain.chain_id() => {
+                println!(
+                    "  {} RPC endpoint reports chain id {} — matches {}",
+                    "✓".green(),
+                    observed,
+                    check.claimed_chain
+                );
+            }
+            Some(observed) => {
+                println!(
+                    "  {} RPC endpoint reports chain id {}, expected {} for {} — is the RPC misconfigured?",
+                    "✗".red(),
+                    observed,
+                    check.claimed_chain.chain_id(),
+                    check.claimed_chain
+                );
+            }
+            None => {
+                println!(
+                    "  {} Could not reach an RPC endpoint for {}",
+                    "⚠".yellow(),
+                    check.claimed_chain
+                );
+            }
+        }
+
+        match (&check.source_token, wrapped_info.as_ref().ok().and_then(|info| info.as_ref())) {
+            (Some(token), Some(wrapped)) => {
+                println!(
+                    "  Source Token:    {} ({})",
+                    token.name.cyan(),
+                    token.symbol.cyan()
+                );
+                let checker = CheckerKind::Evm {
+                    chain: check.claimed_chain,
+                    rpc_url: None,
+                };
+                println!(
+                    "  Source Explorer: {}",
+                    checker.explorer_url(&token.address).cyan()
+                );
+                let claimed_wormhole_id = wormhole_chain_id(check.claimed_chain);
+                if claimed_wormhole_id == wrapped.origin_chain_id {
+                    println!(
+                        "  {} Wrapped-asset origin matches claimed source chain",
+                        "✓".green()
+                    );
+                } else {
+                    println!(
+                        "  {} Wrapped-asset origin is {} (Wormhole id {}), not the claimed {} (Wormhole id {})",
+                        "✗".red(),
+                        wrapped.origin_chain_name,
+                        wrapped.origin_chain_id,
+                        check.claimed_chain,
+                        claimed_wormhole_id
+                    );
+                }
+            }
+            (None, _) => {
+                println!(
+                    "  {} Could not fetch the origin token's metadata on {}",
+                    "⚠".yellow(),
+                    check.claimed_chain
+                );
+            }
+            (Some(_), None) => {
+                println!(
+                    "  {} No wrapped-asset metadata to reconcile against (native SPL token?)",
+                    "⚠".yellow()
+                );
+            }
+        }
+
+        println!();
+        println!("{}", "── Guardian Attestation ──".bright_white());
+        match &check.attestation {
+            Some(attestation) if attestation.quorum_met => {
+                println!(
+                    "  {} Guardian quorum met ({} signatures, guardian set {})",
+                    "✓".green(),
+                    attestation.signatures_present,
+                    attestation
+                        .guardian_set_index
+                        .map(|i| i.to_string())
+                        .unwrap_or_else(|| "unknown".to_string())
+                );
+                if let Some(emitter_chain) = attestation.emitter_chain {
+                    println!(
+                        "  Emitter:         {} (Wormhole id {}), sequence {}",
+                        wormhole_chain_name(emitter_chain),
+                        emitter_chain,
+                        attestation
+                            .sequence
+                            .map(|s| s.to_string())
+                            .unwrap_or_else(|| "unknown".to_string())
+                    );
+                }
+                if let Some(emitter_address) = attestation.emitter_address {
+                    println!(
+                        "  Emitter Address: 0x{}",
+                        emitter_address.iter().map(|b| format!("{b:02x}")).collect::<String>()
+                    );
+                }
+            }
+            Some(attestation) => {
+                println!(
+                    "  {} VAA found but guardian quorum not met ({} signatures, guardian set {})",
+                    "✗".red(),
+                    attestation.signatures_present,
+                    attestation
+                        .guardian_set_index
+                        .map(|i| i.to_string())
+                        .unwrap_or_else(|| "unknown".to_string())
+                );
+            }
+            None => {
+                println!(
+                    "  {} No verifiable VAA found for this token's transfers",
+                    "⚠".yellow()
+                );
+            }
+        }
+
+        println!();
+        println!("{}", "── Supply Reconciliation ──".bright_white());
+        match &check.reconciliation {
+            Some(report) if report.within_tolerance => {
+                println!(
+                    "  {} Locked balance matches minted supply (locked {}, minted {}, drift {})",
+                    "✓".green(),
+                    report.locked,
+                    report.minted,
+                    report.drift
+                );
+            }
+            Some(report) => {
+                println!(
+                    "  {} Supply drift detected — expected {} minted from {} locked, found {} (drift {})",
+                    "✗".red(),
+                    report.expected,
+                    report.locked,
+                    report.minted,
+                    report.drift
+                );
+                if let Some(cause) = &report.likely_cause {
+                    println!("  Likely cause:    {}", cause.cyan());
+                }
+            }
+            None => {
+                println!(
+                    "  {} Could not reconcile — no Token Bridge custody address, or the \
+                     custody balance couldn't be read, for {}",
+                    "⚠".yellow(),
+                    check.claimed_chain
+                );
+            }
+        }
+    }
+
     // NTT health indicators
     println!();
     println!("{}", "── NTT Bridge Health ──".bright_white());
@@ -134,7 +531,7 @@ pub async fn run_status(mint_address: &str, network: &str) -> Result<()> {
     // Wormhole activity
     println!();
     println!("{}", "── Recent Bridge Transfers ──".bright_white());
-    match transfers {
+    match &transfers {
         Ok(ops) => {
             if ops.is_empty() {
                 println!(
@@ -152,14 +549,8 @@ pub async fn run_status(mint_address: &str, network: &str) -> Result<()> {
                     ops.len().to_string().green(),
                     if ops.len() == 1 { "" } else { "s" }
                 );
-                for (i, op) in ops.iter().take(5).enumerate() {
-                    let status = op.status.as_deref().unwrap_or("unknown");
-                    let status_colored = match status {
-                        "completed" => status.green().to_string(),
-                        "pending" => status.yellow().to_string(),
-                        _ => status.dimmed().to_string(),
-                    };
-                    println!("  {}. Status: {}", i + 1, status_colored);
+                for record in decode_transfers(ops, token_info.decimals).iter().take(5) {
+                    println!("  {}", format_transfer_row(record));
                 }
             }
         }
@@ -187,9 +578,193 @@ pub async fn run_status(mint_address: &str, network: &str) -> Result<()> {
     println!("{}", "═".repeat(60).bright_blue());
     println!();
 
+    if watch {
+        watch_transfers(mint_address, token_info.decimals).await;
+    }
+
     Ok(())
 }
 
+/// Interval between WormholeScan polls in `--watch` mode — frequent enough to feel live,
+/// relaxed enough not to hammer a public, unauthenticated API.
+const WATCH_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Re-poll the WormholeScan operations endpoint on `WATCH_POLL_INTERVAL`, diffing against
+/// the previous poll so operators watching a freshly deployed bridge only see what's new:
+/// transfers that weren't there before, and transfers whose state flipped
+/// pending→completed. Runs until the process is interrupted (e.g. Ctrl+C) — there's no
+/// natural end condition for "watch for bridge activity".
+async fn watch_transfers(mint_address: &str, mint_decimals: u8) -> ! {
+    use std::collections::HashMap;
+
+    println!("{}", "── Watching for new transfers (Ctrl+C to stop) ──".bright_white());
+
+    let mut known: HashMap<String, TransferState> = HashMap::new();
+    loop {
+        tokio::time::sleep(WATCH_POLL_INTERVAL).await;
+
+        let pb = spinner("Polling WormholeScan...");
+        let ops = get_wormhole_activity(mint_address).await;
+        pb.finish_and_clear();
+
+        let ops = match ops {
+            Ok(ops) => ops,
+            Err(_) => {
+                println!("  {} Could not reach WormholeScan API", "⚠".yellow());
+                continue;
+            }
+        };
+
+        for record in decode_transfers(&ops, mint_decimals) {
+            match known.get(&record.id) {
+                None => {
+                    println!("  {} New transfer: {}", "+".green(), format_transfer_row(&record));
+                }
+                Some(prev_state) if *prev_state != record.state => {
+                    println!(
+                        "  {} {} → {}: {}",
+                        "↻".cyan(),
+                        prev_state,
+                        record.state,
+                        format_transfer_row(&record)
+                    );
+                }
+                _ => {}
+            }
+            known.insert(record.id.clone(), record.state);
+        }
+    }
+}
+
+/// Resolve the Wormhole Token Bridge program for this network.
+fn token_bridge_program_id(network: &str) -> Result<Pubkey> {
+    let raw = match network {
+        "mainnet" => TOKEN_BRIDGE_PROGRAM_MAINNET,
+        _ => TOKEN_BRIDGE_PROGRAM_DEVNET,
+    };
+    Pubkey::from_str(raw).context("Invalid Token Bridge program ID")
+}
+
+/// Look up the Token Bridge "wrapped meta" PDA for `mint_address` and, if present, decode
+/// its origin-chain metadata. A missing account means `mint_address` is a native SPL
+/// token rather than one minted by the Token Bridge, which is the common case and not an
+/// error.
+///
+/// The account has no Anchor discriminator — it's a raw Borsh-free layout of
+/// `(u16 chain, [u8; 32] token_address, u8 original_decimals)` — so this parses the bytes
+/// by hand, matching how `analyzers::wormhole` already parses other Token Bridge wire
+/// formats (VAAs, attestations) without pulling in a Borsh dependency.
+fn get_wrapped_asset_info(
+    mint_address: &str,
+    network: &str,
+    mint_decimals: u8,
+) -> Result<Option<WrappedAssetInfo>> {
+    let url = match network {
+        "mainnet" => "https://api.mainnet-beta.solana.com",
+        _ => "https://api.devnet.solana.com",
+    };
+    let client = RpcClient::new_with_commitment(url, CommitmentConfig::confirmed());
+    let mint = Pubkey::from_str(mint_address).context("Invalid Solana mint address")?;
+
+    let program_id = token_bridge_program_id(network)?;
+    let (pda, _bump) = Pubkey::find_program_address(&[b"meta", mint.as_ref()], &program_id);
+
+    let account = match client.get_account(&pda) {
+        Ok(account) => account,
+        Err(_) => return Ok(None),
+    };
+
+    const LAYOUT_LEN: usize = 2 + 32 + 1;
+    if account.data.len() < LAYOUT_LEN {
+        anyhow::bail!("wrapped meta account is shorter than the expected layout");
+    }
+
+    let chain_id = u16::from_le_bytes([account.data[0], account.data[1]]);
+    let token_address = &account.data[2..34];
+    let original_decimals = account.data[34];
+
+    Ok(Some(WrappedAssetInfo {
+        origin_chain_name: wormhole_chain_name(chain_id),
+        origin_chain_id: chain_id,
+        origin_token_address: format!(
+            "0x{}",
+            token_address.iter().map(|b| format!("{b:02x}")).collect::<String>()
+        ),
+        original_decimals,
+        decimals_truncated: original_decimals > mint_decimals
+            && mint_decimals == WORMHOLE_MAX_DECIMALS,
+    }))
+}
+
+/// Cross-check a user's claimed EVM source chain against on-chain reality: does the RPC
+/// endpoint we'd use for that chain actually report its expected EIP-155 id (catching a
+/// misconfigured endpoint before it silently feeds back wrong metadata), and can we read
+/// the origin token's own `name()/symbol()/decimals()/totalSupply()` there. `minted` is
+/// the Solana-side mint supply already fetched by the caller, needed to reconcile it
+/// against the source chain's actual locked balance below.
+async fn check_source_chain(
+    claimed_chain: Chain,
+    origin_token_address: Option<&str>,
+    minted: u128,
+) -> SourceChainCheck {
+    let evm = EvmAnalyzer::new(claimed_chain, None);
+    let observed_chain_id = evm.rpc().get_chain_id().await.ok();
+    let bridge_detector = BridgeDetector::new();
+
+    let checker = CheckerKind::Evm {
+        chain: claimed_chain,
+        rpc_url: None,
+    };
+    let source_token = match origin_token_address {
+        Some(address) => checker.token_info(address).await.ok(),
+        None => None,
+    };
+
+    // Fetch and cryptographically verify the guardian VAA for this token's most recent
+    // Wormhole transfer, rather than trusting WormholeScan's mere presence of an
+    // "operation" — see `BridgeDetector::check_wormhole_api` for the guardian-signature
+    // recovery and quorum check itself.
+    let attestation = match origin_token_address {
+        Some(address) => bridge_detector
+            .check_wormhole_api(address, claimed_chain)
+            .await
+            .ok(),
+        None => None,
+    };
+
+    // Reconcile the source chain's actual locked balance (read live from the Token
+    // Bridge's own custody contract, not just its reported `totalSupply`) against the
+    // Solana mint's supply. Needs the source token's own compatibility result to
+    // attribute an out-of-tolerance drift to a known desync-capable issue, so this runs
+    // `analyze_full` rather than just the bare token-info lookup above.
+    let reconciliation = match (origin_token_address, token_bridge_custody_address(claimed_chain)) {
+        (Some(address), Some(custody_address)) => {
+            let locked = evm.rpc().balance_of(address, custody_address).await.ok();
+            let analysis = analyze_full(&evm, &bridge_detector, address, claimed_chain)
+                .await
+                .ok();
+            match (locked, analysis) {
+                (Some(locked), Some(analysis)) => Some(SupplyReconciler::reconcile(
+                    &analysis.compatibility,
+                    analysis.token.decimals,
+                    locked,
+                    minted,
+                )),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+
+    SourceChainCheck {
+        claimed_chain,
+        observed_chain_id,
+        source_token,
+        attestation,
+        reconciliation,
+    }
+}
+
 /// Fetch SPL token mint info from Solana RPC
 fn get_spl_token_info(mint_address: &str, network: &str) -> Result<SplTokenInfo> {
     let url = match network {
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Project-level deploy defaults, checked into a repo as `daybreak.toml` so a team's NTT
+/// manager address, payer keypair, and Sunrise listing fields don't have to be re-typed
+/// as CLI flags on every run. Every field is optional — `config::resolve`/`resolve_opt`
+/// (below) pick the first of CLI flag, config file, environment variable, or built-in
+/// default that's actually set, in that order.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct DaybreakConfig {
+    /// NTT manager address to transfer mint authority to — see `Commands::Deploy`'s
+    /// `--transfer-authority`
+    #[serde(default)]
+    pub ntt_manager_address: Option<String>,
+    /// Path to the Solana payer keypair — see `Commands::Deploy`'s `--keypair`
+    #[serde(default)]
+    pub payer_keypair: Option<String>,
+    /// Preferred RPC endpoint per chain name (e.g. `ethereum`, `bsc`), consulted before
+    /// `ChainRegistry`'s bundled defaults
+    #[serde(default)]
+    pub rpc_urls: HashMap<String, String>,
+    /// Off-chain metadata JSON URI for the deployed SPL token (name/symbol/image) —
+    /// see `SolanaDeployer::create_spl_token`'s `metadata_uri` parameter
+    #[serde(default)]
+    pub token_metadata_uri: Option<String>,
+    /// Token logo image URI, embedded in the metadata JSON pointed to by
+    /// `token_metadata_uri`
+    #[serde(default)]
+    pub token_logo_uri: Option<String>,
+    #[serde(default)]
+    pub sunrise: SunriseConfig,
+}
+
+/// Sunrise listing application fields — see `https://www.sunrise.wtf`
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SunriseConfig {
+    #[serde(default)]
+    pub project_name: Option<String>,
+    #[serde(default)]
+    pub contact_email: Option<String>,
+    #[serde(default)]
+    pub website: Option<String>,
+}
+
+/// Default location for the project config, relative to the current working directory —
+/// meant to be checked into the repo being migrated, same spirit as `Cargo.toml`
+const CONFIG_PATH: &str = "daybreak.toml";
+
+impl DaybreakConfig {
+    /// Load `daybreak.toml` from the current directory. A missing or malformed file
+    /// silently falls back to an empty config — same "never block on a missing override"
+    /// posture as `ChainRegistry::load`.
+    pub fn load() -> Self {
+        std::fs::read_to_string(CONFIG_PATH)
+            .ok()
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// Resolve the preferred RPC endpoint for a chain name (case-insensitive)
+    pub fn rpc_url(&self, chain_name: &str) -> Option<&str> {
+        self.rpc_urls
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(chain_name))
+            .map(|(_, url)| url.as_str())
+    }
+}
+
+/// Resolve a required setting in the order the config subsystem promises: an explicit
+/// CLI flag, then the project config file, then an environment variable, then a
+/// built-in default.
+pub fn resolve(cli: Option<&str>, config: Option<&str>, env_var: &str, default: &str) -> String {
+    resolve_opt(cli, config, env_var).unwrap_or_else(|| default.to_string())
+}
+
+/// Same order as `resolve`, but for settings with no sensible built-in default (e.g. an
+/// NTT manager address) — `None` means none of the four sources had a value.
+pub fn resolve_opt(cli: Option<&str>, config: Option<&str>, env_var: &str) -> Option<String> {
+    cli.map(str::to_string)
+        .or_else(|| config.map(str::to_string))
+        .or_else(|| std::env::var(env_var).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_prefers_cli_over_everything() {
+        let resolved = resolve(Some("from-cli"), Some("from-config"), "DAYBREAK_TEST_VAR_1", "default");
+        assert_eq!(resolved, "from-cli");
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_config_then_default() {
+        let resolved = resolve(None, Some("from-config"), "DAYBREAK_TEST_VAR_2", "default");
+        assert_eq!(resolved, "from-config");
+
+        let resolved = resolve(None, None, "DAYBREAK_TEST_VAR_3", "default");
+        assert_eq!(resolved, "default");
+    }
+
+    #[test]
+    fn test_resolve_opt_returns_none_when_unset() {
+        assert_eq!(resolve_opt(None, None, "DAYBREAK_TEST_VAR_4"), None);
+    }
+
+    #[test]
+    fn test_missing_config_file_falls_back_to_default() {
+        // `load()` always checks ./daybreak.toml; in this sandboxed test environment it
+        // won't exist, so this just exercises the fallback path.
+        let config = DaybreakConfig::load();
+        assert!(config.ntt_manager_address.is_none());
+    }
+}
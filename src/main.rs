@@ -1,6 +1,8 @@
 mod analyzers;
 mod cli;
 mod commands;
+mod config;
+mod migration_state;
 mod output;
 mod report;
 mod scoring;
@@ -20,7 +22,10 @@ async fn main() -> Result<()> {
             address,
             chain,
             skip_holders,
+            holder_source,
             json,
+            stats,
+            emit_schema,
         } => {
             commands::run_scan(
                 &address,
@@ -28,7 +33,10 @@ async fn main() -> Result<()> {
                 cli.rpc_url,
                 cli.etherscan_key,
                 skip_holders,
+                holder_source.as_str(),
                 json,
+                stats,
+                emit_schema,
             )
             .await?;
         }
@@ -37,6 +45,7 @@ async fn main() -> Result<()> {
             chain,
             output,
             skip_holders,
+            holder_source,
         } => {
             commands::run_report(
                 &address,
@@ -45,23 +54,66 @@ async fn main() -> Result<()> {
                 cli.etherscan_key,
                 &output,
                 skip_holders,
+                holder_source.as_str(),
             )
             .await?;
         }
         Commands::Compare {
             address,
             chain,
+            format,
+            baseline,
+            save_snapshot,
+            emit_schema,
+        } => {
+            commands::run_compare(
+                &address,
+                &chain,
+                cli.rpc_url,
+                format,
+                baseline,
+                save_snapshot,
+                emit_schema,
+            )
+            .await?;
+        }
+        Commands::BatchCompare {
+            input,
+            concurrency,
             json,
         } => {
-            commands::run_compare(&address, &chain, cli.rpc_url, json).await?;
+            commands::run_batch_compare(input, cli.rpc_url, concurrency, json).await?;
         }
         Commands::Deploy {
             address,
             chain,
             network,
             keypair,
+            transfer_authority,
+            auto_ntt,
+            airdrop,
+            mint_supply,
+            revoke_mint_authority,
+            metadata_uri,
+            token_2022,
+            transfer_fee_bps,
         } => {
-            commands::run_deploy(&address, &chain, cli.rpc_url, &network, &keypair).await?;
+            commands::run_deploy(
+                &address,
+                &chain,
+                cli.rpc_url,
+                &network,
+                keypair.as_deref(),
+                transfer_authority.as_deref(),
+                auto_ntt,
+                airdrop,
+                mint_supply,
+                revoke_mint_authority,
+                metadata_uri.as_deref(),
+                token_2022,
+                transfer_fee_bps,
+            )
+            .await?;
         }
         Commands::List { chain, limit, json } => {
             commands::run_list(&chain, cli.rpc_url, limit, json).await?;
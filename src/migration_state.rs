@@ -0,0 +1,140 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Default on-disk location for an in-flight migration's resume state
+const STATE_FILE: &str = ".daybreak-migration.json";
+
+/// Which NTT CLI orchestration phases have completed for a migration. Each flag is set
+/// (and the state saved) immediately after its `ntt` command succeeds, so a crash mid-run
+/// resumes at the first phase that's still `false` instead of re-running completed ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NttPhases {
+    pub init: bool,
+    pub add_chain_source: bool,
+    pub add_chain_solana: bool,
+    pub push: bool,
+}
+
+/// Persisted progress for an in-flight `migrate` run. Written to disk after the SPL mint
+/// is created and after each completed NTT phase, so `run_migrate` can detect a matching
+/// state file on startup and resume instead of deploying a second mint for the same token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationState {
+    pub source_address: String,
+    pub network: String,
+    pub mint_address: String,
+    pub decimals: u8,
+    pub ntt_phases: NttPhases,
+}
+
+impl MigrationState {
+    /// Start tracking a fresh migration, right after the SPL mint has been created
+    pub fn new(source_address: &str, network: &str, mint_address: &str, decimals: u8) -> Self {
+        Self {
+            source_address: source_address.to_lowercase(),
+            network: network.to_string(),
+            mint_address: mint_address.to_string(),
+            decimals,
+            ntt_phases: NttPhases::default(),
+        }
+    }
+
+    fn default_path() -> PathBuf {
+        PathBuf::from(STATE_FILE)
+    }
+
+    /// Load the state file, returned only if it was recorded for this same token + network
+    pub fn load_matching(source_address: &str, network: &str) -> Option<Self> {
+        Self::load_matching_at(&Self::default_path(), source_address, network)
+    }
+
+    fn load_matching_at(path: &Path, source_address: &str, network: &str) -> Option<Self> {
+        let contents = std::fs::read_to_string(path).ok()?;
+        let state: Self = serde_json::from_str(&contents).ok()?;
+        if state.source_address.eq_ignore_ascii_case(source_address) && state.network == network {
+            Some(state)
+        } else {
+            None
+        }
+    }
+
+    /// Persist the current progress to disk
+    pub fn save(&self) -> Result<()> {
+        self.save_at(&Self::default_path())
+    }
+
+    fn save_at(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Discard any existing state file, e.g. for `--restart` or once a migration finishes
+    pub fn discard() {
+        Self::discard_at(&Self::default_path());
+    }
+
+    fn discard_at(path: &Path) {
+        std::fs::remove_file(path).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// A fresh, per-call state-file path under the system tempdir — explicit and
+    /// injectable (see `*_at` above) rather than relying on process-global cwd, which
+    /// races under a parallel test run. `AtomicU64` guarantees uniqueness even when two
+    /// tests land in the same process and thread pool slot; a thread name (or its length)
+    /// doesn't.
+    fn temp_state_path() -> PathBuf {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        std::env::temp_dir().join(format!(
+            "daybreak-migration-state-test-{}-{}.json",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ))
+    }
+
+    #[test]
+    fn test_load_matching_round_trip() {
+        let path = temp_state_path();
+        let state = MigrationState::new("0xAbC", "devnet", "MintAddr111", 9);
+        state.save_at(&path).unwrap();
+
+        let loaded = MigrationState::load_matching_at(&path, "0xabc", "devnet");
+        assert!(loaded.is_some());
+        assert_eq!(loaded.unwrap().mint_address, "MintAddr111");
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_load_matching_rejects_different_network() {
+        let path = temp_state_path();
+        let state = MigrationState::new("0xabc", "devnet", "MintAddr111", 9);
+        state.save_at(&path).unwrap();
+
+        assert!(MigrationState::load_matching_at(&path, "0xabc", "mainnet").is_none());
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_discard_removes_file() {
+        let path = temp_state_path();
+        let state = MigrationState::new("0xabc", "devnet", "MintAddr111", 9);
+        state.save_at(&path).unwrap();
+        MigrationState::discard_at(&path);
+
+        assert!(MigrationState::load_matching_at(&path, "0xabc", "devnet").is_none());
+    }
+
+    #[test]
+    fn test_load_matching_missing_file() {
+        let path = temp_state_path();
+        assert!(MigrationState::load_matching_at(&path, "0xabc", "devnet").is_none());
+    }
+}
@@ -0,0 +1,220 @@
+use crate::types::{Feasibility, FullAnalysis, IssueSeverity, MigrationPath, RiskRating};
+
+/// Renders a self-contained HTML report (inline CSS, no external assets) — something a
+/// reviewer can attach to a security writeup or share with a team, rather than only
+/// terminal or raw JSON output. Takes the same `&[MigrationPath]` that
+/// `JsonOutput::format_comparison`/`TerminalOutput::print_comparison` consume, plus the
+/// `FullAnalysis` those paths were compared against, so the risk gauge and
+/// capabilities/bytecode/compatibility sections have something to render.
+pub struct HtmlOutput;
+
+impl HtmlOutput {
+    /// Generate the full report: risk gauge, capabilities table, bytecode and
+    /// compatibility sections (collapsed by default), and a path comparison table with
+    /// the recommended path highlighted.
+    pub fn generate(analysis: &FullAnalysis, paths: &[MigrationPath]) -> String {
+        let mut out = String::new();
+
+        out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        out.push_str(&format!(
+            "<title>Migration Report: {} ({})</title>\n",
+            Self::escape(&analysis.token.name),
+            Self::escape(&analysis.token.symbol)
+        ));
+        out.push_str(Self::STYLE);
+        out.push_str("</head>\n<body>\n");
+
+        out.push_str(&format!(
+            "<h1>{} ({})</h1>\n<p class=\"muted\">Source chain: {}</p>\n",
+            Self::escape(&analysis.token.name),
+            Self::escape(&analysis.token.symbol),
+            analysis.token.chain
+        ));
+
+        Self::write_risk_gauge(&mut out, analysis);
+        Self::write_capabilities(&mut out, analysis);
+        Self::write_comparison(&mut out, paths);
+        Self::write_bytecode_details(&mut out, analysis);
+        Self::write_compatibility_details(&mut out, analysis);
+
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    fn write_risk_gauge(out: &mut String, analysis: &FullAnalysis) {
+        let score = &analysis.risk_score;
+        let (class, label) = match score.rating {
+            RiskRating::Low => ("risk-low", "Low Risk"),
+            RiskRating::Medium => ("risk-medium", "Medium Risk"),
+            RiskRating::High => ("risk-high", "High Risk"),
+        };
+
+        out.push_str("<h2>Risk Score</h2>\n");
+        out.push_str(&format!(
+            "<div class=\"gauge {class}\"><div class=\"gauge-fill\" style=\"width: {}%\"></div>\
+             <span class=\"gauge-label\">{}/100 — {}</span></div>\n",
+            score.total, score.total, label
+        ));
+        out.push_str("<table>\n<tr><th>Component</th><th>Score</th></tr>\n");
+        out.push_str(&format!(
+            "<tr><td>Decimal handling</td><td>{}/20</td></tr>\n",
+            score.components.decimal_handling
+        ));
+        out.push_str(&format!(
+            "<tr><td>Token features</td><td>{}/25</td></tr>\n",
+            score.components.token_features
+        ));
+        out.push_str(&format!(
+            "<tr><td>Bytecode complexity</td><td>{}/20</td></tr>\n",
+            score.components.bytecode_complexity
+        ));
+        out.push_str(&format!(
+            "<tr><td>Holder concentration</td><td>{}/15</td></tr>\n",
+            score.components.holder_concentration
+        ));
+        out.push_str(&format!(
+            "<tr><td>Bridge status</td><td>{}/20</td></tr>\n</table>\n",
+            score.components.bridge_status
+        ));
+
+        if score.trimming.dropped_digits > 0 {
+            out.push_str(&format!(
+                "<p class=\"muted\">{}</p>\n",
+                Self::escape(&score.trimming.example)
+            ));
+        }
+    }
+
+    fn write_capabilities(out: &mut String, analysis: &FullAnalysis) {
+        let cap = &analysis.capabilities;
+        out.push_str("<h2>Capabilities</h2>\n<table>\n");
+        out.push_str(&Self::bool_row("Mintable", cap.has_mint));
+        out.push_str(&Self::bool_row("Burnable", cap.has_burn));
+        out.push_str(&Self::bool_row("Pausable", cap.has_pause));
+        out.push_str(&Self::bool_row("Blacklist", cap.has_blacklist));
+        out.push_str(&Self::bool_row("Permit (EIP-2612)", cap.has_permit));
+        out.push_str(&Self::bool_row("Upgradeable", cap.is_upgradeable));
+        out.push_str("</table>\n");
+    }
+
+    fn write_comparison(out: &mut String, paths: &[MigrationPath]) {
+        out.push_str("<h2>Migration Path Comparison</h2>\n<table>\n");
+        out.push_str("<tr><th>Method</th><th>Feasibility</th><th>Cost</th><th>Time</th><th>Pros</th><th>Cons</th></tr>\n");
+
+        for path in paths {
+            let (feasibility_class, recommended_class) = match path.feasibility {
+                Feasibility::Recommended => ("feasibility-recommended", " class=\"recommended\""),
+                Feasibility::Viable => ("feasibility-viable", ""),
+                Feasibility::NotRecommended => ("feasibility-not-recommended", ""),
+            };
+
+            out.push_str(&format!("<tr{}>\n", recommended_class));
+            out.push_str(&format!("<td>{}</td>\n", Self::escape(&path.method.to_string())));
+            out.push_str(&format!(
+                "<td class=\"{}\">{}</td>\n",
+                feasibility_class, path.feasibility
+            ));
+            out.push_str(&format!("<td>{}</td>\n", Self::escape(&path.estimated_cost_usd)));
+            out.push_str(&format!("<td>{}</td>\n", Self::escape(&path.estimated_time)));
+            out.push_str(&format!("<td>{}</td>\n", Self::list(&path.pros)));
+            out.push_str(&format!("<td>{}</td>\n", Self::list(&path.cons)));
+            out.push_str("</tr>\n");
+        }
+
+        out.push_str("</table>\n");
+    }
+
+    fn write_bytecode_details(out: &mut String, analysis: &FullAnalysis) {
+        let bytecode = &analysis.bytecode;
+        out.push_str("<details>\n<summary>Bytecode Analysis</summary>\n<table>\n");
+        out.push_str(&format!(
+            "<tr><td>Size</td><td>{} bytes ({})</td></tr>\n",
+            bytecode.size_bytes, bytecode.complexity
+        ));
+        out.push_str(&Self::bool_row("Is proxy", bytecode.is_proxy));
+        out.push_str(&Self::bool_row("Has selfdestruct", bytecode.has_selfdestruct));
+        out.push_str(&Self::bool_row("Fee-on-transfer pattern", bytecode.has_fee_pattern));
+        out.push_str("</table>\n</details>\n");
+    }
+
+    fn write_compatibility_details(out: &mut String, analysis: &FullAnalysis) {
+        let compat = &analysis.compatibility;
+        out.push_str("<details>\n<summary>NTT Compatibility</summary>\n<table>\n");
+        out.push_str(&format!(
+            "<tr><td>Status</td><td>{}</td></tr>\n",
+            if compat.is_compatible { "Compatible" } else { "Not Compatible" }
+        ));
+        out.push_str(&format!(
+            "<tr><td>Mode</td><td>{}</td></tr>\n",
+            compat.recommended_mode
+        ));
+        out.push_str("</table>\n");
+
+        if !compat.issues.is_empty() {
+            out.push_str("<ul>\n");
+            for issue in &compat.issues {
+                let severity = match issue.severity {
+                    IssueSeverity::Info => "INFO",
+                    IssueSeverity::Warning => "WARN",
+                    IssueSeverity::Error => "ERROR",
+                };
+                out.push_str(&format!(
+                    "<li><strong>[{}]</strong> {}</li>\n",
+                    severity,
+                    Self::escape(&issue.title)
+                ));
+            }
+            out.push_str("</ul>\n");
+        }
+        out.push_str("</details>\n");
+    }
+
+    fn bool_row(label: &str, value: bool) -> String {
+        format!(
+            "<tr><td>{}</td><td>{}</td></tr>\n",
+            label,
+            if value { "Yes" } else { "No" }
+        )
+    }
+
+    fn list(items: &[String]) -> String {
+        if items.is_empty() {
+            return String::new();
+        }
+        let mut out = String::from("<ul>");
+        for item in items {
+            out.push_str(&format!("<li>{}</li>", Self::escape(item)));
+        }
+        out.push_str("</ul>");
+        out
+    }
+
+    fn escape(value: &str) -> String {
+        value
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+
+    const STYLE: &'static str = "<style>\n\
+        body { font-family: -apple-system, Helvetica, Arial, sans-serif; max-width: 960px; margin: 2rem auto; padding: 0 1rem; color: #1f2328; }\n\
+        h1, h2 { border-bottom: 1px solid #d0d7de; padding-bottom: 0.3rem; }\n\
+        table { border-collapse: collapse; width: 100%; margin-bottom: 1rem; }\n\
+        td, th { border: 1px solid #d0d7de; padding: 0.4rem 0.6rem; text-align: left; }\n\
+        th { background: #f6f8fa; }\n\
+        tr.recommended { background: #dafbe1; font-weight: bold; }\n\
+        .muted { color: #59636e; }\n\
+        .feasibility-recommended { color: #1a7f37; }\n\
+        .feasibility-viable { color: #9a6700; }\n\
+        .feasibility-not-recommended { color: #cf222e; }\n\
+        .gauge { position: relative; background: #f6f8fa; border: 1px solid #d0d7de; border-radius: 6px; height: 1.6rem; margin-bottom: 1rem; overflow: hidden; }\n\
+        .gauge-fill { position: absolute; inset: 0; height: 100%; }\n\
+        .risk-low .gauge-fill { background: #4ac26b; }\n\
+        .risk-medium .gauge-fill { background: #d4a72c; }\n\
+        .risk-high .gauge-fill { background: #e5534b; }\n\
+        .gauge-label { position: relative; z-index: 1; display: block; text-align: center; line-height: 1.6rem; font-weight: bold; }\n\
+        details { margin-bottom: 1rem; }\n\
+        summary { cursor: pointer; font-weight: bold; }\n\
+        </style>\n";
+}
@@ -1,4 +1,4 @@
-use crate::types::{FullAnalysis, MigrationPath};
+use crate::types::{FullAnalysis, MigrationPath, NftScanResult};
 use anyhow::Result;
 
 /// Handles JSON output formatting
@@ -10,8 +10,32 @@ impl JsonOutput {
         serde_json::to_string_pretty(analysis).map_err(Into::into)
     }
 
+    /// Output an NFT collection scan as JSON
+    pub fn format_nft_scan(result: &NftScanResult) -> Result<String> {
+        serde_json::to_string_pretty(result).map_err(Into::into)
+    }
+
     /// Output comparison as JSON
     pub fn format_comparison(paths: &[MigrationPath]) -> Result<String> {
         serde_json::to_string_pretty(paths).map_err(Into::into)
     }
+
+    /// Output a batch of analyses as a single JSON array — see `run_batch_compare`
+    pub fn format_batch(analyses: &[FullAnalysis]) -> Result<String> {
+        serde_json::to_string_pretty(analyses).map_err(Into::into)
+    }
+
+    /// JSON Schema for `format_analysis`'s output — lets downstream consumers (CI checks,
+    /// a TypeScript client, a docs generator) validate `scan --json` output without hand
+    /// maintaining a schema alongside the Rust types
+    pub fn schema_for_analysis() -> Result<String> {
+        let schema = schemars::schema_for!(FullAnalysis);
+        serde_json::to_string_pretty(&schema).map_err(Into::into)
+    }
+
+    /// JSON Schema for `format_comparison`'s output — see `schema_for_analysis`
+    pub fn schema_for_comparison() -> Result<String> {
+        let schema = schemars::schema_for!(Vec<MigrationPath>);
+        serde_json::to_string_pretty(&schema).map_err(Into::into)
+    }
 }
@@ -0,0 +1,231 @@
+use crate::report::CostEstimator;
+use crate::types::{FullAnalysis, IssueSeverity, MigrationPath, MigrationPlan, RiskRating};
+
+/// Generates the markdown migration report (report.md)
+pub struct MarkdownGenerator;
+
+impl MarkdownGenerator {
+    /// Generate the full markdown report for a token analysis and its migration plan
+    pub fn generate(analysis: &FullAnalysis, plan: &MigrationPlan) -> String {
+        let mut out = String::new();
+
+        Self::write_header(&mut out, analysis);
+        Self::write_token_info(&mut out, analysis);
+        Self::write_capabilities(&mut out, analysis);
+        Self::write_bytecode(&mut out, analysis);
+        Self::write_compatibility(&mut out, analysis);
+        Self::write_risk_score(&mut out, analysis);
+        Self::write_cost_estimate(&mut out, analysis);
+        Self::write_migration_plan(&mut out, plan);
+
+        out
+    }
+
+    /// Render a bare set of migration paths (the `compare` command's output) as markdown.
+    /// Unlike `generate`, there's no `FullAnalysis`/`MigrationPlan` to report on here — just
+    /// the paths themselves — so this builds its own small document rather than reusing the
+    /// `write_*` helpers above, which all expect a full report's worth of context.
+    pub fn generate_comparison(paths: &[MigrationPath]) -> String {
+        let mut out = String::new();
+
+        out.push_str("# Migration Path Comparison\n\n");
+        for path in paths {
+            out.push_str(&format!(
+                "## {} — {}\n\n",
+                path.method, path.feasibility
+            ));
+            out.push_str(&format!(
+                "- Estimated cost: {}\n- Estimated time: {}\n\n",
+                path.estimated_cost_usd, path.estimated_time
+            ));
+
+            if !path.pros.is_empty() {
+                out.push_str("**Pros:**\n\n");
+                for pro in &path.pros {
+                    out.push_str(&format!("- {}\n", pro));
+                }
+                out.push('\n');
+            }
+
+            if !path.cons.is_empty() {
+                out.push_str("**Cons:**\n\n");
+                for con in &path.cons {
+                    out.push_str(&format!("- {}\n", con));
+                }
+                out.push('\n');
+            }
+        }
+
+        out
+    }
+
+    fn write_header(out: &mut String, analysis: &FullAnalysis) {
+        out.push_str(&format!(
+            "# Migration Report: {} ({})\n\n",
+            analysis.token.name, analysis.token.symbol
+        ));
+        out.push_str(&format!("Source chain: **{}**\n\n", analysis.token.chain));
+    }
+
+    fn write_token_info(out: &mut String, analysis: &FullAnalysis) {
+        out.push_str("## Token Information\n\n");
+        out.push_str(&format!("- Address: `{}`\n", analysis.token.address));
+        out.push_str(&format!("- Decimals: {}\n", analysis.token.decimals));
+        out.push_str(&format!(
+            "- Total Supply: {}\n\n",
+            analysis.token.total_supply
+        ));
+    }
+
+    fn write_capabilities(out: &mut String, analysis: &FullAnalysis) {
+        let cap = &analysis.capabilities;
+        out.push_str("## Capabilities\n\n");
+        out.push_str(&format!("- Mintable: {}\n", Self::yes_no(cap.has_mint)));
+        out.push_str(&format!("- Burnable: {}\n", Self::yes_no(cap.has_burn)));
+        out.push_str(&format!("- Pausable: {}\n", Self::yes_no(cap.has_pause)));
+        out.push_str(&format!(
+            "- Blacklist: {}\n",
+            Self::yes_no(cap.has_blacklist)
+        ));
+        out.push_str(&format!(
+            "- Permit (EIP-2612): {}\n",
+            Self::yes_no(cap.has_permit)
+        ));
+        out.push_str(&format!(
+            "- Upgradeable: {}\n\n",
+            Self::yes_no(cap.is_upgradeable)
+        ));
+    }
+
+    fn write_bytecode(out: &mut String, analysis: &FullAnalysis) {
+        let bytecode = &analysis.bytecode;
+        out.push_str("## Bytecode Analysis\n\n");
+        out.push_str(&format!(
+            "- Size: {} bytes ({})\n",
+            bytecode.size_bytes, bytecode.complexity
+        ));
+        out.push_str(&format!("- Is Proxy: {}\n", Self::yes_no(bytecode.is_proxy)));
+        if let Some(ref impl_addr) = bytecode.implementation_address {
+            out.push_str(&format!("- Implementation: `{}`\n", impl_addr));
+        }
+        if bytecode.has_selfdestruct {
+            out.push_str("- ⚠ Has selfdestruct\n");
+        }
+        if bytecode.has_fee_pattern {
+            out.push_str("- ⚠ Fee-on-transfer pattern detected\n");
+        }
+        out.push('\n');
+    }
+
+    fn write_compatibility(out: &mut String, analysis: &FullAnalysis) {
+        let compat = &analysis.compatibility;
+        out.push_str("## NTT Compatibility\n\n");
+        out.push_str(&format!(
+            "- Status: {}\n",
+            if compat.is_compatible {
+                "Compatible"
+            } else {
+                "Not Compatible"
+            }
+        ));
+        out.push_str(&format!("- Mode: {}\n", compat.recommended_mode));
+        if compat.decimal_trimming_required {
+            out.push_str(&format!(
+                "- Decimals: {} → {} (trimming required)\n",
+                analysis.token.decimals, compat.solana_decimals
+            ));
+        }
+
+        if !compat.issues.is_empty() {
+            out.push_str("\n### Issues\n\n");
+            for issue in &compat.issues {
+                let severity = match issue.severity {
+                    IssueSeverity::Info => "INFO",
+                    IssueSeverity::Warning => "WARN",
+                    IssueSeverity::Error => "ERROR",
+                };
+                out.push_str(&format!("- **[{}]** {}\n", severity, issue.title));
+            }
+        }
+        out.push('\n');
+    }
+
+    fn write_risk_score(out: &mut String, analysis: &FullAnalysis) {
+        let score = &analysis.risk_score;
+        let rating = match score.rating {
+            RiskRating::Low => "Low Risk",
+            RiskRating::Medium => "Medium Risk",
+            RiskRating::High => "High Risk",
+        };
+        out.push_str("## Risk Score\n\n");
+        out.push_str(&format!("**{}/100 ({})**\n\n", score.total, rating));
+        out.push_str(&format!(
+            "- Decimal handling: {}/20\n",
+            score.components.decimal_handling
+        ));
+        out.push_str(&format!(
+            "- Token features: {}/25\n",
+            score.components.token_features
+        ));
+        out.push_str(&format!(
+            "- Bytecode complexity: {}/20\n",
+            score.components.bytecode_complexity
+        ));
+        out.push_str(&format!(
+            "- Holder concentration: {}/15\n",
+            score.components.holder_concentration
+        ));
+        out.push_str(&format!(
+            "- Bridge status: {}/20\n\n",
+            score.components.bridge_status
+        ));
+
+        let trimming = &score.trimming;
+        if trimming.dropped_digits > 0 {
+            out.push_str(&format!(
+                "**Decimal trimming:** {} → {} decimals ({} digit{} dropped, smallest \
+                 bridgeable unit {} base units)\n\n",
+                trimming.source_decimals,
+                trimming.normalized_decimals,
+                trimming.dropped_digits,
+                if trimming.dropped_digits == 1 { "" } else { "s" },
+                trimming.smallest_bridgeable_unit,
+            ));
+            out.push_str(&format!("{}\n\n", trimming.example));
+        }
+    }
+
+    fn write_cost_estimate(out: &mut String, analysis: &FullAnalysis) {
+        let Some(cost) = &analysis.migration_cost else {
+            return;
+        };
+        out.push_str("## Migration Cost Estimate\n\n");
+        out.push_str("```\n");
+        out.push_str(&CostEstimator::format_costs(cost));
+        out.push_str("\n```\n\n");
+    }
+
+    fn write_migration_plan(out: &mut String, plan: &MigrationPlan) {
+        out.push_str("## Migration Plan\n\n");
+        out.push_str(&format!(
+            "Recommended path: **{}**\n\n",
+            plan.recommended_path
+        ));
+
+        for step in &plan.steps {
+            out.push_str(&format!("{}. **{}** — {}\n", step.order, step.title, step.description));
+            if let Some(ref command) = step.command {
+                out.push_str(&format!("   ```\n   {}\n   ```\n", command));
+            }
+        }
+        out.push('\n');
+    }
+
+    fn yes_no(value: bool) -> &'static str {
+        if value {
+            "Yes"
+        } else {
+            "No"
+        }
+    }
+}
@@ -1,7 +1,9 @@
 pub mod terminal;
 pub mod markdown;
 pub mod json;
+pub mod html;
 
 pub use terminal::TerminalOutput;
 pub use markdown::MarkdownGenerator;
 pub use json::JsonOutput;
+pub use html::HtmlOutput;
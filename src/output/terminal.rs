@@ -1,5 +1,7 @@
+use crate::analyzers::RpcStats;
 use crate::types::{
-    CompatibilityResult, Feasibility, FullAnalysis, IssueSeverity, MigrationPath, RiskRating,
+    CompatibilityResult, Feasibility, FullAnalysis, IssueSeverity, MigrationPath, NftScanResult,
+    RiskRating,
 };
 use colored::Colorize;
 
@@ -62,6 +64,14 @@ impl TerminalOutput {
             if let Some(ref provider) = analysis.bridge_status.bridge_provider {
                 println!("  Bridge: {}", provider);
             }
+            if analysis.bridge_status.bridge_verified {
+                println!("  {} Guardian quorum verified", "✓".green());
+            } else {
+                println!(
+                    "  {} UNVERIFIED — guardian quorum not confirmed, could be a look-alike",
+                    "!".red().bold()
+                );
+            }
         } else {
             println!("  {} No existing Solana presence", "✓".green());
         }
@@ -86,6 +96,55 @@ impl TerminalOutput {
         println!();
     }
 
+    /// Print an ERC-721/1155 collection scan
+    pub fn print_nft_scan(result: &NftScanResult) {
+        let collection = &result.collection;
+        Self::print_header(&format!(
+            "{} ({}) on {}",
+            collection.name, collection.symbol, collection.chain
+        ));
+
+        Self::print_section("Collection Information");
+        println!("  Address:      {}", collection.address.cyan());
+        match collection.total_supply {
+            Some(supply) => println!("  Total Supply: {}", supply),
+            None => println!(
+                "  Total Supply: {}",
+                "unknown (collection isn't ERC721Enumerable)".dimmed()
+            ),
+        }
+        match &collection.base_uri {
+            Some(uri) => println!("  Base URI:     {}", uri),
+            None => println!("  Base URI:     {}", "none found".dimmed()),
+        }
+
+        Self::print_section("NFT Bridge Compatibility");
+        let status = if result.compatibility.is_compatible {
+            "Compatible".green().bold()
+        } else {
+            "Not Compatible".red().bold()
+        };
+        println!("  Status: {}", status);
+
+        if !result.compatibility.issues.is_empty() {
+            println!();
+            println!("  Issues:");
+            for issue in &result.compatibility.issues {
+                let severity = match issue.severity {
+                    IssueSeverity::Info => "[INFO]".dimmed(),
+                    IssueSeverity::Warning => "[WARN]".yellow(),
+                    IssueSeverity::Error => "[ERROR]".red(),
+                };
+                println!("    {} {}", severity, issue.title);
+            }
+        }
+
+        Self::print_section("Migration Path");
+        Self::print_path(&result.migration_path);
+
+        println!();
+    }
+
     /// Print comparison table
     pub fn print_comparison(paths: &[MigrationPath]) {
         Self::print_header("Migration Path Comparison");
@@ -96,6 +155,54 @@ impl TerminalOutput {
         }
     }
 
+    /// Print a line-oriented diff between a baseline and current snapshot (both the
+    /// canonical pretty-printed JSON `SnapshotDiffer::render` produces), coloring removed
+    /// lines red and added lines green. Unchanged lines print uncolored so the output
+    /// stays readable for a token with only a handful of changed fields.
+    pub fn print_diff(baseline: &str, current: &str) {
+        Self::print_header("Snapshot Diff");
+
+        for line in diff::lines(baseline, current) {
+            match line {
+                diff::Result::Left(l) => println!("{}", format!("- {l}").red()),
+                diff::Result::Right(r) => println!("{}", format!("+ {r}").green()),
+                diff::Result::Both(l, _) => println!("  {l}"),
+            }
+        }
+    }
+
+    /// Print opt-in RPC latency/retry instrumentation for `--stats`
+    pub fn print_rpc_stats(stats: &RpcStats) {
+        Self::print_section("RPC Stats");
+        println!("  Total requests: {}", stats.total_requests);
+        println!("  Total retries:  {}", stats.total_retries);
+        if let Some(endpoint) = &stats.last_endpoint {
+            println!("  Last endpoint:  {}", endpoint.cyan());
+        }
+
+        if stats.methods.is_empty() {
+            return;
+        }
+
+        println!();
+        println!(
+            "  {:<20} {:>7} {:>8} {:>8} {:>8} {:>8} {:>8}",
+            "Method", "Count", "Min(ms)", "Avg(ms)", "p50", "p90", "p99"
+        );
+        for method in &stats.methods {
+            println!(
+                "  {:<20} {:>7} {:>8} {:>8} {:>8} {:>8} {:>8}",
+                method.method,
+                method.count,
+                method.min_ms,
+                method.avg_ms,
+                method.p50_ms,
+                method.p90_ms,
+                method.p99_ms
+            );
+        }
+    }
+
     fn print_header(title: &str) {
         println!();
         println!("{}", "═".repeat(60).bright_blue());
@@ -200,6 +307,21 @@ impl TerminalOutput {
             "    Bridge status:        {}/20",
             score.components.bridge_status
         );
+
+        let trimming = &score.trimming;
+        if trimming.dropped_digits > 0 {
+            println!();
+            println!(
+                "  {} NTT normalizes transfers to {} decimals ({} → {}): smallest bridgeable \
+                 unit is {} base units.",
+                "ℹ".cyan(),
+                trimming.normalized_decimals,
+                trimming.source_decimals,
+                trimming.normalized_decimals,
+                trimming.smallest_bridgeable_unit,
+            );
+            println!("    {}", trimming.example.dimmed());
+        }
     }
 
     fn print_verdict(analysis: &FullAnalysis) {
@@ -277,6 +399,16 @@ impl TerminalOutput {
                 if let Some(total) = data.total_holders {
                     println!("  Total holders:       {}", total);
                 }
+                if let Some((from_block, to_block)) = data.scanned_window {
+                    println!(
+                        "  {}",
+                        format!(
+                            "Reconstructed from Transfer logs, blocks {}-{} (not full history)",
+                            from_block, to_block
+                        )
+                        .dimmed()
+                    );
+                }
 
                 // Show top-5 holders
                 if !data.top_holders.is_empty() {
@@ -312,7 +444,7 @@ impl TerminalOutput {
             None => {
                 println!(
                     "  {}",
-                    "Unavailable (requires Etherscan API key via --etherscan-key)".dimmed()
+                    "Unavailable (use --etherscan-key or --holder-source logscan)".dimmed()
                 );
             }
         }
@@ -1,4 +1,7 @@
-use crate::types::{Feasibility, FullAnalysis, MigrationMethod, MigrationPath};
+use crate::types::{
+    Feasibility, FullAnalysis, IssueSeverity, MigrationMethod, MigrationPath,
+    NftCompatibilityResult, ProxyType,
+};
 
 /// Compares different migration paths
 pub struct PathComparator;
@@ -13,6 +16,45 @@ impl PathComparator {
         ]
     }
 
+    /// Evaluate the (sole, for now) migration path for an ERC-721/1155 collection. NFTs
+    /// don't go through `compare`/`FullAnalysis` — there's no decimals/bytecode/holder
+    /// analysis to reuse — so this takes the NFT-specific compatibility result directly.
+    pub fn evaluate_nft_bridge(compat: &NftCompatibilityResult) -> MigrationPath {
+        let mut feasibility = Feasibility::Recommended;
+        let mut cons = vec![
+            "Wrapped per-token, not as a collection — each token id gets its own wrapped \
+                mint the first time it's bridged"
+                .to_string(),
+            "Metaplex metadata (name/symbol/image) must be re-attached after bridging; \
+                the NFT bridge only carries the on-chain token URI"
+                .to_string(),
+        ];
+
+        for issue in &compat.issues {
+            match issue.severity {
+                IssueSeverity::Error => feasibility = Feasibility::NotRecommended,
+                _ if feasibility == Feasibility::Recommended => {
+                    feasibility = Feasibility::Viable;
+                }
+                _ => {}
+            }
+            cons.push(issue.title.clone());
+        }
+
+        MigrationPath {
+            method: MigrationMethod::NftBridge,
+            feasibility,
+            estimated_cost_usd: "$20-100 per token".to_string(),
+            estimated_time: "hours (first transfer creates the wrapped mint)".to_string(),
+            pros: vec![
+                "No custom program needed — uses the existing Wormhole NFT bridge".to_string(),
+                "Preserves per-token provenance via the original contract + token id"
+                    .to_string(),
+            ],
+            cons,
+        }
+    }
+
     fn evaluate_ntt(analysis: &FullAnalysis) -> MigrationPath {
         let mut feasibility = Feasibility::Recommended;
         let mut cons = Vec::new();
@@ -35,6 +77,17 @@ impl PathComparator {
             ));
         }
 
+        if analysis.capabilities.has_burn
+            && !analysis
+                .access_control
+                .mint_authority_controllable(&analysis.capabilities)
+        {
+            cons.push(
+                "Mint authority cannot be reassigned (no owner/role admin found) — falling back to locking mode"
+                    .to_string(),
+            );
+        }
+
         if analysis.bridge_status.already_on_solana {
             cons.push("Token already exists on Solana, coordination needed".to_string());
             if feasibility == Feasibility::Recommended {
@@ -42,6 +95,25 @@ impl PathComparator {
             }
         }
 
+        // A mutable implementation can change token behavior (or mint/blacklist logic)
+        // after NTT's lock/mint authority is granted, undermining the guarantees NTT is
+        // meant to provide. A minimal proxy (EIP-1167 clone) has an immutable
+        // implementation, so it only costs a note rather than a downgrade.
+        if analysis.bytecode.is_proxy {
+            cons.push(
+                "Upgradeable proxy — implementation can change token behavior after lock/mint authority is granted"
+                    .to_string(),
+            );
+            match analysis.bytecode.proxy_type {
+                Some(ProxyType::MinimalProxy) => {
+                    if feasibility == Feasibility::Recommended {
+                        feasibility = Feasibility::Viable;
+                    }
+                }
+                _ => feasibility = Feasibility::NotRecommended,
+            }
+        }
+
         MigrationPath {
             method: MigrationMethod::NttSunrise,
             feasibility,
@@ -1,4 +1,12 @@
-use crate::types::FullAnalysis;
+use crate::analyzers::discovery::TokenDiscovery;
+use crate::analyzers::evm::rpc::FeeHistory;
+use crate::analyzers::evm::EvmRpcClient;
+use crate::analyzers::volume::VolumeAnalyzer;
+use crate::types::{Chain, FullAnalysis};
+use anyhow::{Context, Result};
+use reqwest::Client;
+use serde::Deserialize;
+use solana_client::rpc_client::RpcClient;
 
 /// Estimates migration and operational costs
 pub struct CostEstimator;
@@ -9,34 +17,264 @@ pub struct CostEstimate {
     pub deployment_cost_sol: f64,
     pub per_transfer_cost_usd: f64,
     pub monthly_operational_usd: f64,
+    pub gas_estimate: Option<GasFeeEstimate>,
+    pub solana_priority_fee: Option<SolanaPriorityFeeEstimate>,
+}
+
+/// Live Solana compute-budget price derived from `getRecentPrioritizationFees`, and what
+/// it implies for the redeem-side transaction cost
+#[derive(Debug, Clone)]
+pub struct SolanaPriorityFeeEstimate {
+    /// Compute units budgeted for the redeem transaction (NTT burning-mode mint)
+    pub compute_units: u64,
+    /// Observed micro-lamport priority fee at `percentile` across recent blocks
+    pub priority_fee_micro_lamports: u64,
+    /// Percentile sampled from the recent-fees distribution (50.0 = median, 90.0 = p90)
+    pub percentile: f64,
+}
+
+/// Live EIP-1559 gas price derived from `eth_feeHistory`, and what it implies for the
+/// EVM-side NTT deployment steps
+#[derive(Debug, Clone)]
+pub struct GasFeeEstimate {
+    pub base_fee_gwei: f64,
+    pub priority_fee_gwei: f64,
+    pub deployment_cost_native: f64,
+    pub deployment_cost_usd: f64,
+}
+
+/// Rough aggregate gas for the EVM-side NTT deployment steps: manager + transceiver
+/// deploy, token registration, and initial rate-limit configuration
+const NTT_DEPLOYMENT_GAS: f64 = 2_000_000.0;
+
+/// Number of trailing blocks to sample for the base-fee/priority-fee projection
+const FEE_HISTORY_BLOCK_COUNT: u64 = 20;
+
+/// Priority-fee percentile to sample (roughly "will land within a couple blocks")
+const PRIORITY_FEE_PERCENTILE: f64 = 50.0;
+
+/// Solana base signature fee, fixed by the protocol
+const BASE_SIGNATURE_FEE_LAMPORTS: u64 = 5_000;
+
+/// Compute units budgeted for an NTT redeem (burning mode mint) when the caller doesn't
+/// override it — covers VAA verification plus the mint CPI
+const DEFAULT_NTT_REDEEM_COMPUTE_UNITS: u64 = 300_000;
+
+/// Intrinsic gas for the CREATE opcode (contract creation)
+const CREATE_BASE_GAS: u64 = 32_000;
+
+/// Per-byte CODEDEPOSIT cost charged for the bytes returned by a contract's init code
+const GAS_PER_DEPLOYED_BYTE: u64 = 200;
+
+/// EIP-2929 cold SSTORE of a zero→nonzero slot: 20000 (SSTORE_SET) + 2100 (cold access)
+const COLD_SSTORE_SET_GAS: u64 = 22_100;
+
+/// Rough count of storage slots an NTT manager + transceiver initializer writes (owner,
+/// token address, mode, outbound rate limit + window, transceiver registration,
+/// threshold, paused flag) — an estimate, not something read off a specific contract.
+const NTT_INIT_STORAGE_SLOTS: u64 = 8;
+
+/// Etherscan-family gas oracle response (`module=gastracker&action=gasoracle`)
+#[derive(Deserialize)]
+struct GasOracleResponse {
+    status: String,
+    result: Option<GasOracleResult>,
+}
+
+#[derive(Deserialize)]
+struct GasOracleResult {
+    #[serde(rename = "ProposeGasPrice")]
+    propose_gas_price: String,
 }
 
 impl CostEstimator {
-    /// Estimate NTT deployment costs
-    pub fn estimate_ntt_costs(analysis: &FullAnalysis, sol_price: f64) -> CostEstimate {
-        // Solana deployment costs (rent exemption)
-        let sol_deployment = 2.5; // NTT manager + transceiver + token accounts
-
-        // EVM deployment costs (gas)
-        // Assuming ~2M gas at 30 gwei and $3000 ETH
-        let evm_deployment_usd = match analysis.token.chain {
-            crate::types::Chain::Ethereum => 180.0,  // Mainnet is expensive
-            crate::types::Chain::Polygon => 0.50,    // Very cheap
-            crate::types::Chain::Arbitrum => 5.0,    // L2 pricing
-            crate::types::Chain::Optimism => 5.0,    // L2 pricing
-            crate::types::Chain::Base => 2.0,        // L2 pricing
-            crate::types::Chain::Avalanche => 3.0,
-            crate::types::Chain::Bsc => 1.0,
-        };
+    /// Derive the current EIP-1559 gas price from `eth_feeHistory` and project what the
+    /// EVM-side NTT deployment steps will cost in the chain's native token and USD
+    pub async fn estimate_gas_fee(rpc: &EvmRpcClient, chain: Chain) -> Result<GasFeeEstimate> {
+        let history = rpc
+            .fee_history(FEE_HISTORY_BLOCK_COUNT, &[PRIORITY_FEE_PERCENTILE])
+            .await?;
+
+        let base_fee_wei = Self::project_next_base_fee(&history)?;
+        let priority_fee_wei = Self::median_priority_fee(&history);
+        let total_gas_price_wei = base_fee_wei + priority_fee_wei;
+
+        let deployment_native = (total_gas_price_wei * NTT_DEPLOYMENT_GAS) / 1e18;
+        let native_price = TokenDiscovery::new()
+            .get_price_usd(chain.native_coingecko_id())
+            .await
+            .unwrap_or(0.0);
+
+        Ok(GasFeeEstimate {
+            base_fee_gwei: base_fee_wei / 1e9,
+            priority_fee_gwei: priority_fee_wei / 1e9,
+            deployment_cost_native: deployment_native,
+            deployment_cost_usd: deployment_native * native_price,
+        })
+    }
+
+    /// Project the next block's base fee the way the protocol does:
+    /// `parent_base_fee * gas_used_delta / gas_target / 8`, rising when the parent block
+    /// ran above target and falling when below, clamped to non-negative. `gas_used_ratio`
+    /// is `gasUsed / gasLimit`, so the delta as a fraction of the (half-gas-limit) target
+    /// is `(ratio - 0.5) * 2`.
+    fn project_next_base_fee(history: &FeeHistory) -> Result<f64> {
+        let base_fees = &history.base_fee_per_gas;
+        if base_fees.len() < 2 {
+            anyhow::bail!("fee history returned too few base fees to project from");
+        }
+        let parent_base_fee = base_fees[base_fees.len() - 2];
+        let gas_used_ratio = *history
+            .gas_used_ratio
+            .last()
+            .context("fee history returned no gas-used ratios")?;
+
+        let delta_fraction = (gas_used_ratio - 0.5) * 2.0;
+        let next_base_fee = parent_base_fee + parent_base_fee * delta_fraction / 8.0;
+        Ok(next_base_fee.max(0.0))
+    }
+
+    /// Median of the sampled priority-fee percentile across the fee history window
+    fn median_priority_fee(history: &FeeHistory) -> f64 {
+        let mut rewards: Vec<f64> = history
+            .reward
+            .iter()
+            .filter_map(|block_rewards| block_rewards.first().copied())
+            .collect();
+        if rewards.is_empty() {
+            return 0.0;
+        }
+        // `reward` values come straight off an RPC response — `total_cmp` avoids panicking
+        // if any block's reward came back as a non-finite float (see the analogous fix in
+        // `volume.rs`).
+        rewards.sort_by(|a, b| a.total_cmp(b));
+        rewards[rewards.len() / 2]
+    }
+
+    /// Fetch the current gas price from the Etherscan-family gas oracle and project
+    /// NTT-manager/transceiver deployment cost from the source contract's deployed
+    /// bytecode size: CREATE intrinsic cost + per-byte deposit cost + EIP-2929
+    /// cold-SSTORE accounting for the manager's initializer slots.
+    pub async fn estimate_evm_deployment_cost(
+        chain: Chain,
+        code_bytes: u64,
+        api_key: &str,
+    ) -> Result<f64> {
+        let base_url = VolumeAnalyzer::get_api_url(chain)?;
+        let url = format!(
+            "{}?module=gastracker&action=gasoracle&apikey={}",
+            base_url, api_key
+        );
+
+        let response: GasOracleResponse = Client::new()
+            .get(&url)
+            .send()
+            .await
+            .context("failed to fetch gas oracle")?
+            .json()
+            .await
+            .context("failed to parse gas oracle response")?;
+
+        if response.status != "1" {
+            anyhow::bail!("gas oracle returned an error status");
+        }
+        let gas_price_gwei: f64 = response
+            .result
+            .context("gas oracle response missing result")?
+            .propose_gas_price
+            .parse()
+            .context("gas oracle ProposeGasPrice is not a number")?;
+
+        let total_gas = CREATE_BASE_GAS
+            + GAS_PER_DEPLOYED_BYTE * code_bytes
+            + COLD_SSTORE_SET_GAS * NTT_INIT_STORAGE_SLOTS;
+
+        let native_price = TokenDiscovery::new()
+            .get_price_usd(chain.native_coingecko_id())
+            .await
+            .unwrap_or(0.0);
+
+        let cost_native = (total_gas as f64 * gas_price_gwei) / 1e9;
+        Ok(cost_native * native_price)
+    }
+
+    /// Fetch recent Solana prioritization fees and sample the given percentile (50.0 for
+    /// median, 90.0 for a conservative p90 budget) of the observed micro-lamport price,
+    /// so the redeem-side cost reflects real compute-budget pricing instead of a flat
+    /// guess. `compute_units` defaults to `DEFAULT_NTT_REDEEM_COMPUTE_UNITS` when `None`.
+    pub fn estimate_solana_priority_fee(
+        rpc_url: Option<String>,
+        percentile: f64,
+        compute_units: Option<u64>,
+    ) -> Result<SolanaPriorityFeeEstimate> {
+        let url = rpc_url.unwrap_or_else(|| Chain::Solana.default_rpc_url().to_string());
+        let client = RpcClient::new(url);
+        let fees = client
+            .get_recent_prioritization_fees(&[])
+            .context("failed to fetch recent prioritization fees")?;
+
+        let mut prices: Vec<u64> = fees.iter().map(|f| f.prioritization_fee).collect();
+        prices.sort_unstable();
+
+        Ok(SolanaPriorityFeeEstimate {
+            compute_units: compute_units.unwrap_or(DEFAULT_NTT_REDEEM_COMPUTE_UNITS),
+            priority_fee_micro_lamports: Self::percentile(&prices, percentile),
+            percentile,
+        })
+    }
+
+    /// Nearest-rank percentile of an already-sorted slice
+    fn percentile(sorted: &[u64], percentile: f64) -> u64 {
+        if sorted.is_empty() {
+            return 0;
+        }
+        let idx = ((percentile / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+        sorted[idx.min(sorted.len() - 1)]
+    }
+
+    /// Estimate NTT deployment costs. `sol_deployment` is the live rent-exemption sum
+    /// from `SolanaChecker::estimate_ntt_deployment_cost` (mint + NTT manager/transceiver
+    /// config + token account), falling back to a flat ~2.5 SOL estimate itself if the
+    /// Solana RPC is unreachable. `gas_estimate` is the live EIP-1559 projection from
+    /// `estimate_gas_fee`, when available; `bytecode_deployment_cost_usd` is the gas
+    /// oracle + bytecode-size projection from `estimate_evm_deployment_cost`, used when
+    /// the live feeHistory-based estimate isn't available (e.g. no RPC reachable but an
+    /// Etherscan-family API key is set). `solana_priority_fee` is the live redeem-side
+    /// compute-budget projection from `estimate_solana_priority_fee`, when available;
+    /// otherwise a flat per-transfer estimate is used.
+    pub fn estimate_ntt_costs(
+        analysis: &FullAnalysis,
+        sol_price: f64,
+        sol_deployment: f64,
+        gas_estimate: Option<GasFeeEstimate>,
+        bytecode_deployment_cost_usd: Option<f64>,
+        solana_priority_fee: Option<SolanaPriorityFeeEstimate>,
+    ) -> CostEstimate {
+        // EVM deployment costs (gas). Solana-origin tokens have no EVM-side deployment
+        // leg; `sol_deployment` above already covers the Solana rent cost.
+        let evm_deployment_usd = gas_estimate
+            .as_ref()
+            .map(|g| g.deployment_cost_usd)
+            .or(bytecode_deployment_cost_usd)
+            .unwrap_or(0.0);
 
         let deployment_cost_sol = sol_deployment;
         let deployment_cost_usd = (sol_deployment * sol_price) + evm_deployment_usd;
 
-        // Per-transfer costs
-        // Wormhole: ~$0.05 relayer fee
-        // Solana: ~0.000005 SOL tx fee
-        // Source chain gas varies
-        let per_transfer_cost_usd = 0.10;
+        // Per-transfer costs: the Solana-side redeem is a base signature fee plus the
+        // compute-budget price times the redeem's compute units (the Wormhole relayer fee
+        // and source-chain gas are not modeled here — see `gas_estimate` for the latter).
+        let per_transfer_cost_usd = match &solana_priority_fee {
+            Some(pf) => {
+                let compute_fee_lamports = (pf.compute_units as f64
+                    * pf.priority_fee_micro_lamports as f64
+                    / 1_000_000.0)
+                    .ceil();
+                let total_lamports = BASE_SIGNATURE_FEE_LAMPORTS as f64 + compute_fee_lamports;
+                (total_lamports / 1e9) * sol_price
+            }
+            None => 0.10,
+        };
 
         // Monthly operational (assuming relayer costs)
         let monthly_operational_usd = 50.0;
@@ -46,12 +284,14 @@ impl CostEstimator {
             deployment_cost_sol,
             per_transfer_cost_usd,
             monthly_operational_usd,
+            gas_estimate,
+            solana_priority_fee,
         }
     }
 
     /// Format costs for display
     pub fn format_costs(estimate: &CostEstimate) -> String {
-        format!(
+        let mut out = format!(
             "Deployment: ~${:.0} ({:.2} SOL + EVM gas)\n\
              Per Transfer: ~${:.2}\n\
              Monthly Ops: ~${:.0}",
@@ -59,6 +299,22 @@ impl CostEstimator {
             estimate.deployment_cost_sol,
             estimate.per_transfer_cost_usd,
             estimate.monthly_operational_usd
-        )
+        );
+
+        if let Some(gas) = &estimate.gas_estimate {
+            out.push_str(&format!(
+                "\nEVM Gas (live): {:.2} gwei base + {:.2} gwei priority (~${:.2})",
+                gas.base_fee_gwei, gas.priority_fee_gwei, gas.deployment_cost_usd
+            ));
+        }
+
+        if let Some(pf) = &estimate.solana_priority_fee {
+            out.push_str(&format!(
+                "\nSolana Compute (live): {} CU @ {} micro-lamports (p{:.0}) — per-transfer reflects current congestion",
+                pf.compute_units, pf.priority_fee_micro_lamports, pf.percentile
+            ));
+        }
+
+        out
     }
 }
@@ -1,6 +1,8 @@
 use crate::types::{
-    FullAnalysis, MigrationMethod, MigrationPlan, MigrationStep, NttDeploymentConfig, NttMode,
+    Chain, FullAnalysis, MigrationMethod, MigrationPlan, MigrationStep, NttDeploymentConfig,
+    NttMode, NttPeerConfig, NttRateLimitsConfig,
 };
+use std::collections::HashMap;
 
 /// Generates migration plans based on analysis results
 pub struct MigrationPlanGenerator;
@@ -47,29 +49,90 @@ impl MigrationPlanGenerator {
             MigrationMethod::NttSunrise => Self::generate_ntt_steps(analysis),
             MigrationMethod::NeonEvm => Self::generate_neon_steps(analysis),
             MigrationMethod::NativeRewrite => Self::generate_native_steps(analysis),
+            // `determine_recommended_path` never selects this for a `FullAnalysis` (that
+            // struct is fungible-token-only) — NFT collections get their own plan via
+            // `generate_nft_bridge_steps`, called directly from the NFT scan path instead.
+            MigrationMethod::NftBridge => Vec::new(),
         }
     }
 
+    /// Step-by-step instructions for migrating an NFT collection via the Wormhole NFT
+    /// bridge. Unlike `generate_steps`, this doesn't take a `FullAnalysis` — there's no
+    /// decimals/bytecode/holder data for an NFT collection to reuse.
+    pub fn generate_nft_bridge_steps(collection: &crate::types::NftCollectionInfo) -> Vec<MigrationStep> {
+        let mut steps = vec![
+            MigrationStep {
+                order: 1,
+                title: "Install Wormhole CLI".to_string(),
+                description: "Install the Wormhole CLI, which drives the NFT bridge's \
+                    transfer/redeem flow"
+                    .to_string(),
+                command: Some("npm install -g @wormhole-foundation/wormhole-cli".to_string()),
+            },
+            MigrationStep {
+                order: 2,
+                title: "Transfer a Token".to_string(),
+                description: format!(
+                    "Lock a token from {} on {} into the NFT bridge, which mints its wrapped \
+                        counterpart on Solana the first time that specific token id is bridged",
+                    collection.symbol, collection.chain
+                ),
+                command: Some(format!(
+                    "worm nft transfer --network mainnet --chain {} --token {} --token-id <TOKEN_ID> --target-chain solana",
+                    collection.chain.to_string().to_lowercase(),
+                    collection.address
+                )),
+            },
+            MigrationStep {
+                order: 3,
+                title: "Redeem on Solana".to_string(),
+                description: "Submit the VAA on Solana to mint the wrapped NFT and attach its \
+                    Metaplex metadata account"
+                    .to_string(),
+                command: Some("worm nft redeem --network mainnet --chain solana".to_string()),
+            },
+        ];
+
+        if collection.base_uri.is_none() {
+            steps.push(MigrationStep {
+                order: 4,
+                title: "Confirm Metadata Resolves".to_string(),
+                description: "No base/token URI could be read from the source contract — \
+                    confirm each token's `tokenURI()` resolves before bridging so the wrapped \
+                    NFT's Metaplex metadata isn't left empty"
+                    .to_string(),
+                command: None,
+            });
+        }
+
+        steps
+    }
+
     fn generate_ntt_steps(analysis: &FullAnalysis) -> Vec<MigrationStep> {
         let mut steps = Vec::new();
         let mode = &analysis.compatibility.recommended_mode;
+        let mut order = 0u8;
+        let mut next_order = || {
+            order += 1;
+            order
+        };
 
         steps.push(MigrationStep {
-            order: 1,
+            order: next_order(),
             title: "Install NTT CLI".to_string(),
             description: "Install the Wormhole NTT CLI tool".to_string(),
             command: Some("npm install -g @wormhole-foundation/ntt-cli".to_string()),
         });
 
         steps.push(MigrationStep {
-            order: 2,
+            order: next_order(),
             title: "Initialize NTT Project".to_string(),
             description: "Create a new NTT deployment configuration".to_string(),
             command: Some("ntt init".to_string()),
         });
 
         steps.push(MigrationStep {
-            order: 3,
+            order: next_order(),
             title: "Configure Source Chain".to_string(),
             description: format!(
                 "Add {} as source chain with {} mode",
@@ -83,8 +146,48 @@ impl MigrationPlanGenerator {
             )),
         });
 
+        if analysis.bytecode.is_proxy {
+            steps.push(MigrationStep {
+                order: next_order(),
+                title: "Verify Proxy Admin".to_string(),
+                description: "Token contract is an upgradeable proxy — confirm the \
+                    implementation is frozen or the upgrade admin is renounced/multisig-controlled \
+                    before granting NTT lock/mint authority"
+                    .to_string(),
+                command: None,
+            });
+        }
+
+        if *mode == NttMode::Burning {
+            let transfer_cmd = if analysis.access_control.has_role_based_access {
+                format!("cast send {} \"grantRole(bytes32,address)\" <MINTER_ROLE> <NTT_MANAGER_ADDRESS>", analysis.token.address)
+            } else {
+                format!("cast send {} \"transferOwnership(address)\" <NTT_MANAGER_ADDRESS>", analysis.token.address)
+            };
+            steps.push(MigrationStep {
+                order: next_order(),
+                title: "Transfer Mint Authority".to_string(),
+                description: "Burning mode requires the NTT manager to mint and burn the \
+                    source token directly — grant it mint authority before enabling transfers"
+                    .to_string(),
+                command: Some(transfer_cmd),
+            });
+        }
+
+        if analysis.capabilities.has_pause {
+            steps.push(MigrationStep {
+                order: next_order(),
+                title: "Check Pause State".to_string(),
+                description: "Token contract has a pause function — a paused source token \
+                    will freeze all NTT bridging until it's unpaused, so confirm it isn't \
+                    paused before testing transfers"
+                    .to_string(),
+                command: None,
+            });
+        }
+
         steps.push(MigrationStep {
-            order: 4,
+            order: next_order(),
             title: "Configure Destination Chain".to_string(),
             description: format!(
                 "Add Solana as destination with {} decimals",
@@ -97,7 +200,7 @@ impl MigrationPlanGenerator {
         });
 
         steps.push(MigrationStep {
-            order: 5,
+            order: next_order(),
             title: "Deploy NTT Contracts".to_string(),
             description: "Deploy the NTT manager and transceiver contracts".to_string(),
             command: Some("ntt deploy".to_string()),
@@ -115,14 +218,14 @@ impl MigrationPlanGenerator {
             None => "ntt configure-limits --daily-limit 1000000".to_string(),
         };
         steps.push(MigrationStep {
-            order: 6,
+            order: next_order(),
             title: "Configure Rate Limits".to_string(),
             description: rate_limit_desc,
             command: Some(rate_limit_cmd),
         });
 
         steps.push(MigrationStep {
-            order: 7,
+            order: next_order(),
             title: "Test Transfer".to_string(),
             description: "Perform a test transfer with a small amount".to_string(),
             command: Some("ntt transfer --amount 1 --to <SOLANA_ADDRESS>".to_string()),
@@ -195,6 +298,30 @@ impl MigrationPlanGenerator {
             NttMode::Burning => "burning", // Both sides burn for full burning mode
         };
 
+        // Solana is the only destination peer Daybreak derives a plan for today, so the
+        // limits/peers sections below both key on its Wormhole chain id. A future
+        // multi-destination deployment would extend these maps with one entry per peer.
+        let solana_chain_id = crate::analyzers::bridges::wormhole_chain_id(Chain::Solana);
+        let inbound_limit = analysis
+            .rate_limit
+            .as_ref()
+            .map(|rl| rl.recommended_daily_limit);
+
+        let mut inbound_capacity_per_chain = HashMap::new();
+        if let Some(limit) = inbound_limit {
+            inbound_capacity_per_chain.insert(solana_chain_id, limit);
+        }
+
+        let mut peers = HashMap::new();
+        peers.insert(
+            solana_chain_id,
+            NttPeerConfig {
+                chain_name: "solana".to_string(),
+                manager_address: None,
+                inbound_rate_limit: inbound_limit,
+            },
+        );
+
         NttDeploymentConfig {
             network: crate::types::NetworkConfig {
                 source_chain: analysis.token.chain.to_string().to_lowercase(),
@@ -210,6 +337,14 @@ impl MigrationPlanGenerator {
                     mode: dest_mode.to_string(),
                 },
             },
+            limits: NttRateLimitsConfig {
+                outbound_capacity: inbound_limit.unwrap_or(0),
+                inbound_capacity_per_chain,
+                // NTT's rate limiter window matches the 24h bucket `volume::VolumeAnalyzer`
+                // already models its recommendations around
+                refill_window_secs: 86_400,
+            },
+            peers,
         }
     }
 }
@@ -2,9 +2,10 @@ pub mod migration_plan;
 pub mod ntt_config;
 pub mod cost_estimate;
 pub mod comparison;
+pub mod snapshot;
 
 pub use migration_plan::MigrationPlanGenerator;
-pub use ntt_config::NttConfigGenerator;
-#[allow(unused_imports)]
+pub use ntt_config::{NttConfigGenerator, NttDestination, NttNetwork};
 pub use cost_estimate::CostEstimator;
 pub use comparison::PathComparator;
+pub use snapshot::SnapshotDiffer;
@@ -5,19 +5,68 @@ use serde::Serialize;
 /// Generates NTT deployment configuration files
 pub struct NttConfigGenerator;
 
+/// Network environment for the generated deployment, so a devnet config can be
+/// generated for dry runs without touching the mainnet one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NttNetwork {
+    Mainnet,
+    Testnet,
+}
+
+impl NttNetwork {
+    fn as_str(self) -> &'static str {
+        match self {
+            NttNetwork::Mainnet => "mainnet",
+            NttNetwork::Testnet => "testnet",
+        }
+    }
+}
+
+/// A single NTT destination peer. NTT is a hub-and-spoke system, so a deployment can
+/// fan out from the source chain to any number of these, each with its own mode,
+/// decimals, and rate limit rather than a single hardcoded Solana route.
+#[derive(Debug, Clone)]
+pub struct NttDestination {
+    pub chain: String,
+    pub mode: String,
+    pub decimals: u8,
+    pub token_address: Option<String>,
+    pub daily_limit: Option<u64>,
+    pub per_transaction_limit: Option<u64>,
+}
+
+impl NttDestination {
+    /// The Solana leg of the deployment, derived from the analyzed token's
+    /// compatibility result (always burning mode, decimals already accounts for
+    /// trimming). The SPL mint address isn't known until after deployment.
+    pub fn solana(analysis: &FullAnalysis) -> Self {
+        let (daily_limit, per_transaction_limit) = analysis
+            .rate_limit
+            .as_ref()
+            .map(|rl| {
+                (
+                    Some(rl.recommended_daily_limit),
+                    Some(rl.recommended_per_tx_limit),
+                )
+            })
+            .unwrap_or((None, None));
+
+        Self {
+            chain: "solana".to_string(),
+            mode: "burning".to_string(),
+            decimals: analysis.compatibility.solana_decimals,
+            token_address: None,
+            daily_limit,
+            per_transaction_limit,
+        }
+    }
+}
+
 #[derive(Serialize)]
 struct DeploymentJson {
     version: &'static str,
     network: NetworkSection,
     chains: ChainsSection,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    rate_limits: Option<RateLimitsSection>,
-}
-
-#[derive(Serialize)]
-struct RateLimitsSection {
-    daily_limit: u64,
-    per_transaction_limit: u64,
 }
 
 #[derive(Serialize)]
@@ -29,7 +78,7 @@ struct NetworkSection {
 #[derive(Serialize)]
 struct ChainsSection {
     source: ChainConfig,
-    destination: ChainConfig,
+    peers: Vec<ChainConfig>,
 }
 
 #[derive(Serialize)]
@@ -40,6 +89,14 @@ struct ChainConfig {
     ntt_manager: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     transceiver: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate_limits: Option<RateLimitsSection>,
+}
+
+#[derive(Serialize)]
+struct RateLimitsSection {
+    daily_limit: u64,
+    per_transaction_limit: u64,
 }
 
 #[derive(Serialize)]
@@ -51,26 +108,44 @@ struct TokenConfig {
 }
 
 impl NttConfigGenerator {
-    /// Generate deployment.json content
-    pub fn generate_deployment_json(analysis: &FullAnalysis) -> Result<String> {
-        let config = &analysis.compatibility;
-
-        let source_mode = config.recommended_mode.to_string().to_lowercase();
-
-        // NTT destination is always burning
-        let dest_mode = "burning";
+    /// Generate deployment.json content covering the source chain plus an arbitrary
+    /// set of destination peers
+    pub fn generate_deployment_json(
+        analysis: &FullAnalysis,
+        network: NttNetwork,
+        destinations: &[NttDestination],
+    ) -> Result<String> {
+        let source_mode = analysis
+            .compatibility
+            .recommended_mode
+            .to_string()
+            .to_lowercase();
 
-        let rate_limits = analysis.rate_limit.as_ref().map(|rl| RateLimitsSection {
-            daily_limit: rl.recommended_daily_limit,
-            per_transaction_limit: rl.recommended_per_tx_limit,
-        });
+        let peers = destinations
+            .iter()
+            .map(|dest| ChainConfig {
+                chain: dest.chain.clone(),
+                token: TokenConfig {
+                    address: dest.token_address.clone(),
+                    decimals: dest.decimals,
+                    mode: dest.mode.clone(),
+                },
+                ntt_manager: None,
+                transceiver: None,
+                rate_limits: dest.daily_limit.zip(dest.per_transaction_limit).map(
+                    |(daily_limit, per_transaction_limit)| RateLimitsSection {
+                        daily_limit,
+                        per_transaction_limit,
+                    },
+                ),
+            })
+            .collect();
 
         let deployment = DeploymentJson {
             version: "1.0.0",
             network: NetworkSection {
-                network_type: "mainnet",
+                network_type: network.as_str(),
             },
-            rate_limits,
             chains: ChainsSection {
                 source: ChainConfig {
                     chain: analysis.token.chain.to_string().to_lowercase(),
@@ -81,25 +156,21 @@ impl NttConfigGenerator {
                     },
                     ntt_manager: None,
                     transceiver: None,
+                    rate_limits: None,
                 },
-                destination: ChainConfig {
-                    chain: "solana".to_string(),
-                    token: TokenConfig {
-                        address: None, // To be deployed
-                        decimals: config.solana_decimals,
-                        mode: dest_mode.to_string(),
-                    },
-                    ntt_manager: None,
-                    transceiver: None,
-                },
+                peers,
             },
         };
 
         serde_json::to_string_pretty(&deployment).map_err(Into::into)
     }
 
-    /// Generate NTT CLI commands for deployment
-    pub fn generate_cli_commands(analysis: &FullAnalysis) -> Vec<String> {
+    /// Generate NTT CLI commands for deployment: one `add-chain`/`set-peer`/
+    /// `configure-limits` block per destination, after the shared init/source steps
+    pub fn generate_cli_commands(
+        analysis: &FullAnalysis,
+        destinations: &[NttDestination],
+    ) -> Vec<String> {
         let mode = analysis
             .compatibility
             .recommended_mode
@@ -107,12 +178,6 @@ impl NttConfigGenerator {
             .to_lowercase();
         let chain = analysis.token.chain.to_string().to_lowercase();
 
-        let daily_limit = analysis
-            .rate_limit
-            .as_ref()
-            .map(|r| r.recommended_daily_limit)
-            .unwrap_or(1_000_000);
-
         let mut cmds = vec![
             "# NTT Deployment Commands".to_string(),
             "".to_string(),
@@ -124,42 +189,54 @@ impl NttConfigGenerator {
                 "ntt add-chain {} --mode {} --token {}",
                 chain, mode, analysis.token.address
             ),
-            "".to_string(),
-            "# 3. Add destination chain (Solana)".to_string(),
-            format!(
-                "ntt add-chain solana --mode burning --decimals {}",
-                analysis.compatibility.solana_decimals
-            ),
-            "".to_string(),
-            "# 4. Deploy contracts".to_string(),
-            "ntt deploy".to_string(),
-            "".to_string(),
         ];
 
-        // Rate limit command with calculated or fallback value
-        if let Some(ref rl) = analysis.rate_limit {
+        let mut step = 3;
+        for dest in destinations {
+            cmds.push("".to_string());
+            cmds.push(format!("# {}. Add destination chain ({})", step, dest.chain));
             cmds.push(format!(
-                "# 5. Configure rate limits (based on {} daily transfers)",
-                rl.daily_transfers
+                "ntt add-chain {} --mode {} --decimals {}",
+                dest.chain, dest.mode, dest.decimals
             ));
+            step += 1;
+
+            cmds.push("".to_string());
+            cmds.push(format!("# {}. Set {} as a peer", step, dest.chain));
+            cmds.push(format!("ntt set-peer {}", dest.chain));
+            step += 1;
+
+            let daily_limit = dest.daily_limit.unwrap_or(1_000_000);
+            cmds.push("".to_string());
+            cmds.push(format!("# {}. Configure rate limits for {}", step, dest.chain));
             cmds.push(format!(
-                "ntt configure-limits --daily-limit {}",
-                rl.recommended_daily_limit
+                "ntt configure-limits --chain {} --daily-limit {}",
+                dest.chain, daily_limit
             ));
-        } else {
-            cmds.push("# 5. Configure rate limits (adjust based on expected volume)".to_string());
-            cmds.push(format!("ntt configure-limits --daily-limit {}", daily_limit));
+            step += 1;
         }
 
-        // Post-deploy: transfer mint authority
         cmds.push("".to_string());
-        cmds.push("# 6. Transfer SPL mint authority to NTT manager (REQUIRED for bridging)".to_string());
-        cmds.push("# Replace <NTT_MANAGER> with the address from `ntt deploy` output".to_string());
-        cmds.push("spl-token authorize <SPL_MINT> mint <NTT_MANAGER>".to_string());
+        cmds.push(format!("# {}. Deploy contracts", step));
+        cmds.push("ntt deploy".to_string());
+        step += 1;
+
+        // Post-deploy: transfer mint authority (only meaningful once Solana is a
+        // destination, which is the common case but not guaranteed)
+        if destinations.iter().any(|d| d.chain == "solana") {
+            cmds.push("".to_string());
+            cmds.push(format!(
+                "# {}. Transfer SPL mint authority to NTT manager (REQUIRED for bridging)",
+                step
+            ));
+            step += 1;
+            cmds.push("# Replace <NTT_MANAGER> with the address from `ntt deploy` output".to_string());
+            cmds.push("spl-token authorize <SPL_MINT> mint <NTT_MANAGER>".to_string());
+        }
 
         cmds.push("".to_string());
-        cmds.push("# 7. Test transfer".to_string());
-        cmds.push("ntt transfer --amount 1 --to <SOLANA_ADDRESS>".to_string());
+        cmds.push(format!("# {}. Test transfer", step));
+        cmds.push("ntt transfer --amount 1 --to <DESTINATION_ADDRESS>".to_string());
 
         cmds
     }
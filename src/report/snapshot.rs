@@ -0,0 +1,34 @@
+use crate::output::JsonOutput;
+use crate::types::FullAnalysis;
+use anyhow::{Context, Result};
+
+/// Persists and renders `FullAnalysis` snapshots for `compare --baseline`, so a user can
+/// track how a token's capabilities, risk score, or bridge status shift between runs —
+/// useful since a token's owner can toggle mint/pause/blacklist between deployments.
+pub struct SnapshotDiffer;
+
+impl SnapshotDiffer {
+    /// Load a previously saved snapshot from disk.
+    pub fn load(path: &str) -> Result<FullAnalysis> {
+        let raw = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read baseline snapshot '{path}'"))?;
+        serde_json::from_str(&raw)
+            .with_context(|| format!("'{path}' is not a valid analysis snapshot"))
+    }
+
+    /// Save the current analysis as a snapshot, reusing `JsonOutput`'s own serialization
+    /// so the snapshot format never drifts from what `--format json` would print.
+    pub fn save(path: &str, analysis: &FullAnalysis) -> Result<()> {
+        let json = JsonOutput::format_analysis(analysis)?;
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write snapshot to '{path}'"))
+    }
+
+    /// Canonical pretty-printed text for an analysis, ready to hand to `diff::lines` —
+    /// see `TerminalOutput::print_diff`. Deliberately the same serialization `save` and
+    /// `JsonOutput::format_analysis` use, so a saved-then-reloaded snapshot diffs as
+    /// empty against itself.
+    pub fn render(analysis: &FullAnalysis) -> Result<String> {
+        JsonOutput::format_analysis(analysis)
+    }
+}
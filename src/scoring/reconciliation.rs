@@ -0,0 +1,159 @@
+use crate::types::{CompatibilityResult, ReconciliationReport};
+
+/// Reconciles a token's locked balance on its source chain against its minted supply on
+/// Solana — the live, on-chain-balance counterpart to `CompatibilityChecker`'s static
+/// `REBASING`/`FEE_ON_TRANSFER` desync warnings. Static analysis says a token *might*
+/// desync; this is the production check for whether it actually has.
+pub struct SupplyReconciler;
+
+impl SupplyReconciler {
+    /// A one-raw-unit cushion on the Solana side. `locked` and `minted` are typically read
+    /// a few seconds apart from two different RPCs, and floor-division trimming on an
+    /// in-flight amount can land a unit either side of `expected` without anything having
+    /// actually desynced.
+    const ROUNDING_TOLERANCE: u128 = 1;
+
+    /// Compare a source-chain locked balance against the Solana-side minted supply.
+    /// `source_decimals` is the token's native decimals (pre-trim); `compatibility`
+    /// supplies the resolved Solana decimals the trim target already settled on.
+    pub fn reconcile(
+        compatibility: &CompatibilityResult,
+        source_decimals: u8,
+        locked: u128,
+        minted: u128,
+    ) -> ReconciliationReport {
+        let expected = Self::trim(locked, source_decimals, compatibility.solana_decimals);
+        let drift = expected.abs_diff(minted);
+        let within_tolerance = drift <= Self::ROUNDING_TOLERANCE;
+
+        let likely_cause = if within_tolerance {
+            None
+        } else {
+            Self::attribute_cause(compatibility)
+        };
+
+        ReconciliationReport {
+            locked,
+            minted,
+            expected,
+            drift,
+            within_tolerance,
+            likely_cause,
+        }
+    }
+
+    /// Scale `amount` from `from_decimals` down to `to_decimals` by floor division — the
+    /// same truncation `CompatibilityChecker::check_decimals` warns about, so `expected`
+    /// already reflects the precision loss a real bridge would apply.
+    fn trim(amount: u128, from_decimals: u8, to_decimals: u8) -> u128 {
+        if from_decimals <= to_decimals {
+            return amount;
+        }
+        let scale = 10u128.pow((from_decimals - to_decimals) as u32);
+        amount / scale
+    }
+
+    /// Attribute an out-of-tolerance drift to whichever desync-capable issue is already on
+    /// record for this token, preferring rebasing (it breaks the invariant unconditionally)
+    /// over fee-on-transfer (which only breaks it on the locked side).
+    fn attribute_cause(compatibility: &CompatibilityResult) -> Option<String> {
+        ["REBASING", "FEE_ON_TRANSFER"]
+            .into_iter()
+            .find(|code| compatibility.issues.iter().any(|i| i.code == *code))
+            .map(str::to_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CompatibilityIssue, IssueSeverity, NttMode, SupplyModel};
+
+    fn sample_result(solana_decimals: u8, issues: Vec<CompatibilityIssue>) -> CompatibilityResult {
+        CompatibilityResult {
+            is_compatible: issues.iter().all(|i| i.severity != IssueSeverity::Error),
+            recommended_mode: NttMode::Locking,
+            issues,
+            decimal_trimming_required: solana_decimals < 18,
+            solana_decimals,
+            supply_model: SupplyModel::Fixed,
+            governance: Default::default(),
+        }
+    }
+
+    fn rebasing_issue() -> CompatibilityIssue {
+        CompatibilityIssue {
+            severity: IssueSeverity::Error,
+            code: "REBASING".to_string(),
+            title: "Rebasing Token Detected".to_string(),
+            description: String::new(),
+            recommendation: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_reconcile_exact_match_within_tolerance() {
+        let compat = sample_result(8, vec![]);
+        // 18-decimal locked balance trimmed to 8 decimals
+        let locked = 1_000_000_000_000_000_000u128;
+        let minted = 100_000_000u128;
+        let report = SupplyReconciler::reconcile(&compat, 18, locked, minted);
+
+        assert_eq!(report.expected, 100_000_000);
+        assert_eq!(report.drift, 0);
+        assert!(report.within_tolerance);
+        assert!(report.likely_cause.is_none());
+    }
+
+    #[test]
+    fn test_reconcile_no_trimming_needed() {
+        let compat = sample_result(6, vec![]);
+        let report = SupplyReconciler::reconcile(&compat, 6, 500, 500);
+        assert_eq!(report.expected, 500);
+        assert!(report.within_tolerance);
+    }
+
+    #[test]
+    fn test_reconcile_one_unit_rounding_is_tolerated() {
+        let compat = sample_result(8, vec![]);
+        let report = SupplyReconciler::reconcile(&compat, 18, 1_000_000_000_000_000_000, 99_999_999);
+        assert_eq!(report.drift, 1);
+        assert!(report.within_tolerance);
+    }
+
+    #[test]
+    fn test_reconcile_drift_attributed_to_rebasing() {
+        let compat = sample_result(8, vec![rebasing_issue()]);
+        let report = SupplyReconciler::reconcile(&compat, 18, 1_000_000_000_000_000_000, 50_000_000);
+
+        assert!(!report.within_tolerance);
+        assert_eq!(report.likely_cause.as_deref(), Some("REBASING"));
+    }
+
+    #[test]
+    fn test_reconcile_drift_with_no_known_cause() {
+        let compat = sample_result(8, vec![]);
+        let report = SupplyReconciler::reconcile(&compat, 18, 1_000_000_000_000_000_000, 50_000_000);
+
+        assert!(!report.within_tolerance);
+        assert!(report.likely_cause.is_none());
+    }
+
+    #[test]
+    fn test_as_issue_none_when_within_tolerance() {
+        let compat = sample_result(8, vec![]);
+        let report = SupplyReconciler::reconcile(&compat, 18, 1_000_000_000_000_000_000, 100_000_000);
+        assert!(report.as_issue().is_none());
+    }
+
+    #[test]
+    fn test_as_issue_errors_when_drifted() {
+        let compat = sample_result(8, vec![rebasing_issue()]);
+        let report = SupplyReconciler::reconcile(&compat, 18, 1_000_000_000_000_000_000, 50_000_000);
+        let issue = report.as_issue().expect("drift should produce an issue");
+
+        assert_eq!(issue.severity, IssueSeverity::Error);
+        assert_eq!(issue.code, "SUPPLY_DRIFT");
+        assert!(issue.description.contains("REBASING"));
+    }
+}
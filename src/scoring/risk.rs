@@ -1,6 +1,6 @@
 use crate::types::{
-    BridgeStatus, BytecodeAnalysis, BytecodeComplexity, HolderData, RiskComponents, RiskScore,
-    TokenCapabilities, TokenInfo,
+    AttestationStatus, BridgeStatus, BytecodeAnalysis, BytecodeComplexity, HolderData,
+    RiskComponents, RiskScore, TokenCapabilities, TokenInfo, TrimmingAnalysis,
 };
 
 /// Calculates composite risk score for token migration
@@ -23,7 +23,7 @@ impl RiskScorer {
             bridge_status: Self::score_bridge(bridge_status),
         };
 
-        RiskScore::from_components(components)
+        RiskScore::from_components(components, TrimmingAnalysis::calculate(token.decimals))
     }
 
     /// Score decimal handling complexity (0-20 points)
@@ -47,9 +47,14 @@ impl RiskScorer {
 
         let mut score = 0u8;
 
-        // Fee-on-transfer is highly problematic for bridges
+        // Fee-on-transfer used to be a near-instant 15, since a vanilla SPL mint can't
+        // reproduce it and holders would silently lose the fee behavior post-migration.
+        // `SolanaDeployer::create_spl_token_2022` now gives every fee-pattern token a
+        // migration target that keeps the fee semantics (Token-2022's TransferFee
+        // extension), so the penalty is just the residual cost of running a less
+        // battle-tested token program and needing downstream tooling to handle it.
         if bytecode.has_fee_pattern {
-            score += 15;
+            score += 5;
         }
 
         // Pausable tokens can cause bridge issues
@@ -117,7 +122,7 @@ impl RiskScorer {
         if bridge_status.already_on_solana {
             // Already bridged = coordination needed
             15
-        } else if bridge_status.wormhole_attested {
+        } else if bridge_status.wormhole_attestation.verified {
             // Wormhole attested but not on Solana
             5
         } else {
@@ -192,8 +197,8 @@ mod tests {
             has_fee_pattern: true,
             ..Default::default()
         };
-        // 15 + 3 + 4 = 22, capped at 25
-        assert_eq!(RiskScorer::score_features(&caps, &bytecode), 22);
+        // 5 + 3 + 4 = 12 (fee penalty reduced now that Token-2022 is a migration target)
+        assert_eq!(RiskScorer::score_features(&caps, &bytecode), 12);
     }
 
     #[test]
@@ -208,8 +213,8 @@ mod tests {
             has_selfdestruct: true,
             ..Default::default()
         };
-        // 15 + 3 + 4 + 3 = 25 → capped at 25
-        assert_eq!(RiskScorer::score_features(&caps, &bytecode), 25);
+        // 5 + 3 + 4 + 3 = 15
+        assert_eq!(RiskScorer::score_features(&caps, &bytecode), 15);
     }
 
     // ── Holder concentration scoring ───────────────────────
@@ -229,6 +234,7 @@ mod tests {
             }],
             top_10_concentration: 60.0,
             total_holders: Some(100),
+            scanned_window: None,
         };
         assert_eq!(RiskScorer::score_holders(Some(&data)), 15);
     }
@@ -243,6 +249,7 @@ mod tests {
             }],
             top_10_concentration: concentration,
             total_holders: Some(1000),
+            scanned_window: None,
         };
         assert_eq!(RiskScorer::score_holders(Some(&make(49.9))), 0);
         assert_eq!(RiskScorer::score_holders(Some(&make(69.9))), 5);
@@ -264,7 +271,10 @@ mod tests {
     #[test]
     fn test_bridge_wormhole_attested() {
         let bs = BridgeStatus {
-            wormhole_attested: true,
+            wormhole_attestation: AttestationStatus {
+                verified: true,
+                ..Default::default()
+            },
             ..Default::default()
         };
         assert_eq!(RiskScorer::score_bridge(&bs), 5);
@@ -301,6 +311,7 @@ mod tests {
             }],
             top_10_concentration: 90.0,
             total_holders: Some(50),
+            scanned_window: None,
         }; // 15 pts holders
 
         let score =
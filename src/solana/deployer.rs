@@ -1,4 +1,4 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
     commitment_config::CommitmentConfig,
@@ -8,7 +8,26 @@ use solana_sdk::{
     system_instruction,
     transaction::Transaction,
 };
+use spl_associated_token_account::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account,
+};
+use spl_token::instruction::AuthorityType;
 use spl_token::state::Mint;
+use spl_token_2022::extension::{transfer_fee::instruction::initialize_transfer_fee_config, ExtensionType};
+use spl_token_2022::state::Mint as Mint2022;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How many times to retry the `requestAirdrop` RPC call itself if the faucet is
+/// temporarily rate-limited or unreachable
+const AIRDROP_REQUEST_RETRIES: u32 = 3;
+/// Delay between `requestAirdrop` retries
+const AIRDROP_REQUEST_RETRY_DELAY: Duration = Duration::from_secs(2);
+/// How long to keep polling the balance for airdropped funds to land before giving up
+const AIRDROP_CONFIRM_TIMEOUT: Duration = Duration::from_secs(30);
+/// How often to poll the balance while waiting for an airdrop to land
+const AIRDROP_POLL_INTERVAL: Duration = Duration::from_millis(1000);
 
 /// Result of deploying an SPL token
 #[derive(Debug, Clone)]
@@ -17,6 +36,13 @@ pub struct DeployResult {
     pub signature: Signature,
     pub cost_sol: f64,
     pub network: String,
+    /// The Metaplex Token Metadata PDA created alongside the mint, so wallets/explorers
+    /// resolve a name and symbol instead of showing an anonymous mint
+    pub metadata_pda: Pubkey,
+    /// Which token program owns the mint — `spl_token::id()` for a vanilla mint, or
+    /// `spl_token_2022::id()` when `create_spl_token_2022` configured the TransferFee
+    /// extension
+    pub token_program: Pubkey,
 }
 
 impl DeployResult {
@@ -51,8 +77,19 @@ impl SolanaDeployer {
         }
     }
 
-    /// Create an SPL token mint matching the EVM token specs
-    pub fn create_spl_token(&self, payer: &Keypair, decimals: u8) -> Result<DeployResult> {
+    /// Create an SPL token mint matching the EVM token specs, with a Metaplex Token
+    /// Metadata account attached in the same transaction so the mint shows up with a
+    /// name and symbol in wallets/explorers instead of as an anonymous account.
+    /// `metadata_uri` is the off-chain JSON (image, description, etc.) — optional, since
+    /// not every migrated token has one to carry over.
+    pub fn create_spl_token(
+        &self,
+        payer: &Keypair,
+        decimals: u8,
+        name: &str,
+        symbol: &str,
+        metadata_uri: Option<&str>,
+    ) -> Result<DeployResult> {
         // Cap decimals at 9 (SPL max)
         let spl_decimals = decimals.min(9);
 
@@ -81,13 +118,359 @@ impl SolanaDeployer {
             spl_decimals,
         )?;
 
+        let (metadata_pda, _bump) = mpl_token_metadata::accounts::Metadata::find_pda(&mint_pubkey);
+        let create_metadata_ix = mpl_token_metadata::instructions::CreateMetadataAccountV3Builder::new()
+            .metadata(metadata_pda)
+            .mint(mint_pubkey)
+            .mint_authority(payer.pubkey())
+            .payer(payer.pubkey())
+            .update_authority(payer.pubkey(), true)
+            .data(mpl_token_metadata::types::DataV2 {
+                name: name.to_string(),
+                symbol: symbol.to_string(),
+                uri: metadata_uri.unwrap_or_default().to_string(),
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            })
+            .is_mutable(true)
+            .instruction();
+
+        let recent_blockhash = self
+            .client
+            .get_latest_blockhash()
+            .context("Failed to get recent blockhash")?;
+
+        let tx = Transaction::new_signed_with_payer(
+            &[create_account_ix, init_mint_ix, create_metadata_ix],
+            Some(&payer.pubkey()),
+            &[payer, &mint_keypair],
+            recent_blockhash,
+        );
+
+        let signature = self
+            .client
+            .send_and_confirm_transaction(&tx)
+            .context("Transaction failed — do you have enough SOL?")?;
+
+        let cost_sol = mint_rent as f64 / 1_000_000_000.0;
+
+        Ok(DeployResult {
+            mint_address: mint_pubkey,
+            signature,
+            cost_sol,
+            network: self.network.clone(),
+            metadata_pda,
+            token_program: spl_token::id(),
+        })
+    }
+
+    /// Create a Token-2022 mint with the `TransferFeeConfig` extension initialized, for
+    /// tokens whose EVM bytecode applies a fee on every transfer — a vanilla SPL mint has
+    /// no way to reproduce that behavior, so holders would silently lose it on migration.
+    /// Token-2022 requires every extension configured before `initialize_mint2`, and the
+    /// mint account itself must be sized up front to hold the extension's state, so the
+    /// instruction order here (create sized account → init extension → init mint) differs
+    /// from `create_spl_token`'s plain SPL path even though the rest (metadata attached in
+    /// the same transaction) stays the same.
+    pub fn create_spl_token_2022(
+        &self,
+        payer: &Keypair,
+        decimals: u8,
+        name: &str,
+        symbol: &str,
+        metadata_uri: Option<&str>,
+        fee_bps: u16,
+        max_fee: u64,
+    ) -> Result<DeployResult> {
+        let spl_decimals = decimals.min(9);
+
+        let mint_keypair = Keypair::new();
+        let mint_pubkey = mint_keypair.pubkey();
+
+        let mint_len =
+            ExtensionType::try_calculate_account_len::<Mint2022>(&[ExtensionType::TransferFeeConfig])
+                .context("Failed to size a Token-2022 mint account for the TransferFee extension")?;
+
+        let mint_rent = self
+            .client
+            .get_minimum_balance_for_rent_exemption(mint_len)
+            .context("Failed to get rent exemption — is the Solana RPC reachable?")?;
+
+        let create_account_ix = system_instruction::create_account(
+            &payer.pubkey(),
+            &mint_pubkey,
+            mint_rent,
+            mint_len as u64,
+            &spl_token_2022::id(),
+        );
+
+        let init_transfer_fee_ix = initialize_transfer_fee_config(
+            &spl_token_2022::id(),
+            &mint_pubkey,
+            Some(&payer.pubkey()), // transfer fee config authority
+            Some(&payer.pubkey()), // withdraw withheld authority
+            fee_bps,
+            max_fee,
+        )?;
+
+        let init_mint_ix = spl_token_2022::instruction::initialize_mint2(
+            &spl_token_2022::id(),
+            &mint_pubkey,
+            &payer.pubkey(),       // mint authority
+            Some(&payer.pubkey()), // freeze authority
+            spl_decimals,
+        )?;
+
+        let (metadata_pda, _bump) = mpl_token_metadata::accounts::Metadata::find_pda(&mint_pubkey);
+        let create_metadata_ix = mpl_token_metadata::instructions::CreateMetadataAccountV3Builder::new()
+            .metadata(metadata_pda)
+            .mint(mint_pubkey)
+            .mint_authority(payer.pubkey())
+            .payer(payer.pubkey())
+            .update_authority(payer.pubkey(), true)
+            .data(mpl_token_metadata::types::DataV2 {
+                name: name.to_string(),
+                symbol: symbol.to_string(),
+                uri: metadata_uri.unwrap_or_default().to_string(),
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            })
+            .is_mutable(true)
+            .instruction();
+
+        let recent_blockhash = self
+            .client
+            .get_latest_blockhash()
+            .context("Failed to get recent blockhash")?;
+
+        let tx = Transaction::new_signed_with_payer(
+            &[
+                create_account_ix,
+                init_transfer_fee_ix,
+                init_mint_ix,
+                create_metadata_ix,
+            ],
+            Some(&payer.pubkey()),
+            &[payer, &mint_keypair],
+            recent_blockhash,
+        );
+
+        let signature = self
+            .client
+            .send_and_confirm_transaction(&tx)
+            .context("Transaction failed — do you have enough SOL?")?;
+
+        let cost_sol = mint_rent as f64 / 1_000_000_000.0;
+
+        Ok(DeployResult {
+            mint_address: mint_pubkey,
+            signature,
+            cost_sol,
+            network: self.network.clone(),
+            metadata_pda,
+            token_program: spl_token_2022::id(),
+        })
+    }
+
+    /// Create the payer's associated token account for `mint` and mint `amount_raw` (in
+    /// the mint's base units) into it, in a single transaction. Returns the ATA address.
+    /// `token_program` must match whichever program the mint was created under
+    /// (`DeployResult::token_program`) — a Token-2022 mint's ATA lives at a different
+    /// address than a legacy SPL Token ATA for the same owner/mint pair, and the legacy
+    /// `mint_to` instruction targets the wrong program entirely.
+    pub fn mint_initial_supply(
+        &self,
+        payer: &Keypair,
+        mint: &Pubkey,
+        amount_raw: u64,
+        token_program: &Pubkey,
+    ) -> Result<(Pubkey, Signature)> {
+        let ata = get_associated_token_address_with_program_id(&payer.pubkey(), mint, token_program);
+
+        let create_ata_ix = create_associated_token_account(
+            &payer.pubkey(),
+            &payer.pubkey(),
+            mint,
+            token_program,
+        );
+
+        let mint_to_ix = if *token_program == spl_token_2022::id() {
+            spl_token_2022::instruction::mint_to(token_program, mint, &ata, &payer.pubkey(), &[], amount_raw)?
+        } else {
+            spl_token::instruction::mint_to(token_program, mint, &ata, &payer.pubkey(), &[], amount_raw)?
+        };
+
+        let recent_blockhash = self
+            .client
+            .get_latest_blockhash()
+            .context("Failed to get recent blockhash")?;
+
+        let tx = Transaction::new_signed_with_payer(
+            &[create_ata_ix, mint_to_ix],
+            Some(&payer.pubkey()),
+            &[payer],
+            recent_blockhash,
+        );
+
+        let signature = self
+            .client
+            .send_and_confirm_transaction(&tx)
+            .context("Failed to mint initial supply")?;
+
+        Ok((ata, signature))
+    }
+
+    /// Set the mint authority to `None`, permanently fixing the token's supply.
+    /// `token_program` must match the mint's owning program — see `mint_initial_supply`.
+    pub fn revoke_mint_authority(
+        &self,
+        payer: &Keypair,
+        mint: &Pubkey,
+        token_program: &Pubkey,
+    ) -> Result<Signature> {
+        let revoke_ix = if *token_program == spl_token_2022::id() {
+            spl_token_2022::instruction::set_authority(
+                token_program,
+                mint,
+                None,
+                AuthorityType::MintTokens,
+                &payer.pubkey(),
+                &[],
+            )?
+        } else {
+            spl_token::instruction::set_authority(
+                token_program,
+                mint,
+                None,
+                AuthorityType::MintTokens,
+                &payer.pubkey(),
+                &[],
+            )?
+        };
+
+        let recent_blockhash = self
+            .client
+            .get_latest_blockhash()
+            .context("Failed to get recent blockhash")?;
+
+        let tx = Transaction::new_signed_with_payer(
+            &[revoke_ix],
+            Some(&payer.pubkey()),
+            &[payer],
+            recent_blockhash,
+        );
+
+        self.client
+            .send_and_confirm_transaction(&tx)
+            .context("Failed to revoke mint authority")
+    }
+
+    /// Mint a sized Metaplex collection NFT: a 0-decimal mint with supply 1, its metadata
+    /// account (`DataV2`, sized via `CollectionDetails::V1`), and a master edition capping
+    /// it at `max_supply: 0` so it stays a unique, non-fungible collection marker rather
+    /// than something a wallet could print copies of. Individual collection items are
+    /// minted and verified against this mint separately — see the CLI's post-deploy steps.
+    pub fn create_collection_nft(
+        &self,
+        payer: &Keypair,
+        name: &str,
+        symbol: &str,
+        base_uri: &str,
+        max_size: u64,
+    ) -> Result<DeployResult> {
+        let mint_keypair = Keypair::new();
+        let mint_pubkey = mint_keypair.pubkey();
+
+        let mint_rent = self
+            .client
+            .get_minimum_balance_for_rent_exemption(Mint::LEN)
+            .context("Failed to get rent exemption — is the Solana RPC reachable?")?;
+
+        let create_account_ix = system_instruction::create_account(
+            &payer.pubkey(),
+            &mint_pubkey,
+            mint_rent,
+            Mint::LEN as u64,
+            &spl_token::id(),
+        );
+
+        let init_mint_ix = spl_token::instruction::initialize_mint2(
+            &spl_token::id(),
+            &mint_pubkey,
+            &payer.pubkey(),
+            Some(&payer.pubkey()),
+            0, // NFTs have no fractional units
+        )?;
+
+        let ata = get_associated_token_address(&payer.pubkey(), &mint_pubkey);
+        let create_ata_ix = create_associated_token_account(
+            &payer.pubkey(),
+            &payer.pubkey(),
+            &mint_pubkey,
+            &spl_token::id(),
+        );
+
+        let mint_to_ix = spl_token::instruction::mint_to(
+            &spl_token::id(),
+            &mint_pubkey,
+            &ata,
+            &payer.pubkey(),
+            &[],
+            1, // exactly one unit — the collection marker itself is non-fungible
+        )?;
+
+        let (metadata_pda, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint_pubkey);
+        let (master_edition_pda, _) =
+            mpl_token_metadata::accounts::MasterEdition::find_pda(&mint_pubkey);
+
+        let create_metadata_ix = mpl_token_metadata::instructions::CreateMetadataAccountV3Builder::new()
+            .metadata(metadata_pda)
+            .mint(mint_pubkey)
+            .mint_authority(payer.pubkey())
+            .payer(payer.pubkey())
+            .update_authority(payer.pubkey(), true)
+            .data(mpl_token_metadata::types::DataV2 {
+                name: name.to_string(),
+                symbol: symbol.to_string(),
+                uri: base_uri.to_string(),
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            })
+            .is_mutable(true)
+            .collection_details(mpl_token_metadata::types::CollectionDetails::V1 { size: max_size })
+            .instruction();
+
+        let create_master_edition_ix =
+            mpl_token_metadata::instructions::CreateMasterEditionV3Builder::new()
+                .edition(master_edition_pda)
+                .mint(mint_pubkey)
+                .update_authority(payer.pubkey())
+                .mint_authority(payer.pubkey())
+                .payer(payer.pubkey())
+                .metadata(metadata_pda)
+                .max_supply(0) // a unique edition, not a printable template
+                .instruction();
+
         let recent_blockhash = self
             .client
             .get_latest_blockhash()
             .context("Failed to get recent blockhash")?;
 
         let tx = Transaction::new_signed_with_payer(
-            &[create_account_ix, init_mint_ix],
+            &[
+                create_account_ix,
+                init_mint_ix,
+                create_ata_ix,
+                mint_to_ix,
+                create_metadata_ix,
+                create_master_edition_ix,
+            ],
             Some(&payer.pubkey()),
             &[payer, &mint_keypair],
             recent_blockhash,
@@ -105,6 +488,8 @@ impl SolanaDeployer {
             signature,
             cost_sol,
             network: self.network.clone(),
+            metadata_pda,
+            token_program: spl_token::id(),
         })
     }
 
@@ -114,6 +499,57 @@ impl SolanaDeployer {
         Ok(lamports as f64 / 1_000_000_000.0)
     }
 
+    /// Request SOL from the cluster's faucet (devnet/testnet only) and wait for it to
+    /// land. `requestAirdrop` returns as soon as the faucet accepts the request, not once
+    /// the transaction is confirmed, so we poll the balance afterward rather than trusting
+    /// the request alone.
+    pub fn request_airdrop(&self, pubkey: &Pubkey, sol: f64) -> Result<()> {
+        if self.is_mainnet() {
+            bail!("Can't request a faucet airdrop on mainnet — fund the wallet manually");
+        }
+
+        let balance_before = self.get_balance(pubkey).unwrap_or(0.0);
+        let lamports = (sol * 1_000_000_000.0) as u64;
+
+        let mut last_err = None;
+        for attempt in 1..=AIRDROP_REQUEST_RETRIES {
+            match self.client.request_airdrop(pubkey, lamports) {
+                Ok(_signature) => {
+                    last_err = None;
+                    break;
+                }
+                Err(e) => {
+                    last_err = Some(e);
+                    if attempt < AIRDROP_REQUEST_RETRIES {
+                        thread::sleep(AIRDROP_REQUEST_RETRY_DELAY);
+                    }
+                }
+            }
+        }
+        if let Some(e) = last_err {
+            bail!(
+                "Faucet unavailable after {} attempts — is the {} faucet reachable? {}",
+                AIRDROP_REQUEST_RETRIES,
+                self.network,
+                e
+            );
+        }
+
+        let target_balance = balance_before + sol - 0.001; // epsilon for float rounding
+        let deadline = Instant::now() + AIRDROP_CONFIRM_TIMEOUT;
+        while Instant::now() < deadline {
+            if self.get_balance(pubkey)? >= target_balance {
+                return Ok(());
+            }
+            thread::sleep(AIRDROP_POLL_INTERVAL);
+        }
+
+        bail!(
+            "Airdrop requested but funds hadn't landed after {:?} — try again or fund manually",
+            AIRDROP_CONFIRM_TIMEOUT
+        )
+    }
+
     pub fn is_mainnet(&self) -> bool {
         self.network == "mainnet"
     }
@@ -1,7 +1,9 @@
+use super::compatibility::{CompatibilityIssue, IssueSeverity};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Proxy contract types following EIP standards
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum ProxyType {
     /// EIP-1967 transparent proxy
     Eip1967,
@@ -28,7 +30,7 @@ impl std::fmt::Display for ProxyType {
 }
 
 /// Result of analyzing contract bytecode
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct BytecodeAnalysis {
     pub size_bytes: usize,
     pub is_proxy: bool,
@@ -37,6 +39,19 @@ pub struct BytecodeAnalysis {
     pub has_selfdestruct: bool,
     pub has_delegatecall: bool,
     pub has_fee_pattern: bool,
+    /// Basis-point transfer fee rate, when one could be determined. Left `None` even
+    /// when `has_fee_pattern` is true: the linear-sweep disassembler recognizes the
+    /// selector shape of a fee setter/getter, but pulling the actual constant back out
+    /// means tracing a storage read through the `transfer` control-flow path, which
+    /// this disassembler doesn't attempt. `SolanaDeployer::create_spl_token_2022`
+    /// falls back to `--transfer-fee-bps` (default 0) when this is `None`.
+    pub fee_bps: Option<u16>,
+    /// Maximum fee charged per transfer, in the token's base units — same caveat as
+    /// `fee_bps` above.
+    pub max_fee: Option<u64>,
+    /// A `cap()` selector is present in the dispatch table — the OpenZeppelin
+    /// `ERC20Capped` tell, used to classify the token's `SupplyModel`.
+    pub has_cap: bool,
     pub complexity: BytecodeComplexity,
 }
 
@@ -50,13 +65,16 @@ impl Default for BytecodeAnalysis {
             has_selfdestruct: false,
             has_delegatecall: false,
             has_fee_pattern: false,
+            fee_bps: None,
+            max_fee: None,
+            has_cap: false,
             complexity: BytecodeComplexity::Simple,
         }
     }
 }
 
 /// Contract complexity rating based on bytecode size
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum BytecodeComplexity {
     /// Less than 5KB
     Simple,
@@ -77,15 +95,19 @@ impl std::fmt::Display for BytecodeComplexity {
 }
 
 /// Token holder distribution data
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct HolderData {
     pub top_holders: Vec<HolderInfo>,
     pub top_10_concentration: f64,
     pub total_holders: Option<u64>,
+    /// `(from_block, to_block)` actually scanned, set only when this data was
+    /// reconstructed from `Transfer` logs rather than an Etherscan-style holder-list API —
+    /// a windowed reconstruction, not a full-history balance snapshot, and labeled as such.
+    pub scanned_window: Option<(u64, u64)>,
 }
 
 /// Individual holder information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct HolderInfo {
     pub address: String,
     pub balance: String,
@@ -93,7 +115,7 @@ pub struct HolderInfo {
 }
 
 /// Risk rating categories
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum RiskRating {
     /// Score 0-33: Safe for migration
     Low,
@@ -114,7 +136,7 @@ impl std::fmt::Display for RiskRating {
 }
 
 /// Individual risk score components
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RiskComponents {
     /// 0-20 points: >8 decimals adds complexity
     pub decimal_handling: u8,
@@ -141,16 +163,19 @@ impl Default for RiskComponents {
 }
 
 /// Composite risk score for migration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RiskScore {
     /// Total score 0-100 (lower = safer)
     pub total: u8,
     pub rating: RiskRating,
     pub components: RiskComponents,
+    /// The concrete consequence behind `components.decimal_handling`'s 0-20 bucket — see
+    /// `TrimmingAnalysis`.
+    pub trimming: TrimmingAnalysis,
 }
 
 impl RiskScore {
-    pub fn from_components(components: RiskComponents) -> Self {
+    pub fn from_components(components: RiskComponents, trimming: TrimmingAnalysis) -> Self {
         let total = components.decimal_handling
             + components.token_features
             + components.bytecode_complexity
@@ -171,6 +196,118 @@ impl RiskScore {
             total,
             rating,
             components,
+            trimming,
         }
     }
 }
+
+/// Concrete consequence of NTT's decimal normalization, behind `RiskComponents`'s coarse
+/// 0-20 `decimal_handling` bucket. NTT always normalizes a transfer to
+/// `min(8, source_decimals, dest_decimals)` — since `SolanaDeployer` caps the Solana
+/// mint's own decimals at 9 (itself >= 8 whenever the source has more), the source side's
+/// decimals are what actually decide how much precision a transfer loses.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TrimmingAnalysis {
+    pub source_decimals: u8,
+    /// `min(8, source_decimals)` — NTT's normalized decimal for this token
+    pub normalized_decimals: u8,
+    /// Low-order decimal digits dropped per transfer
+    pub dropped_digits: u8,
+    /// `10^dropped_digits` — how many source-chain base units collapse into one
+    /// normalized unit
+    pub trim_factor: u128,
+    /// Smallest source-chain amount, in base units, that survives normalization without
+    /// rounding to zero and becoming unbridgeable
+    pub smallest_bridgeable_unit: u128,
+    /// Worked example for display, e.g. "any transfer under 10,000,000,000 base units
+    /// rounds to zero and cannot be bridged"
+    pub example: String,
+}
+
+impl TrimmingAnalysis {
+    /// Compute the trimming consequence for a token with `source_decimals` decimal places.
+    pub fn calculate(source_decimals: u8) -> Self {
+        let normalized_decimals = source_decimals.min(8);
+        let dropped_digits = source_decimals.saturating_sub(normalized_decimals);
+        // `decimals()` is an unclamped uint8 read straight off the analyzed contract, so a
+        // hostile or malformed token can report up to 255 — saturate rather than let
+        // `10^dropped_digits` overflow u128 (at dropped_digits >= 39).
+        let trim_factor = 10u128.saturating_pow(dropped_digits as u32);
+        let smallest_bridgeable_unit = trim_factor;
+
+        let example = if dropped_digits == 0 {
+            "No trimming — every base unit of this token survives NTT normalization."
+                .to_string()
+        } else {
+            format!(
+                "Any transfer below {} base units ({} dropped digit{}) rounds to zero and \
+                 cannot be bridged; every transfer above that loses up to {} base units to \
+                 rounding.",
+                smallest_bridgeable_unit,
+                dropped_digits,
+                if dropped_digits == 1 { "" } else { "s" },
+                trim_factor - 1,
+            )
+        };
+
+        Self {
+            source_decimals,
+            normalized_decimals,
+            dropped_digits,
+            trim_factor,
+            smallest_bridgeable_unit,
+            example,
+        }
+    }
+}
+
+/// Result of comparing a token's source-chain locked balance against its Solana-side
+/// minted supply — the live counterpart to the static `REBASING`/`FEE_ON_TRANSFER` issues
+/// `CompatibilityChecker` flags at analysis time. Produced by `SupplyReconciler::reconcile`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReconciliationReport {
+    /// Locked balance on the source chain, in source-chain raw units
+    pub locked: u128,
+    /// Minted supply on Solana, in Solana raw units
+    pub minted: u128,
+    /// `locked` trimmed to Solana decimals — what `minted` should equal if nothing desynced
+    pub expected: u128,
+    /// `|expected - minted|`, in Solana raw units
+    pub drift: u128,
+    pub within_tolerance: bool,
+    /// The compatibility issue code (e.g. `REBASING`, `FEE_ON_TRANSFER`) already on record
+    /// for this token that best explains an out-of-tolerance drift, if any matched.
+    pub likely_cause: Option<String>,
+}
+
+impl ReconciliationReport {
+    /// Surface an out-of-tolerance drift as a `CompatibilityIssue`, reusing the same
+    /// severity/reporting vehicle the rest of the checker uses rather than inventing a
+    /// parallel one just for this live check.
+    pub fn as_issue(&self) -> Option<CompatibilityIssue> {
+        if self.within_tolerance {
+            return None;
+        }
+
+        let cause = self
+            .likely_cause
+            .as_deref()
+            .map(|code| format!(" Likely cause: {code} (see that issue for details)."))
+            .unwrap_or_default();
+
+        Some(CompatibilityIssue {
+            severity: IssueSeverity::Error,
+            code: "SUPPLY_DRIFT".to_string(),
+            title: "Supply Conservation Violated".to_string(),
+            description: format!(
+                "Locked balance on the source chain implies {} minted units on Solana, \
+                 but {} are actually minted — a drift of {}.{}",
+                self.expected, self.minted, self.drift, cause
+            ),
+            recommendation: "Halt bridging immediately and reconcile the discrepancy before \
+                any further transfers. If a rebasing or fee-on-transfer mechanism is the \
+                cause, the token is not safely bridgeable in locking mode."
+                .to_string(),
+        })
+    }
+}
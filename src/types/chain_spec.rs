@@ -0,0 +1,119 @@
+use serde::Deserialize;
+
+/// Configuration for a single EVM chain: its id, RPC endpoints, and the bits used by the
+/// bridge/Wormhole integration. Loaded from a registry file rather than baked into a
+/// `match` arm, so adding an RPC override (or, for the fields `EvmAnalyzer` actually
+/// consults, a new endpoint) doesn't require recompiling.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChainSpec {
+    pub name: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    pub chain_id: u64,
+    pub rpc_urls: Vec<String>,
+    #[serde(default)]
+    pub explorer_api_base: Option<String>,
+    #[serde(default)]
+    pub wormhole_chain_id: Option<u16>,
+    #[serde(default)]
+    pub token_bridge_address: Option<String>,
+}
+
+impl ChainSpec {
+    fn matches(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name) || self.aliases.iter().any(|a| a.eq_ignore_ascii_case(name))
+    }
+
+    /// The first configured RPC endpoint, used when the CLI doesn't override `--rpc-url`
+    pub fn default_rpc_url(&self) -> Option<&str> {
+        self.rpc_urls.first().map(|s| s.as_str())
+    }
+}
+
+/// Bundled defaults: the seven chains `Chain` already knows about, so the registry is a
+/// superset of the hardcoded enum rather than a second, divergent source of truth
+const BUILTIN_CHAINS_JSON: &str = include_str!("chains.json");
+
+/// Default location for a user-extensible chain registry, so a testnet, new L2, or
+/// private fork's RPC endpoint can be added (or an existing one overridden) without
+/// recompiling — see `ChainSpec`.
+const USER_CHAINS_PATH: &str = "~/.config/daybreak/chains.json";
+
+/// Registry of `ChainSpec`s: the bundled defaults, extended (or overridden, by matching
+/// name/alias) by entries from a user-supplied JSON file.
+#[derive(Debug, Clone, Default)]
+pub struct ChainRegistry {
+    specs: Vec<ChainSpec>,
+}
+
+impl ChainRegistry {
+    /// Load the bundled defaults, merged with the user registry file if it exists and
+    /// parses. A missing or malformed override file silently falls back to defaults
+    /// rather than failing analysis.
+    pub fn load() -> Self {
+        let mut specs: Vec<ChainSpec> =
+            serde_json::from_str(BUILTIN_CHAINS_JSON).unwrap_or_default();
+
+        if let Some(path) = Self::user_path() {
+            if let Ok(contents) = std::fs::read_to_string(path) {
+                if let Ok(extra) = serde_json::from_str::<Vec<ChainSpec>>(&contents) {
+                    for spec in extra {
+                        specs.retain(|existing| !existing.matches(&spec.name));
+                        specs.push(spec);
+                    }
+                }
+            }
+        }
+
+        Self { specs }
+    }
+
+    fn user_path() -> Option<std::path::PathBuf> {
+        match std::env::var("HOME") {
+            Ok(home) => Some(std::path::PathBuf::from(
+                USER_CHAINS_PATH.replacen('~', &home, 1),
+            )),
+            Err(_) => Some(std::path::PathBuf::from(USER_CHAINS_PATH)),
+        }
+    }
+
+    /// Resolve a chain name or alias (case-insensitive) against the registry — e.g. to
+    /// look up a testnet or private fork that has no `Chain` enum variant
+    pub fn resolve(&self, name: &str) -> Option<&ChainSpec> {
+        self.specs.iter().find(|spec| spec.matches(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_chains_load() {
+        let registry = ChainRegistry::load();
+        let eth = registry.resolve("ethereum").expect("ethereum is bundled");
+        assert_eq!(eth.chain_id, 1);
+        assert_eq!(eth.default_rpc_url(), Some("https://eth.llamarpc.com"));
+    }
+
+    #[test]
+    fn test_resolve_is_case_insensitive_and_matches_aliases() {
+        let registry = ChainRegistry::load();
+        assert!(registry.resolve("ETH").is_some());
+        assert!(registry.resolve("Matic").is_some());
+    }
+
+    #[test]
+    fn test_resolve_unknown_chain_returns_none() {
+        let registry = ChainRegistry::load();
+        assert!(registry.resolve("sepolia").is_none());
+    }
+
+    #[test]
+    fn test_missing_user_file_falls_back_to_defaults() {
+        // `load()` always checks ~/.config/daybreak/chains.json; in this sandboxed test
+        // environment it won't exist, so this just exercises the fallback path.
+        let registry = ChainRegistry::load();
+        assert!(registry.resolve("bsc").is_some());
+    }
+}
@@ -1,7 +1,8 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Severity level for compatibility issues
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum IssueSeverity {
     /// Informational only, no action needed
     Info,
@@ -22,7 +23,7 @@ impl std::fmt::Display for IssueSeverity {
 }
 
 /// A specific compatibility issue detected
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CompatibilityIssue {
     pub severity: IssueSeverity,
     pub code: String,
@@ -32,7 +33,7 @@ pub struct CompatibilityIssue {
 }
 
 /// NTT transfer mode
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum NttMode {
     /// Lock tokens on source, mint on destination
     Locking,
@@ -49,18 +50,90 @@ impl std::fmt::Display for NttMode {
     }
 }
 
+/// How a token's total supply can change after deployment — governs whether `Locking`
+/// mode is safe: a token whose owner can mint without bound breaks the 1:1 lock/mint
+/// invariant NTT relies on, since the source-chain supply the Solana side is meant to
+/// mirror is no longer fixed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum SupplyModel {
+    /// No mint capability reachable, or mint authority has been renounced — supply is fixed.
+    Fixed,
+    /// Mintable, but bounded by an on-chain `cap()` (OpenZeppelin `ERC20Capped`).
+    Capped,
+    /// Mintable with no on-chain cap, by an owner/role that hasn't been renounced.
+    Unlimited,
+}
+
+impl std::fmt::Display for SupplyModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SupplyModel::Fixed => write!(f, "Fixed"),
+            SupplyModel::Capped => write!(f, "Capped"),
+            SupplyModel::Unlimited => write!(f, "Unlimited"),
+        }
+    }
+}
+
 /// Overall NTT compatibility assessment
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CompatibilityResult {
     pub is_compatible: bool,
     pub recommended_mode: NttMode,
     pub issues: Vec<CompatibilityIssue>,
     pub decimal_trimming_required: bool,
     pub solana_decimals: u8,
+    pub supply_model: SupplyModel,
+    pub governance: GovernanceProfile,
+}
+
+/// Who ultimately holds a token's privileged powers, classified from the resolved
+/// controller's own on-chain footprint rather than assumed from its mere presence — an
+/// EOA, a Gnosis-Safe-style multisig, or an OpenZeppelin `TimelockController`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ControllerType {
+    /// A resolved controller with no code of its own — an externally-owned account.
+    Eoa,
+    /// A resolved controller whose bytecode matches a Gnosis-Safe-style multisig.
+    Multisig,
+    /// A resolved controller whose bytecode matches an OpenZeppelin `TimelockController`.
+    Timelock,
+    /// No controller could be resolved, or its bytecode matched none of the above.
+    Unknown,
+}
+
+impl std::fmt::Display for ControllerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ControllerType::Eoa => write!(f, "EOA"),
+            ControllerType::Multisig => write!(f, "Multisig"),
+            ControllerType::Timelock => write!(f, "Timelock"),
+            ControllerType::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// Who controls a token's privileged functions (mint, pause, upgrade) and how — the
+/// centralization-risk counterpart to the individual `has_mint`/`has_pause`/`is_upgradeable`
+/// capability flags, which say a power exists but not who holds it or how accountable
+/// they are. `controller` is `None` when no owner/role admin could be resolved (e.g. a
+/// hardcoded minter, or an origin chain `check_governance` doesn't resolve on).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct GovernanceProfile {
+    pub controller: Option<String>,
+    pub controller_type: ControllerType,
+    pub controls_mint: bool,
+    pub controls_pause: bool,
+    pub controls_upgrade: bool,
+}
+
+impl Default for ControllerType {
+    fn default() -> Self {
+        ControllerType::Unknown
+    }
 }
 
 /// How a token is bridged to Solana — the key Sunrise distinction
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum BridgeType {
     /// Wormhole Portal wrapped token (synthetic wToken)
     Portal,
@@ -80,14 +153,81 @@ impl std::fmt::Display for BridgeType {
     }
 }
 
+/// Result of verifying a token's Wormhole VAA (Verified Action Approval) rather than just
+/// noting that some cross-chain activity exists. `verified` requires both a recognized
+/// `guardian_set_index` and a quorum of recovered signatures matching it; `attested_decimals`
+/// is populated only for a token bridge attestation (VAA payload type 2) and should be
+/// cross-checked against the scanned token's own `decimals` to catch trimming mismatches.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct AttestationStatus {
+    pub verified: bool,
+    pub guardian_set_index: Option<u32>,
+    pub signatures_present: u32,
+    pub quorum_met: bool,
+    pub attested_decimals: Option<u8>,
+    /// Wormhole chain id of the VAA's emitter — who actually produced this message, not
+    /// just who's asking about it
+    pub emitter_chain: Option<u16>,
+    /// The emitter's 32-byte address on its native chain, left-padded if shorter
+    pub emitter_address: Option<[u8; 32]>,
+    /// The emitter's per-chain, monotonically increasing message sequence number
+    pub sequence: Option<u64>,
+}
+
+/// Result of an ERC-721 collection's NTT/Solana migration compatibility check. NFTs have
+/// no decimals and no `NttMode` — each item is unique and non-fungible — so this doesn't
+/// reuse `CompatibilityResult`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NftCompatibilityResult {
+    pub is_compatible: bool,
+    pub issues: Vec<CompatibilityIssue>,
+}
+
 /// Existing bridge detection results
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct BridgeStatus {
     pub already_on_solana: bool,
     pub solana_address: Option<String>,
     pub bridge_provider: Option<String>,
     pub bridge_type: Option<BridgeType>,
-    pub wormhole_attested: bool,
+    /// Whether `already_on_solana` rests on something cryptographically or structurally
+    /// trustworthy rather than mere presence: a curated, hand-verified address pair, or a
+    /// live Wormhole VAA with guardian quorum (`wormhole_attestation.quorum_met`). A token
+    /// sharing a symbol with a real bridged asset, but not actually a guardian-attested
+    /// match, reports `already_on_solana` but `bridge_verified: false`.
+    pub bridge_verified: bool,
+    pub wormhole_attestation: AttestationStatus,
+    /// The Token Bridge's own record of where a derived wrapped mint (`solana_address`)
+    /// actually came from — read from its wrapped-asset-meta PDA. Lets a caller confirm
+    /// the existing wrapped mint really is this token before treating `already_on_solana`
+    /// as a reason to short-circuit a deploy, rather than trusting the PDA derivation alone.
+    pub wrapped_origin: Option<WrappedAssetOrigin>,
+}
+
+/// A wrapped mint's origin, as recorded by the Token Bridge program's wrapped-asset-meta
+/// account (seeds `["meta", mint]`) — not re-derived or guessed, read back from the
+/// account the bridge program itself wrote.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct WrappedAssetOrigin {
+    /// Wormhole chain id the wrapped mint originated from
+    pub chain: u16,
+    /// The original 32-byte token address on its native chain, left-padded if shorter
+    pub token_address: [u8; 32],
+    pub original_decimals: u8,
+}
+
+impl WrappedAssetOrigin {
+    /// Render `token_address` as a `0x`-prefixed hex string, trimmed to the trailing 20
+    /// bytes for EVM origins (left-padding makes the leading 12 bytes always zero there) —
+    /// the form a user can actually look up on a block explorer.
+    pub fn token_address_hex(&self) -> String {
+        let bytes = if self.token_address[..12].iter().all(|b| *b == 0) {
+            &self.token_address[12..]
+        } else {
+            &self.token_address[..]
+        };
+        format!("0x{}", bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>())
+    }
 }
 
 #[cfg(test)]
@@ -108,7 +248,14 @@ mod tests {
         assert!(status.solana_address.is_none());
         assert!(status.bridge_provider.is_none());
         assert!(status.bridge_type.is_none());
-        assert!(!status.wormhole_attested);
+        assert!(!status.wormhole_attestation.verified);
+    }
+
+    #[test]
+    fn test_supply_model_display() {
+        assert_eq!(SupplyModel::Fixed.to_string(), "Fixed");
+        assert_eq!(SupplyModel::Capped.to_string(), "Capped");
+        assert_eq!(SupplyModel::Unlimited.to_string(), "Unlimited");
     }
 
     #[test]
@@ -131,7 +278,9 @@ mod tests {
             solana_address: Some("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string()),
             bridge_provider: Some("Native (USDC)".to_string()),
             bridge_type: Some(BridgeType::Native),
-            wormhole_attested: false,
+            bridge_verified: true,
+            wormhole_attestation: AttestationStatus::default(),
+            wrapped_origin: None,
         };
         let json = serde_json::to_string(&status).unwrap();
         assert!(json.contains("\"already_on_solana\":true"));
@@ -143,6 +292,24 @@ mod tests {
         assert!(deserialized.already_on_solana);
     }
 
+    #[test]
+    fn test_controller_type_display() {
+        assert_eq!(ControllerType::Eoa.to_string(), "EOA");
+        assert_eq!(ControllerType::Multisig.to_string(), "Multisig");
+        assert_eq!(ControllerType::Timelock.to_string(), "Timelock");
+        assert_eq!(ControllerType::Unknown.to_string(), "Unknown");
+    }
+
+    #[test]
+    fn test_governance_profile_default() {
+        let profile = GovernanceProfile::default();
+        assert!(profile.controller.is_none());
+        assert_eq!(profile.controller_type, ControllerType::Unknown);
+        assert!(!profile.controls_mint);
+        assert!(!profile.controls_pause);
+        assert!(!profile.controls_upgrade);
+    }
+
     #[test]
     fn test_ntt_mode_pairing_rule() {
         // NTT mode pairing: if source is Locking, destination MUST be Burning
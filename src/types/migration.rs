@@ -1,7 +1,9 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Available migration methods to Solana
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum MigrationMethod {
     /// Wormhole Native Token Transfers via Sunrise
     NttSunrise,
@@ -9,6 +11,8 @@ pub enum MigrationMethod {
     NeonEvm,
     /// Full native Solana rewrite
     NativeRewrite,
+    /// Wormhole NFT Bridge (per-token wrapped mint + Metaplex metadata)
+    NftBridge,
 }
 
 impl std::fmt::Display for MigrationMethod {
@@ -17,12 +21,13 @@ impl std::fmt::Display for MigrationMethod {
             MigrationMethod::NttSunrise => write!(f, "NTT (Sunrise)"),
             MigrationMethod::NeonEvm => write!(f, "Neon EVM"),
             MigrationMethod::NativeRewrite => write!(f, "Native Rewrite"),
+            MigrationMethod::NftBridge => write!(f, "NFT Bridge (Wormhole)"),
         }
     }
 }
 
 /// How suitable a migration path is for this token
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum Feasibility {
     /// Best option for this token
     Recommended,
@@ -43,7 +48,7 @@ impl std::fmt::Display for Feasibility {
 }
 
 /// A potential migration path with analysis
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MigrationPath {
     pub method: MigrationMethod,
     pub feasibility: Feasibility,
@@ -76,6 +81,35 @@ pub struct MigrationStep {
 pub struct NttDeploymentConfig {
     pub network: NetworkConfig,
     pub tokens: TokensConfig,
+    pub limits: NttRateLimitsConfig,
+    /// NTT peer chains, keyed by Wormhole chain id rather than name — the id is what the
+    /// NTT manager program actually keys its peer registry by, so this maps directly onto
+    /// `ntt set-peer`/`ntt push` input instead of needing a name-to-id lookup downstream
+    pub peers: HashMap<u16, NttPeerConfig>,
+}
+
+/// Token-bucket rate limits for the NTT manager, mirroring the model
+/// `volume::RateLimitRecommendation` already uses: a capacity that refills linearly back
+/// to full over `refill_window_secs`, protecting the bridge against drain attacks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NttRateLimitsConfig {
+    /// Outbound capacity (tokens) leaving the source chain
+    pub outbound_capacity: u64,
+    /// Inbound capacity (tokens) accepted from each peer, keyed by that peer's Wormhole
+    /// chain id
+    pub inbound_capacity_per_chain: HashMap<u16, u64>,
+    /// Seconds for a drained bucket to refill back to full capacity
+    pub refill_window_secs: u64,
+}
+
+/// A single NTT peer chain, keyed by Wormhole chain id in `NttDeploymentConfig::peers`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NttPeerConfig {
+    pub chain_name: String,
+    /// NTT manager address on the peer chain — unknown until `ntt deploy` actually runs,
+    /// so this is filled in by hand (or a later `ntt push`) rather than guessed at here
+    pub manager_address: Option<String>,
+    pub inbound_rate_limit: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -103,14 +137,32 @@ pub struct DestinationTokenConfig {
 }
 
 /// Full analysis result combining all data
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FullAnalysis {
     pub token: super::TokenInfo,
     pub capabilities: super::TokenCapabilities,
+    pub access_control: super::AccessControl,
     pub bytecode: super::BytecodeAnalysis,
     pub compatibility: super::CompatibilityResult,
     pub bridge_status: super::BridgeStatus,
     pub risk_score: super::RiskScore,
     pub holder_data: Option<super::HolderData>,
     pub rate_limit: Option<crate::analyzers::volume::RateLimitRecommendation>,
+    /// Never serialized (see `#[serde(skip)]`) — it's a transient, RPC-price-dependent
+    /// estimate computed per-invocation, not part of the token's analysis state. Skipped
+    /// from the schema for the same reason rather than asserting a `CostEstimate` shape
+    /// nothing will ever actually emit.
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub migration_cost: Option<crate::report::cost_estimate::CostEstimate>,
+}
+
+/// Analysis result for an ERC-721/ERC-1155 collection. Parallel to `FullAnalysis` rather
+/// than a variant of it — an NFT collection has no decimals, bytecode, or holder
+/// distribution analysis to report, and bridges per-token instead of as a single status.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NftScanResult {
+    pub collection: super::NftCollectionInfo,
+    pub compatibility: super::NftCompatibilityResult,
+    pub migration_path: MigrationPath,
 }
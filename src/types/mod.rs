@@ -1,9 +1,11 @@
 pub mod token;
 pub mod analysis;
+pub mod chain_spec;
 pub mod compatibility;
 pub mod migration;
 
 pub use token::*;
 pub use analysis::*;
+pub use chain_spec::*;
 pub use compatibility::*;
 pub use migration::*;
@@ -1,7 +1,9 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-/// Supported EVM chains
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Supported token origin chains — the EVM chains `EvmAnalyzer` handles directly, plus
+/// `Solana` for SPL-originated tokens (see `SourceChainAnalyzer`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum Chain {
     Ethereum,
@@ -11,10 +13,16 @@ pub enum Chain {
     Base,
     Avalanche,
     Bsc,
+    Solana,
 }
 
 impl Chain {
-    /// Returns the chain ID for EVM networks
+    /// Returns true for chains analyzed via `EvmAnalyzer` (RPC, bytecode, EIP standards)
+    pub fn is_evm(&self) -> bool {
+        !matches!(self, Chain::Solana)
+    }
+
+    /// Returns the chain ID for EVM networks. Solana has no equivalent concept.
     pub fn chain_id(&self) -> u64 {
         match self {
             Chain::Ethereum => 1,
@@ -24,6 +32,7 @@ impl Chain {
             Chain::Base => 8453,
             Chain::Avalanche => 43114,
             Chain::Bsc => 56,
+            Chain::Solana => 0,
         }
     }
 
@@ -37,6 +46,18 @@ impl Chain {
             Chain::Base => "https://base.llamarpc.com",
             Chain::Avalanche => "https://avalanche.llamarpc.com",
             Chain::Bsc => "https://bsc.llamarpc.com",
+            Chain::Solana => "https://api.mainnet-beta.solana.com",
+        }
+    }
+
+    /// CoinGecko coin id for the chain's native gas token
+    pub fn native_coingecko_id(&self) -> &'static str {
+        match self {
+            Chain::Ethereum | Chain::Arbitrum | Chain::Optimism | Chain::Base => "ethereum",
+            Chain::Polygon => "matic-network",
+            Chain::Avalanche => "avalanche-2",
+            Chain::Bsc => "binancecoin",
+            Chain::Solana => "solana",
         }
     }
 
@@ -49,6 +70,7 @@ impl Chain {
             Chain::Base => "Base",
             Chain::Avalanche => "Avalanche",
             Chain::Bsc => "BSC",
+            Chain::Solana => "Solana",
         }
     }
 }
@@ -71,13 +93,14 @@ impl std::str::FromStr for Chain {
             "base" => Ok(Chain::Base),
             "avalanche" | "avax" => Ok(Chain::Avalanche),
             "bsc" | "bnb" => Ok(Chain::Bsc),
+            "solana" | "sol" => Ok(Chain::Solana),
             _ => anyhow::bail!("Unknown chain: {}", s),
         }
     }
 }
 
 /// Basic ERC-20 token information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TokenInfo {
     pub address: String,
     pub chain: Chain,
@@ -87,8 +110,22 @@ pub struct TokenInfo {
     pub total_supply: String,
 }
 
+/// Basic ERC-721 collection information. `total_supply` is only known when the
+/// collection implements `ERC721Enumerable`; `base_uri` is best-effort (see
+/// `NftAnalyzer::get_collection_info`) since `baseURI()` isn't part of the ERC-721
+/// standard itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NftCollectionInfo {
+    pub address: String,
+    pub chain: Chain,
+    pub name: String,
+    pub symbol: String,
+    pub base_uri: Option<String>,
+    pub total_supply: Option<u64>,
+}
+
 /// Detected token capabilities based on function selectors
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct TokenCapabilities {
     pub has_mint: bool,
     pub has_burn: bool,
@@ -96,4 +133,58 @@ pub struct TokenCapabilities {
     pub has_blacklist: bool,
     pub has_permit: bool,
     pub is_upgradeable: bool,
+    /// `approve()` is present with neither `increaseAllowance()` nor `decreaseAllowance()`
+    /// — the classic ERC-20 approve front-running race has no safer alternative to use.
+    pub has_unmitigated_approve_race: bool,
+    /// The contract calls out to a transfer hook — an ERC-1820 registry lookup (the
+    /// standard ERC-777 `tokensToSend`/`tokensReceived` dispatch), or exposes those
+    /// selectors itself. NTT's locking mode calls `transferFrom` into the manager; a hook
+    /// that fires mid-transfer lets the recipient re-enter the manager before the lock
+    /// completes.
+    pub has_transfer_hook: bool,
+}
+
+/// Who can administer the token — the thing that actually has to change hands for NTT
+/// burning mode to work, since the NTT manager needs mint authority on the source token.
+/// EVM origins detect this from `Ownable`/`AccessControl`-style function selectors;
+/// `SplAnalyzer` reports it directly from the mint's `mint_authority` field.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct AccessControl {
+    /// Ownable-style admin (`owner()`, `transferOwnership`, `renounceOwnership`)
+    pub has_owner: bool,
+    /// OpenZeppelin `AccessControl`-style role admin (`hasRole`, `grantRole`)
+    pub has_role_based_access: bool,
+}
+
+impl AccessControl {
+    /// Whether some external party could reassign mint authority to the NTT manager.
+    /// A token with mint capability but no owner/role hook (e.g. a hardcoded minter, or
+    /// a fixed-supply token with no mint function at all) has nothing to hand over.
+    pub fn mint_authority_controllable(&self, capabilities: &TokenCapabilities) -> bool {
+        capabilities.has_mint && (self.has_owner || self.has_role_based_access)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_is_evm() {
+        assert!(Chain::Ethereum.is_evm());
+        assert!(Chain::Bsc.is_evm());
+        assert!(!Chain::Solana.is_evm());
+    }
+
+    #[test]
+    fn test_from_str_solana() {
+        assert_eq!(Chain::from_str("solana").unwrap(), Chain::Solana);
+        assert_eq!(Chain::from_str("SOL").unwrap(), Chain::Solana);
+    }
+
+    #[test]
+    fn test_solana_chain_id_has_no_evm_equivalent() {
+        assert_eq!(Chain::Solana.chain_id(), 0);
+    }
 }